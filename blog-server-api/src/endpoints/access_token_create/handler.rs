@@ -0,0 +1,87 @@
+use blog_server_services::traits::access_token_service::TokenScope;
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::{AccessTokenCreateRequestContent, AccessTokenCreateRequestData};
+use super::response_content_failure::AccessTokenCreateResponseContentFailure;
+use super::response_content_failure::AccessTokenCreateResponseContentFailure::*;
+use super::response_content_success::{
+    AccessTokenCreateContainer, AccessTokenCreateResponseContentSuccess,
+};
+use crate::utils::auth;
+
+const MAX_NAME_LENGTH: usize = 64;
+const MAX_LIFETIME_DAYS: u64 = 365;
+
+pub async fn http_handler(
+    (AccessTokenCreateRequestContent {
+        access_token_create_data,
+        access_token_service,
+        auth_session_future,
+    },): (AccessTokenCreateRequestContent,),
+) -> Result<AccessTokenCreateResponseContentSuccess, AccessTokenCreateResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    let AccessTokenCreateRequestData {
+        name,
+        scopes,
+        expires_in_days,
+    } = access_token_create_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(ValidationError {
+            reason: format!("name must be 1 to {MAX_NAME_LENGTH} characters long"),
+        });
+    }
+
+    if scopes.is_empty() {
+        return Err(ValidationError {
+            reason: "at least one scope is required".to_string(),
+        });
+    }
+    // Checked again on every request, this only rejects tokens that could never work.
+    if let Some(scope) = scopes.iter().find(|s| !author.can(s.capability())) {
+        return Err(ValidationError {
+            reason: format!("scope `{}` is not available for you", scope.as_str()),
+        });
+    }
+
+    if !(1..=MAX_LIFETIME_DAYS).contains(&expires_in_days) {
+        return Err(ValidationError {
+            reason: format!("expiry must be 1 to {MAX_LIFETIME_DAYS} days"),
+        });
+    }
+
+    let (access_token, token) = auth::create_access_token(
+        &author,
+        name,
+        &scopes,
+        expires_in_days * 60 * 60 * 24,
+        access_token_service,
+    )
+    .await
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(AccessTokenCreateContainer {
+        id: access_token.id,
+        name: access_token.base.name,
+        scopes: TokenScope::split(&access_token.base.scopes),
+        created_at: access_token.base.created_at,
+        expires_at: access_token.base.expires_at,
+        token,
+    }
+    .into())
+}
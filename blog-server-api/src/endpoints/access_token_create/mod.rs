@@ -0,0 +1,6 @@
+mod handler;
+mod request_content;
+mod response_content_failure;
+mod response_content_success;
+
+pub use handler::http_handler;
@@ -0,0 +1,42 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::{AccessTokenService, TokenScope};
+use blog_server_services::traits::author_service::AuthorService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessTokenCreateRequestData {
+    /// What the token is for, like the CI job using it.
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub expires_in_days: u64,
+}
+
+pub struct AccessTokenCreateRequestContent {
+    pub(super) access_token_create_data: DResult<AccessTokenCreateRequestData>,
+    pub(super) access_token_service: Arc<dyn AccessTokenService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AccessTokenCreateRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn AccessTokenService>>,
+{
+    type Data = AccessTokenCreateRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            access_token_create_data: origin_content.data_result,
+            access_token_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AccessTokenCreateResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    Unauthorized { reason: String },
+    Forbidden,
+    ValidationError { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AccessTokenCreateResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AccessTokenCreateResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AccessTokenCreateResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AccessTokenCreateResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AccessTokenCreateResponseContentFailure::ValidationError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AccessTokenCreateResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AccessTokenCreateResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AccessTokenCreateResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "ACCESS_TOKEN_CREATE_PARAMS_DECODE_ERROR"
+            }
+            AccessTokenCreateResponseContentFailure::Unauthorized { reason: _ } => {
+                "ACCESS_TOKEN_CREATE_UNAUTHORIZED"
+            }
+            AccessTokenCreateResponseContentFailure::Forbidden => "ACCESS_TOKEN_CREATE_FORBIDDEN",
+            AccessTokenCreateResponseContentFailure::ValidationError { reason: _ } => {
+                "ACCESS_TOKEN_CREATE_VALIDATION_ERROR"
+            }
+            AccessTokenCreateResponseContentFailure::DatabaseError { reason: _ } => {
+                "ACCESS_TOKEN_CREATE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AccessTokenCreateResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            AccessTokenCreateResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AccessTokenCreateResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            AccessTokenCreateResponseContentFailure::ValidationError { reason } => {
+                format!("validation error: {}", reason)
+            }
+            AccessTokenCreateResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
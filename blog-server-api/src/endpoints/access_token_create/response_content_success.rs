@@ -0,0 +1,49 @@
+use blog_server_services::traits::access_token_service::TokenScope;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessTokenCreateContainer {
+    pub id: u64,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub created_at: u64,
+    pub expires_at: u64,
+    /// Shown only once, only its hash is stored.
+    pub token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessTokenCreateResponseContentSuccess {
+    container: AccessTokenCreateContainer,
+}
+
+impl Into<AccessTokenCreateResponseContentSuccess> for AccessTokenCreateContainer {
+    fn into(self) -> AccessTokenCreateResponseContentSuccess {
+        AccessTokenCreateResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for AccessTokenCreateResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AccessTokenCreateResponseContentSuccess {
+    type Data = AccessTokenCreateContainer;
+
+    fn identifier(&self) -> &'static str {
+        "ACCESS_TOKEN_CREATE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("access token created".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
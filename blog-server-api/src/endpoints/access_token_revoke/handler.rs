@@ -0,0 +1,36 @@
+use super::request_content::AccessTokenRevokeRequestContent;
+use super::response_content_failure::AccessTokenRevokeResponseContentFailure;
+use super::response_content_failure::AccessTokenRevokeResponseContentFailure::*;
+use super::response_content_success::AccessTokenRevokeResponseContentSuccess;
+
+pub async fn http_handler(
+    (AccessTokenRevokeRequestContent {
+        id,
+        access_token_service,
+        auth_session_future,
+    },): (AccessTokenRevokeRequestContent,),
+) -> Result<AccessTokenRevokeResponseContentSuccess, AccessTokenRevokeResponseContentFailure> {
+    let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
+        reason: e.to_string(),
+    })?;
+
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    let revoked = access_token_service
+        .revoke_access_token(&id, &author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if !revoked {
+        return Err(NotFound);
+    }
+
+    Ok(AccessTokenRevokeResponseContentSuccess)
+}
@@ -0,0 +1,68 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AccessTokenRevokeResponseContentFailure {
+    Unauthorized { reason: String },
+    IncorrectIdFormat { reason: String },
+    NotFound,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AccessTokenRevokeResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AccessTokenRevokeResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AccessTokenRevokeResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AccessTokenRevokeResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            AccessTokenRevokeResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AccessTokenRevokeResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AccessTokenRevokeResponseContentFailure::Unauthorized { reason: _ } => {
+                "ACCESS_TOKEN_REVOKE_UNAUTHORIZED"
+            }
+            AccessTokenRevokeResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                "ACCESS_TOKEN_REVOKE_INCORRECT_ID_FORMAT"
+            }
+            AccessTokenRevokeResponseContentFailure::NotFound => "ACCESS_TOKEN_REVOKE_NOT_FOUND",
+            AccessTokenRevokeResponseContentFailure::DatabaseError { reason: _ } => {
+                "ACCESS_TOKEN_REVOKE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AccessTokenRevokeResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AccessTokenRevokeResponseContentFailure::IncorrectIdFormat { reason } => {
+                format!("incorrect value provided for access token ID: {}", reason)
+            }
+            AccessTokenRevokeResponseContentFailure::NotFound => {
+                "access token not found".to_string()
+            }
+            AccessTokenRevokeResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
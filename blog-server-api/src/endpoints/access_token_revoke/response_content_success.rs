@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AccessTokenRevokeResponseContentSuccess;
+
+impl ApiResponseContentBase for AccessTokenRevokeResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AccessTokenRevokeResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "ACCESS_TOKEN_REVOKE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("access token revoked".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
@@ -0,0 +1,27 @@
+use super::request_content::AccessTokensRequestContent;
+use super::response_content_failure::AccessTokensResponseContentFailure;
+use super::response_content_failure::AccessTokensResponseContentFailure::*;
+use super::response_content_success::{AccessTokensContainer, AccessTokensResponseContentSuccess};
+
+pub async fn http_handler(
+    (AccessTokensRequestContent {
+        access_token_service,
+        auth_session_future,
+    },): (AccessTokensRequestContent,),
+) -> Result<AccessTokensResponseContentSuccess, AccessTokensResponseContentFailure> {
+    let auth_session = auth_session_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let access_tokens = access_token_service
+        .active_access_tokens_by_author_id(&auth_session.author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(AccessTokensContainer {
+        access_tokens: access_tokens.into_iter().map(Into::into).collect(),
+    }
+    .into())
+}
@@ -0,0 +1,29 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::AuthorService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AccessTokensRequestContent {
+    pub(super) access_token_service: Arc<dyn AccessTokenService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AccessTokensRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn AccessTokenService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            access_token_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AccessTokensResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AccessTokensResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AccessTokensResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AccessTokensResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AccessTokensResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AccessTokensResponseContentFailure::Unauthorized { reason: _ } => {
+                "ACCESS_TOKENS_UNAUTHORIZED"
+            }
+            AccessTokensResponseContentFailure::DatabaseError { reason: _ } => {
+                "ACCESS_TOKENS_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AccessTokensResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AccessTokensResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
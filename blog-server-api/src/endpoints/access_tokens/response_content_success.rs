@@ -0,0 +1,67 @@
+use blog_server_services::traits::access_token_service::{AccessToken, TokenScope};
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessTokenEntity {
+    pub id: u64,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub created_at: u64,
+    pub last_used_at: Option<u64>,
+    pub expires_at: u64,
+}
+
+impl From<AccessToken> for AccessTokenEntity {
+    fn from(access_token: AccessToken) -> Self {
+        AccessTokenEntity {
+            id: access_token.id,
+            scopes: TokenScope::split(&access_token.base.scopes),
+            name: access_token.base.name,
+            created_at: access_token.base.created_at,
+            last_used_at: access_token.base.last_used_at,
+            expires_at: access_token.base.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessTokensContainer {
+    pub access_tokens: Vec<AccessTokenEntity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessTokensResponseContentSuccess {
+    container: AccessTokensContainer,
+}
+
+impl Into<AccessTokensResponseContentSuccess> for AccessTokensContainer {
+    fn into(self) -> AccessTokensResponseContentSuccess {
+        AccessTokensResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for AccessTokensResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AccessTokensResponseContentSuccess {
+    type Data = AccessTokensContainer;
+
+    fn identifier(&self) -> &'static str {
+        "ACCESS_TOKENS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("active access tokens returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
@@ -0,0 +1,330 @@
+use blog_server_services::traits::audit_service::{AuditAction, AuditQuery, AuditTarget};
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::AuditRequestContent;
+use super::response_content_failure::AuditResponseContentFailure;
+use super::response_content_failure::AuditResponseContentFailure::*;
+use super::response_content_success::{AuditContainer, AuditResponseContentSuccess};
+
+pub async fn http_handler(
+    (AuditRequestContent {
+        filter,
+        offset,
+        limit,
+        audit_service,
+        auth_author_future,
+    },): (AuditRequestContent,),
+) -> Result<AuditResponseContentSuccess, AuditResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    if !author.can(Capability::ViewAuditLog) {
+        return Err(Forbidden);
+    }
+
+    let query = AuditQuery {
+        actor_id: filter.actor_id,
+        action: match filter.action {
+            None => None,
+            Some(action) => Some(AuditAction::parse(&action).ok_or(IncorrectFilter {
+                reason: format!("unknown action '{action}'"),
+            })?),
+        },
+        target_type: match filter.target_type {
+            None => None,
+            Some(target_type) => Some(AuditTarget::parse(&target_type).ok_or(IncorrectFilter {
+                reason: format!("unknown target type '{target_type}'"),
+            })?),
+        },
+        target_id: filter.target_id,
+    };
+
+    let offset = offset.unwrap_or(0).max(0);
+    let limit = limit.unwrap_or(50).max(0).min(50);
+
+    let (records_result, total_result) = tokio::join!(
+        audit_service.records(&query, &offset, &limit),
+        audit_service.records_count(&query),
+    );
+
+    let records = records_result.map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    let total = total_result.map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(AuditContainer {
+        records: records.into_iter().map(|r| r.into()).collect(),
+        total,
+        offset,
+        limit,
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::request_content::AuditRequestContentFilter;
+    use super::*;
+    use crate::utils::auth;
+    use async_trait::async_trait;
+    use blog_server_services::traits::audit_service::{AuditRecord, AuditService, BaseAuditRecord};
+    use blog_server_services::traits::author_service::{Author, AuthorRole, BaseAuthor};
+    use screw_components::dyn_result::DResult;
+    use std::sync::{Arc, Mutex};
+
+    struct MockAuditService {
+        records: Vec<AuditRecord>,
+        limits: Mutex<Vec<u64>>,
+    }
+
+    impl MockAuditService {
+        fn matching(&self, query: &AuditQuery) -> Vec<AuditRecord> {
+            self.records
+                .iter()
+                .filter(|r| query.actor_id.is_none_or(|id| r.base.actor_id == id))
+                .filter(|r| query.action.is_none_or(|a| r.base.action == a))
+                .filter(|r| query.target_type.is_none_or(|t| r.base.target_type == t))
+                .filter(|r| query.target_id.is_none_or(|id| r.base.target_id == id))
+                .cloned()
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl AuditService for MockAuditService {
+        async fn records_count(&self, query: &AuditQuery) -> DResult<u64> {
+            Ok(self.matching(query).len() as u64)
+        }
+
+        async fn records(
+            &self,
+            query: &AuditQuery,
+            offset: &u64,
+            limit: &u64,
+        ) -> DResult<Vec<AuditRecord>> {
+            self.limits.lock().unwrap().push(*limit);
+            Ok(self
+                .matching(query)
+                .into_iter()
+                .skip(*offset as usize)
+                .take(*limit as usize)
+                .collect())
+        }
+    }
+
+    fn record(
+        id: u64,
+        actor_id: u64,
+        action: AuditAction,
+        target_type: AuditTarget,
+        target_id: u64,
+    ) -> AuditRecord {
+        AuditRecord {
+            id,
+            base: BaseAuditRecord {
+                actor_id,
+                action,
+                target_type,
+                target_id,
+                before: None,
+                after: None,
+                created_at: 0,
+            },
+        }
+    }
+
+    fn sample_author(role: AuthorRole) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash: None,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    fn no_filter() -> AuditRequestContentFilter {
+        AuditRequestContentFilter {
+            actor_id: None,
+            action: None,
+            target_type: None,
+            target_id: None,
+        }
+    }
+
+    async fn audit(
+        author: Result<Author, auth::Error>,
+        filter: AuditRequestContentFilter,
+        limit: Option<u64>,
+        audit_service: Arc<MockAuditService>,
+    ) -> Result<AuditResponseContentSuccess, AuditResponseContentFailure> {
+        http_handler((AuditRequestContent {
+            filter,
+            offset: None,
+            limit,
+            audit_service,
+            auth_author_future: Box::pin(std::future::ready(author)),
+        },))
+        .await
+    }
+
+    fn audit_service() -> Arc<MockAuditService> {
+        Arc::new(MockAuditService {
+            records: vec![
+                record(1, 1, AuditAction::AuthorBlocked, AuditTarget::Author, 5),
+                record(2, 1, AuditAction::PostRecommended, AuditTarget::Post, 7),
+                record(3, 2, AuditAction::AuthorBlocked, AuditTarget::Author, 6),
+                record(4, 2, AuditAction::CommentDeleted, AuditTarget::Comment, 9),
+            ],
+            limits: Mutex::new(vec![]),
+        })
+    }
+
+    fn record_ids(
+        result: Result<AuditResponseContentSuccess, AuditResponseContentFailure>,
+    ) -> Vec<u64> {
+        let container = result.ok().unwrap().container;
+        assert_eq!(container.total, container.records.len() as u64);
+        container.records.iter().map(|r| r.id).collect()
+    }
+
+    #[tokio::test]
+    async fn only_editors_read_the_log() {
+        for role in [
+            AuthorRole::Reader,
+            AuthorRole::Contributor,
+            AuthorRole::Author,
+        ] {
+            let result = audit(Ok(sample_author(role)), no_filter(), None, audit_service()).await;
+            assert!(matches!(result, Err(Forbidden)));
+        }
+        for role in [AuthorRole::Editor, AuthorRole::Admin] {
+            let result = audit(Ok(sample_author(role)), no_filter(), None, audit_service()).await;
+            assert_eq!(record_ids(result), vec![1, 2, 3, 4]);
+        }
+
+        let result = audit(
+            Err(auth::Error::TokenMissing),
+            no_filter(),
+            None,
+            audit_service(),
+        )
+        .await;
+        assert!(matches!(result, Err(Unauthorized { .. })));
+    }
+
+    #[tokio::test]
+    async fn blocked_editor_is_forbidden() {
+        let mut author = sample_author(AuthorRole::Editor);
+        author.base.blocked = 1;
+        let result = audit(Ok(author), no_filter(), None, audit_service()).await;
+        assert!(matches!(result, Err(Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn filters_narrow_the_records() {
+        let editor = || Ok(sample_author(AuthorRole::Editor));
+
+        let filters = [
+            (
+                AuditRequestContentFilter {
+                    actor_id: Some(2),
+                    ..no_filter()
+                },
+                vec![3, 4],
+            ),
+            (
+                AuditRequestContentFilter {
+                    action: Some("author_blocked".into()),
+                    ..no_filter()
+                },
+                vec![1, 3],
+            ),
+            (
+                AuditRequestContentFilter {
+                    target_type: Some("post".into()),
+                    ..no_filter()
+                },
+                vec![2],
+            ),
+            (
+                AuditRequestContentFilter {
+                    action: Some("author_blocked".into()),
+                    target_type: Some("author".into()),
+                    target_id: Some(6),
+                    ..no_filter()
+                },
+                vec![3],
+            ),
+        ];
+        for (filter, ids) in filters {
+            let result = audit(editor(), filter, None, audit_service()).await;
+            assert_eq!(record_ids(result), ids);
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_filter_values_are_rejected() {
+        for filter in [
+            AuditRequestContentFilter {
+                action: Some("author_deleted".into()),
+                ..no_filter()
+            },
+            AuditRequestContentFilter {
+                target_type: Some("tag".into()),
+                ..no_filter()
+            },
+        ] {
+            let result = audit(
+                Ok(sample_author(AuthorRole::Editor)),
+                filter,
+                None,
+                audit_service(),
+            )
+            .await;
+            assert!(matches!(result, Err(IncorrectFilter { .. })));
+        }
+    }
+
+    #[tokio::test]
+    async fn limit_is_capped() {
+        let audit_service = audit_service();
+        for limit in [None, Some(10), Some(1000)] {
+            let result = audit(
+                Ok(sample_author(AuthorRole::Editor)),
+                no_filter(),
+                limit,
+                audit_service.clone(),
+            )
+            .await;
+            assert!(result.is_ok());
+        }
+        assert_eq!(*audit_service.limits.lock().unwrap(), vec![50, 10, 50]);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::audit_service::AuditService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AuditRequestContentFilter {
+    pub actor_id: Option<u64>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<u64>,
+}
+
+pub struct AuditRequestContent {
+    pub(super) filter: AuditRequestContentFilter,
+    pub(super) offset: Option<u64>,
+    pub(super) limit: Option<u64>,
+    pub(super) audit_service: Arc<dyn AuditService>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuditRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuditService>>
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            filter: AuditRequestContentFilter {
+                actor_id: origin_content
+                    .query
+                    .get("actor_id")
+                    .map(|v| v.parse().ok())
+                    .flatten(),
+                action: origin_content.query.get("action").map(|v| v.to_owned()),
+                target_type: origin_content
+                    .query
+                    .get("target_type")
+                    .map(|v| v.to_owned()),
+                target_id: origin_content
+                    .query
+                    .get("target_id")
+                    .map(|v| v.parse().ok())
+                    .flatten(),
+            },
+            offset: origin_content
+                .query
+                .get("offset")
+                .map(|v| v.parse().ok())
+                .flatten(),
+            limit: origin_content
+                .query
+                .get("limit")
+                .map(|v| v.parse().ok())
+                .flatten(),
+            audit_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuditResponseContentFailure {
+    DatabaseError { reason: String },
+    Unauthorized { reason: String },
+    Forbidden,
+    IncorrectFilter { reason: String },
+}
+
+impl ApiResponseContentBase for AuditResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuditResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            AuditResponseContentFailure::Unauthorized { reason: _ } => &StatusCode::UNAUTHORIZED,
+            AuditResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AuditResponseContentFailure::IncorrectFilter { reason: _ } => &StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuditResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuditResponseContentFailure::DatabaseError { reason: _ } => "AUDIT_DATABASE_ERROR",
+            AuditResponseContentFailure::Unauthorized { reason: _ } => "AUDIT_UNAUTHORIZED",
+            AuditResponseContentFailure::Forbidden => "AUDIT_FORBIDDEN",
+            AuditResponseContentFailure::IncorrectFilter { reason: _ } => "AUDIT_INCORRECT_FILTER",
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuditResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            AuditResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuditResponseContentFailure::Forbidden => String::from("insufficient rights"),
+            AuditResponseContentFailure::IncorrectFilter { reason } => {
+                format!("incorrect filter value: {}", reason)
+            }
+        })
+    }
+}
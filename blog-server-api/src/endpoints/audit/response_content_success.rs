@@ -0,0 +1,75 @@
+use blog_server_services::traits::audit_service::{AuditAction, AuditRecord, AuditTarget};
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecordEntity {
+    pub id: u64,
+    pub actor_id: u64,
+    pub action: AuditAction,
+    pub target_type: AuditTarget,
+    pub target_id: u64,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub created_at: u64,
+}
+
+impl From<AuditRecord> for AuditRecordEntity {
+    fn from(record: AuditRecord) -> Self {
+        let snapshot = |s: Option<String>| s.and_then(|s| serde_json::from_str(&s).ok());
+        AuditRecordEntity {
+            id: record.id,
+            actor_id: record.base.actor_id,
+            action: record.base.action,
+            target_type: record.base.target_type,
+            target_id: record.base.target_id,
+            before: snapshot(record.base.before),
+            after: snapshot(record.base.after),
+            created_at: record.base.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditContainer {
+    pub records: Vec<AuditRecordEntity>,
+    pub total: u64,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditResponseContentSuccess {
+    pub(super) container: AuditContainer,
+}
+
+impl Into<AuditResponseContentSuccess> for AuditContainer {
+    fn into(self) -> AuditResponseContentSuccess {
+        AuditResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for AuditResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuditResponseContentSuccess {
+    type Data = AuditContainer;
+
+    fn identifier(&self) -> &'static str {
+        "AUDIT_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("audit records returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
@@ -1,54 +1,70 @@
 use std::sync::Arc;
 
 use blog_generic::entities::AuthorContainer;
-use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
 
 use super::request_content::AuthorRequestContent;
 use super::response_content_failure::AuthorResponseContentFailure;
 use super::response_content_failure::AuthorResponseContentFailure::*;
 use super::response_content_success::AuthorResponseContentSuccess;
 
+async fn author_by_slug(
+    slug: &String,
+    author_service: &Arc<dyn AuthorService>,
+) -> Result<Author, AuthorResponseContentFailure> {
+    if slug.is_empty() {
+        return Err(SlugEmpty);
+    }
+
+    author_service
+        .author_by_slug(slug)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(NotFound)
+}
+
 pub async fn http_handler(
     (AuthorRequestContent {
         slug,
         author_service,
+        follow_service,
     },): (AuthorRequestContent,),
 ) -> Result<AuthorResponseContentSuccess, AuthorResponseContentFailure> {
-    if slug.is_empty() {
-        return Err(SlugEmpty);
-    }
+    let author = author_by_slug(&slug, &author_service).await?;
 
-    let author = author_service
-        .author_by_slug(&slug)
+    let follow_counts = follow_service
+        .follow_counts(&author.id)
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
-        })?
-        .ok_or(NotFound)?;
+        })?;
 
-    Ok(author.into())
+    Ok((author, follow_counts).into())
 }
 
 pub async fn direct_handler(
     slug: String,
     author_service: Arc<dyn AuthorService>,
 ) -> Option<AuthorContainer> {
-    http_handler((AuthorRequestContent {
-        slug,
-        author_service,
-    },))
-    .await
-    .ok()
-    .map(|s| s.container)
+    author_by_slug(&slug, &author_service)
+        .await
+        .ok()
+        .map(|author| AuthorContainer {
+            author: author.into(),
+        })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use async_trait::async_trait;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
     use blog_server_services::traits::author_service::{
-        Author, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        AuthorRole, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor, DeletedAuthorPosts,
     };
+    use blog_server_services::traits::follow_service::{FollowCounts, FollowService};
     use screw_components::dyn_result::DResult;
 
     enum MockBehavior {
@@ -109,6 +125,10 @@ mod tests {
             unimplemented!()
         }
 
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
         async fn set_author_override_social_data_by_id(
             &self,
             _id: &u64,
@@ -144,6 +164,15 @@ mod tests {
             unimplemented!()
         }
 
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
         async fn update_secondary_author_by_id(
             &self,
             _id: &u64,
@@ -152,7 +181,29 @@ mod tests {
             unimplemented!()
         }
 
-        async fn set_author_blocked_by_id(&self, _id: &u64, _is_blocked: &u8) -> DResult<()> {
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
             unimplemented!()
         }
 
@@ -163,6 +214,68 @@ mod tests {
         ) -> DResult<()> {
             unimplemented!()
         }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockFollowService;
+
+    #[async_trait]
+    impl FollowService for MockFollowService {
+        async fn follow_author(&self, _follower_id: &u64, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn unfollow_author(&self, _follower_id: &u64, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn follow_tag(&self, _follower_id: &u64, _tag_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn unfollow_tag(&self, _follower_id: &u64, _tag_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn followed_author_ids(&self, _follower_id: &u64) -> DResult<Vec<u64>> {
+            unimplemented!()
+        }
+
+        async fn followed_tag_ids(&self, _follower_id: &u64) -> DResult<Vec<u64>> {
+            unimplemented!()
+        }
+
+        async fn follower_ids(&self, _author_id: &u64) -> DResult<Vec<u64>> {
+            unimplemented!()
+        }
+
+        async fn follow_counts(&self, _author_id: &u64) -> DResult<FollowCounts> {
+            Ok(FollowCounts {
+                followers: 3,
+                following: 2,
+            })
+        }
     }
 
     fn sample_author() -> Author {
@@ -179,13 +292,21 @@ mod tests {
                 registered_at: 0,
                 status: None,
                 image_url: None,
-                editor: 0,
+                role: AuthorRole::Contributor,
                 blocked: 0,
                 yandex_id: None,
                 telegram_id: None,
                 notification_subscribed: None,
                 override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
             },
+            token_scopes: None,
         }
     }
 
@@ -197,6 +318,7 @@ mod tests {
         let result = http_handler((AuthorRequestContent {
             slug: String::new(),
             author_service: service,
+            follow_service: Arc::new(MockFollowService),
         },))
         .await;
         assert!(matches!(result, Err(SlugEmpty)));
@@ -210,6 +332,7 @@ mod tests {
         let result = http_handler((AuthorRequestContent {
             slug: "missing".into(),
             author_service: service,
+            follow_service: Arc::new(MockFollowService),
         },))
         .await;
         assert!(matches!(result, Err(NotFound)));
@@ -223,6 +346,7 @@ mod tests {
         let result = http_handler((AuthorRequestContent {
             slug: "john".into(),
             author_service: service,
+            follow_service: Arc::new(MockFollowService),
         },))
         .await;
         assert!(matches!(result, Err(DatabaseError { .. })));
@@ -236,8 +360,33 @@ mod tests {
         let result = http_handler((AuthorRequestContent {
             slug: "john".into(),
             author_service: service,
+            follow_service: Arc::new(MockFollowService),
+        },))
+        .await;
+        let author = result.ok().unwrap().container.author;
+        assert_eq!(author.followers_count, 3);
+        assert_eq!(author.following_count, 2);
+    }
+
+    #[tokio::test]
+    async fn contacts_are_shown_only_when_public() {
+        let mut author = sample_author();
+        author.base.email = Some("john@example.com".into());
+        author.base.mobile = Some("+10000000000".into());
+        author.base.email_public = 1;
+        author.base.links = Some("https://example.com https://github.com/john".into());
+        let service = Arc::new(MockAuthorService {
+            behavior: MockBehavior::Success(Some(author)),
+        });
+        let result = http_handler((AuthorRequestContent {
+            slug: "john".into(),
+            author_service: service,
+            follow_service: Arc::new(MockFollowService),
         },))
         .await;
-        assert!(matches!(result, Ok(_)));
+        let author = result.ok().unwrap().container.author;
+        assert_eq!(author.author.email.as_deref(), Some("john@example.com"));
+        assert_eq!(author.author.mobile, None);
+        assert_eq!(author.profile.links.len(), 2);
     }
 }
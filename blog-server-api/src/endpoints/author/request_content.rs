@@ -1,16 +1,18 @@
 use crate::extensions::Resolve;
 use blog_server_services::traits::author_service::*;
+use blog_server_services::traits::follow_service::FollowService;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use std::sync::Arc;
 
 pub struct AuthorRequestContent {
     pub(super) slug: String,
     pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) follow_service: Arc<dyn FollowService>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for AuthorRequestContent
 where
-    Extensions: Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn FollowService>>,
 {
     type Data = ();
 
@@ -22,6 +24,7 @@ where
                 .map(|n| n.to_owned())
                 .unwrap_or_default(),
             author_service: origin_content.extensions.resolve(),
+            follow_service: origin_content.extensions.resolve(),
         }
     }
 }
@@ -1,18 +1,43 @@
-use blog_generic::entities::AuthorContainer;
-use blog_server_services::traits::author_service::Author as ServiceAuthor;
+use blog_generic::entities::Author as EAuthor;
+use blog_server_services::traits::author_service::{Author as ServiceAuthor, AuthorProfile};
+use blog_server_services::traits::follow_service::FollowCounts;
 use hyper::StatusCode;
 use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+/// The author entity extended with the profile and follow counts, old clients just ignore them.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowedAuthor {
+    #[serde(flatten)]
+    pub author: EAuthor,
+    #[serde(flatten)]
+    pub profile: AuthorProfile,
+    pub followers_count: u64,
+    pub following_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowedAuthorContainer {
+    pub author: FollowedAuthor,
+}
 
 #[derive(Debug, Clone)]
 pub struct AuthorResponseContentSuccess {
-    pub(super) container: AuthorContainer,
+    pub(super) container: FollowedAuthorContainer,
 }
 
-impl Into<AuthorResponseContentSuccess> for ServiceAuthor {
+impl Into<AuthorResponseContentSuccess> for (ServiceAuthor, FollowCounts) {
     fn into(self) -> AuthorResponseContentSuccess {
+        let (author, follow_counts) = self;
         AuthorResponseContentSuccess {
-            container: AuthorContainer {
-                author: self.into(),
+            container: FollowedAuthorContainer {
+                author: FollowedAuthor {
+                    profile: author.profile(),
+                    author: author.into(),
+                    followers_count: follow_counts.followers,
+                    following_count: follow_counts.following,
+                },
             },
         }
     }
@@ -25,7 +50,7 @@ impl ApiResponseContentBase for AuthorResponseContentSuccess {
 }
 
 impl ApiResponseContentSuccess for AuthorResponseContentSuccess {
-    type Data = AuthorContainer;
+    type Data = FollowedAuthorContainer;
 
     fn identifier(&self) -> &'static str {
         "AUTHOR_FOUND"
@@ -1,3 +1,8 @@
+use blog_server_services::traits::audit_service::{
+    AuditAction, AuditTarget, BaseAuditRecord, ModerationDecision,
+};
+use blog_server_services::traits::author_service::Capability;
+
 use super::request_content::AuthorBlockRequestContent;
 use super::response_content_failure::AuthorBlockResponseContentFailure;
 use super::response_content_failure::AuthorBlockResponseContentFailure::*;
@@ -19,6 +24,7 @@ async fn http_handler(
     AuthorBlockRequestContent {
         id,
         author_service,
+        moderation_decision_service,
         auth_author_future,
     }: AuthorBlockRequestContent,
     is_blocked: u8,
@@ -31,16 +37,54 @@ async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.editor != 1 {
+    if !author.can(Capability::ManageAuthors) {
         return Err(Forbidden);
     }
 
+    let target_author = author_service
+        .author_by_id(&id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(NotFound)?;
+
+    let action = if is_blocked == 1 {
+        AuditAction::AuthorBlocked
+    } else {
+        AuditAction::AuthorUnblocked
+    };
+
     author_service
-        .set_author_blocked_by_id(&id, &is_blocked)
+        .set_author_blocked_by_id(
+            &id,
+            &is_blocked,
+            &BaseAuditRecord::new(
+                author.id,
+                action,
+                AuditTarget::Author,
+                id,
+                Some(&serde_json::json!({ "blocked": target_author.base.blocked })),
+                Some(&serde_json::json!({ "blocked": is_blocked })),
+            ),
+        )
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
         })?;
 
+    let moderation_decision = ModerationDecision {
+        moderator_id: author.id,
+        author_id: id,
+        action,
+        target_type: AuditTarget::Author,
+        target_id: id,
+    };
+    tokio::spawn(async move {
+        moderation_decision_service
+            .publish(moderation_decision)
+            .await
+    });
+
     Ok(AuthorBlockResponseContentSuccess)
 }
@@ -1,5 +1,8 @@
 use crate::extensions::Resolve;
 use crate::utils::auth;
+use blog_server_services::traits::Publish;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::audit_service::ModerationDecision;
 use blog_server_services::traits::author_service::*;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use screw_components::dyn_fn::DFuture;
@@ -8,12 +11,15 @@ use std::sync::Arc;
 pub struct AuthorBlockRequestContent {
     pub(super) id: String,
     pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) moderation_decision_service: Arc<dyn Publish<ModerationDecision>>,
     pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for AuthorBlockRequestContent
 where
-    Extensions: Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn Publish<ModerationDecision>>>,
 {
     type Data = ();
 
@@ -25,9 +31,11 @@ where
                 .map(|n| n.to_owned())
                 .unwrap_or_default(),
             author_service: origin_content.extensions.resolve(),
+            moderation_decision_service: origin_content.extensions.resolve(),
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -6,6 +6,7 @@ pub enum AuthorBlockResponseContentFailure {
     Forbidden,
     DatabaseError { reason: String },
     IncorrectIdFormat { reason: String },
+    NotFound,
 }
 
 impl ApiResponseContentBase for AuthorBlockResponseContentFailure {
@@ -21,6 +22,7 @@ impl ApiResponseContentBase for AuthorBlockResponseContentFailure {
             AuthorBlockResponseContentFailure::IncorrectIdFormat { reason: _ } => {
                 &StatusCode::BAD_REQUEST
             }
+            AuthorBlockResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
         }
     }
 }
@@ -38,6 +40,7 @@ impl ApiResponseContentFailure for AuthorBlockResponseContentFailure {
             AuthorBlockResponseContentFailure::IncorrectIdFormat { reason: _ } => {
                 "AUTHOR_BLOCK_INCORRECT_ID_FORMAT"
             }
+            AuthorBlockResponseContentFailure::NotFound => "AUTHOR_BLOCK_NOT_FOUND",
         }
     }
 
@@ -61,6 +64,9 @@ impl ApiResponseContentFailure for AuthorBlockResponseContentFailure {
             AuthorBlockResponseContentFailure::IncorrectIdFormat { reason } => {
                 format!("incorrect value provided for author ID: {}", reason)
             }
+            AuthorBlockResponseContentFailure::NotFound => {
+                "author record not found in database".to_string()
+            }
         })
     }
 }
@@ -0,0 +1,536 @@
+use blog_server_services::traits::author_service::DeletedAuthorPosts;
+use password_hash::Error;
+
+use super::request_content::{AuthorDeleteRequestContent, AuthorDeleteRequestData};
+use super::response_content_failure::AuthorDeleteResponseContentFailure;
+use super::response_content_failure::AuthorDeleteResponseContentFailure::*;
+use super::response_content_success::AuthorDeleteResponseContentSuccess;
+use crate::utils::{login_attempts, password, two_factor};
+
+pub async fn http_handler(
+    (AuthorDeleteRequestContent {
+        author_delete_data,
+        author_service,
+        two_factor_service,
+        login_attempt_service,
+        account_deletion_config,
+        auth_session_future,
+        client_ip,
+    },): (AuthorDeleteRequestContent,),
+) -> Result<AuthorDeleteResponseContentSuccess, AuthorDeleteResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    let AuthorDeleteRequestData {
+        slug,
+        current_password,
+        code,
+    } = author_delete_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    if slug != author.base.slug {
+        return Err(SlugMismatch);
+    }
+
+    let posts = match account_deletion_config.reassign_posts_to {
+        Some(target_id) if target_id == author.id => return Err(ReassignTargetDeleted),
+        Some(target_id) => DeletedAuthorPosts::Reassign(target_id),
+        None => DeletedAuthorPosts::Delete,
+    };
+
+    let retry_after =
+        login_attempts::begin_attempt(&author.id, &client_ip, login_attempt_service.clone())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    // A stolen access token alone must not be enough to erase the account.
+    if let Some(password_hash) = &author.base.password_hash {
+        let current_password = current_password.ok_or(WrongPassword)?;
+        password::verify(&current_password, password_hash).map_err(|e| match e {
+            Error::Password => WrongPassword,
+            _ => PasswordVerificationError {
+                reason: e.to_string(),
+            },
+        })?;
+    }
+
+    let author_totp = two_factor::enabled_totp(&author.id, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if let Some(author_totp) = author_totp {
+        let code = code.ok_or(WrongCode)?;
+        let is_code_valid = two_factor::verify_code(&author_totp, &code, two_factor_service)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+        if !is_code_valid {
+            return Err(WrongCode);
+        }
+    }
+
+    login_attempts::register_success(&author.id, &client_ip, login_attempt_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    author_service
+        .delete_author(&author.id, &posts)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(AuthorDeleteResponseContentSuccess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::AccountDeletionConfig;
+    use crate::utils::auth;
+    use async_trait::async_trait;
+    use blog_server_services::impls::create_memory_login_attempt_service;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+    };
+    use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+    use blog_server_services::traits::two_factor_service::{AuthorTotp, TwoFactorService};
+    use screw_components::dyn_result::DResult;
+    use std::sync::{Arc, Mutex};
+
+    struct MockAuthorService {
+        deleted: Mutex<Vec<(u64, DeletedAuthorPosts)>>,
+    }
+
+    #[async_trait]
+    impl AuthorService for MockAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, _id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_slug(&self, _slug: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, id: &u64, posts: &DeletedAuthorPosts) -> DResult<()> {
+            self.deleted.lock().unwrap().push((*id, *posts));
+            Ok(())
+        }
+    }
+
+    struct MockTwoFactorService {
+        totp: Option<AuthorTotp>,
+    }
+
+    #[async_trait]
+    impl TwoFactorService for MockTwoFactorService {
+        async fn totp_by_author_id(&self, _author_id: &u64) -> DResult<Option<AuthorTotp>> {
+            Ok(self.totp.clone())
+        }
+
+        async fn set_pending_totp(&self, _author_id: &u64, _secret: &String) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn enable_totp(
+            &self,
+            _author_id: &u64,
+            _recovery_code_hashes: &Vec<String>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn use_totp_step(&self, _author_id: &u64, _step: &u64) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn use_recovery_code(&self, _author_id: &u64, code_hash: &String) -> DResult<bool> {
+            Ok(*code_hash == two_factor::recovery_code_hash("abcd-efgh"))
+        }
+
+        async fn unused_recovery_codes_count(&self, _author_id: &u64) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn disable_totp(&self, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_author(password_hash: Option<String>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    fn enabled_totp() -> AuthorTotp {
+        AuthorTotp {
+            author_id: 1,
+            secret: "MZXW6YTBOI".into(),
+            enabled: 1,
+            last_used_step: None,
+            created_at: 0,
+        }
+    }
+
+    struct Deletion {
+        author: Author,
+        current_password: Option<&'static str>,
+        code: Option<&'static str>,
+        totp: Option<AuthorTotp>,
+        reassign_posts_to: Option<u64>,
+    }
+
+    impl Deletion {
+        fn of(author: Author) -> Self {
+            Self {
+                author,
+                current_password: None,
+                code: None,
+                totp: None,
+                reassign_posts_to: None,
+            }
+        }
+
+        async fn run(
+            self,
+            login_attempt_service: Arc<dyn LoginAttemptService>,
+        ) -> (
+            Result<AuthorDeleteResponseContentSuccess, AuthorDeleteResponseContentFailure>,
+            Vec<(u64, DeletedAuthorPosts)>,
+        ) {
+            let author_service = Arc::new(MockAuthorService {
+                deleted: Mutex::new(vec![]),
+            });
+            let author = self.author;
+            let result = http_handler((AuthorDeleteRequestContent {
+                author_delete_data: Ok(AuthorDeleteRequestData {
+                    slug: "john".into(),
+                    current_password: self.current_password.map(str::to_string),
+                    code: self.code.map(str::to_string),
+                }),
+                author_service: author_service.clone(),
+                two_factor_service: Arc::new(MockTwoFactorService { totp: self.totp }),
+                login_attempt_service,
+                account_deletion_config: AccountDeletionConfig {
+                    reassign_posts_to: self.reassign_posts_to,
+                },
+                auth_session_future: Box::pin(async move {
+                    Ok(auth::AuthSession {
+                        author,
+                        session_id: 1,
+                        authenticated_at: 0,
+                        expires_at: u64::MAX,
+                    })
+                }),
+                client_ip: "127.0.0.1".into(),
+            },))
+            .await;
+            let deleted = author_service.deleted.lock().unwrap().clone();
+            (result, deleted)
+        }
+    }
+
+    #[tokio::test]
+    async fn posts_are_deleted_by_default() {
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let (result, deleted) = Deletion {
+            current_password: Some("secret"),
+            ..Deletion::of(sample_author(Some(hash)))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(deleted, vec![(1, DeletedAuthorPosts::Delete)]);
+    }
+
+    #[tokio::test]
+    async fn posts_are_reassigned_when_configured() {
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let (result, deleted) = Deletion {
+            current_password: Some("secret"),
+            reassign_posts_to: Some(2),
+            ..Deletion::of(sample_author(Some(hash)))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(deleted, vec![(1, DeletedAuthorPosts::Reassign(2))]);
+    }
+
+    #[tokio::test]
+    async fn posts_are_not_reassigned_to_the_deleted_author() {
+        let (result, deleted) = Deletion {
+            reassign_posts_to: Some(1),
+            ..Deletion::of(sample_author(None))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(matches!(result, Err(ReassignTargetDeleted)));
+        assert!(deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn password_is_required() {
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let (result, deleted) = Deletion::of(sample_author(Some(hash.clone())))
+            .run(create_memory_login_attempt_service())
+            .await;
+        assert!(matches!(result, Err(WrongPassword)));
+        assert!(deleted.is_empty());
+
+        let (result, deleted) = Deletion {
+            current_password: Some("wrong"),
+            ..Deletion::of(sample_author(Some(hash)))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(matches!(result, Err(WrongPassword)));
+        assert!(deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn two_factor_code_is_required_when_enabled() {
+        let (result, deleted) = Deletion {
+            totp: Some(enabled_totp()),
+            ..Deletion::of(sample_author(None))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(matches!(result, Err(WrongCode)));
+        assert!(deleted.is_empty());
+
+        let (result, deleted) = Deletion {
+            totp: Some(enabled_totp()),
+            code: Some("wrong"),
+            ..Deletion::of(sample_author(None))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(matches!(result, Err(WrongCode)));
+        assert!(deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn recovery_code_is_accepted() {
+        let (result, deleted) = Deletion {
+            totp: Some(enabled_totp()),
+            code: Some("abcd-efgh"),
+            ..Deletion::of(sample_author(None))
+        }
+        .run(create_memory_login_attempt_service())
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(deleted, vec![(1, DeletedAuthorPosts::Delete)]);
+    }
+
+    #[tokio::test]
+    async fn repeated_wrong_passwords_are_throttled() {
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let login_attempt_service = create_memory_login_attempt_service();
+        for _ in 0..5 {
+            let (result, _) = Deletion {
+                current_password: Some("wrong"),
+                ..Deletion::of(sample_author(Some(hash.clone())))
+            }
+            .run(login_attempt_service.clone())
+            .await;
+            assert!(matches!(result, Err(WrongPassword)));
+        }
+        let (result, deleted) = Deletion {
+            current_password: Some("secret"),
+            ..Deletion::of(sample_author(Some(hash)))
+        }
+        .run(login_attempt_service)
+        .await;
+        assert!(matches!(result, Err(Blocked { retry_after: _ })));
+        assert!(deleted.is_empty());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::extensions::{AccountDeletionConfig, Resolve, TrustedProxies};
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+use blog_server_services::traits::two_factor_service::TwoFactorService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorDeleteRequestData {
+    /// Slug of the current author, typed in as a confirmation.
+    pub slug: String,
+    /// Not required for accounts that don't have a password.
+    pub current_password: Option<String>,
+    /// Current TOTP code or one of the recovery codes, required when 2FA is enabled.
+    pub code: Option<String>,
+}
+
+pub struct AuthorDeleteRequestContent {
+    pub(super) author_delete_data: DResult<AuthorDeleteRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) two_factor_service: Arc<dyn TwoFactorService>,
+    pub(super) login_attempt_service: Arc<dyn LoginAttemptService>,
+    pub(super) account_deletion_config: AccountDeletionConfig,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+    pub(super) client_ip: String,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorDeleteRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn TwoFactorService>>
+        + Resolve<Arc<dyn LoginAttemptService>>
+        + Resolve<AccountDeletionConfig>
+        + Resolve<Arc<TrustedProxies>>,
+{
+    type Data = AuthorDeleteRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            author_delete_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            two_factor_service: origin_content.extensions.resolve(),
+            login_attempt_service: origin_content.extensions.resolve(),
+            account_deletion_config: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+            client_ip: auth::client_ip(
+                &origin_content.http_parts,
+                &origin_content.remote_addr,
+                origin_content.extensions.resolve(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorDeleteResponseContentFailure {
+    Unauthorized { reason: String },
+    ParamsDecodeError { reason: String },
+    SlugMismatch,
+    ReassignTargetDeleted,
+    WrongPassword,
+    WrongCode,
+    Blocked { retry_after: u64 },
+    DatabaseError { reason: String },
+    PasswordVerificationError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorDeleteResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorDeleteResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorDeleteResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorDeleteResponseContentFailure::SlugMismatch => &StatusCode::BAD_REQUEST,
+            AuthorDeleteResponseContentFailure::ReassignTargetDeleted => &StatusCode::CONFLICT,
+            AuthorDeleteResponseContentFailure::WrongPassword => &StatusCode::FORBIDDEN,
+            AuthorDeleteResponseContentFailure::WrongCode => &StatusCode::FORBIDDEN,
+            AuthorDeleteResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
+            AuthorDeleteResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            AuthorDeleteResponseContentFailure::PasswordVerificationError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorDeleteResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorDeleteResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_DELETE_UNAUTHORIZED"
+            }
+            AuthorDeleteResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "AUTHOR_DELETE_PARAMS_DECODE_ERROR"
+            }
+            AuthorDeleteResponseContentFailure::SlugMismatch => "AUTHOR_DELETE_SLUG_MISMATCH",
+            AuthorDeleteResponseContentFailure::ReassignTargetDeleted => {
+                "AUTHOR_DELETE_REASSIGN_TARGET_DELETED"
+            }
+            AuthorDeleteResponseContentFailure::WrongPassword => "AUTHOR_DELETE_WRONG_PASSWORD",
+            AuthorDeleteResponseContentFailure::WrongCode => "AUTHOR_DELETE_WRONG_CODE",
+            AuthorDeleteResponseContentFailure::Blocked { retry_after: _ } => {
+                "AUTHOR_DELETE_BLOCKED"
+            }
+            AuthorDeleteResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_DELETE_DATABASE_ERROR"
+            }
+            AuthorDeleteResponseContentFailure::PasswordVerificationError { reason: _ } => {
+                "AUTHOR_DELETE_PASSWORD_VERIFICATION_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorDeleteResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorDeleteResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            AuthorDeleteResponseContentFailure::SlugMismatch => {
+                "slug doesn't match the author".to_string()
+            }
+            AuthorDeleteResponseContentFailure::ReassignTargetDeleted => {
+                "posts can't be reassigned to the author being deleted".to_string()
+            }
+            AuthorDeleteResponseContentFailure::WrongPassword => {
+                "wrong current password passed to request".to_string()
+            }
+            AuthorDeleteResponseContentFailure::WrongCode => {
+                "wrong two-factor code passed to request".to_string()
+            }
+            AuthorDeleteResponseContentFailure::Blocked { retry_after } => {
+                format!("too many wrong attempts, retry in {} seconds", retry_after)
+            }
+            AuthorDeleteResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            AuthorDeleteResponseContentFailure::PasswordVerificationError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("password verification error: {}", reason)
+                } else {
+                    "internal password verification error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AuthorDeleteResponseContentSuccess;
+
+impl ApiResponseContentBase for AuthorDeleteResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorDeleteResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_DELETE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("author deleted".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
@@ -0,0 +1,120 @@
+use blog_server_services::traits::author_service::AuthorView;
+use blog_server_services::traits::post_service::{PostsQuery, PostsQueryAnswer};
+use blog_server_services::utils::time_utils;
+
+use super::request_content::AuthorExportRequestContent;
+use super::response_content_failure::AuthorExportResponseContentFailure;
+use super::response_content_failure::AuthorExportResponseContentFailure::*;
+use super::response_content_success::*;
+
+/// Posts are read in pages of this size until the cursor runs out.
+const POSTS_PAGE_LIMIT: u64 = 100;
+
+pub async fn http_handler(
+    (AuthorExportRequestContent {
+        post_service,
+        comment_service,
+        follow_service,
+        notification_preference_service,
+        email_subscription_service,
+        identity_service,
+        auth_session_future,
+    },): (AuthorExportRequestContent,),
+) -> Result<AuthorExportResponseContentSuccess, AuthorExportResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    let mut posts = vec![];
+    let mut cursor = None;
+    loop {
+        let PostsQueryAnswer {
+            posts: page,
+            next_cursor,
+            ..
+        } = post_service
+            .posts(
+                PostsQuery::cursor_and_limit(cursor, &POSTS_PAGE_LIMIT).author_id(Some(&author.id)),
+            )
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+        posts.extend(page.into_iter().map(ExportedPost::from));
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    let comments = comment_service
+        .comments_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let follows = ExportedFollows {
+        author_ids: follow_service
+            .followed_author_ids(&author.id)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?,
+        tag_ids: follow_service
+            .followed_tag_ids(&author.id)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?,
+    };
+
+    let notification_preferences = notification_preference_service
+        .notification_preferences_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let email_subscription = match &author.base.email {
+        Some(email) => email_subscription_service
+            .email_subscription_by_email(&email.to_lowercase())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?,
+        None => None,
+    };
+
+    let identities = identity_service
+        .identities_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let telegram_subscribed = author.base.notification_subscribed.unwrap_or_default() == 1;
+    let profile = author.profile();
+    let email_public = author.base.email_public == 1;
+    let mobile_public = author.base.mobile_public == 1;
+
+    Ok(AuthorExportContainer {
+        author: ExportedAuthor {
+            author: author.entity(AuthorView::Owner),
+            profile,
+            email_public,
+            mobile_public,
+        },
+        posts,
+        comments: comments.into_iter().map(ExportedComment::from).collect(),
+        follows,
+        notification_preferences: (notification_preferences, telegram_subscribed).into(),
+        email_subscription: email_subscription.map(ExportedEmailSubscription::from),
+        identities: identities.into_iter().map(ExportedIdentity::from).collect(),
+        exported_at: time_utils::now_as_secs(),
+    }
+    .into())
+}
@@ -0,0 +1,50 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::comment_service::CommentService;
+use blog_server_services::traits::email_subscription_service::EmailSubscriptionService;
+use blog_server_services::traits::follow_service::FollowService;
+use blog_server_services::traits::identity_service::IdentityService;
+use blog_server_services::traits::notification_preference_service::NotificationPreferenceService;
+use blog_server_services::traits::post_service::PostService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AuthorExportRequestContent {
+    pub(super) post_service: Arc<dyn PostService>,
+    pub(super) comment_service: Arc<dyn CommentService>,
+    pub(super) follow_service: Arc<dyn FollowService>,
+    pub(super) notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    pub(super) email_subscription_service: Arc<dyn EmailSubscriptionService>,
+    pub(super) identity_service: Arc<dyn IdentityService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorExportRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn PostService>>
+        + Resolve<Arc<dyn CommentService>>
+        + Resolve<Arc<dyn FollowService>>
+        + Resolve<Arc<dyn NotificationPreferenceService>>
+        + Resolve<Arc<dyn EmailSubscriptionService>>
+        + Resolve<Arc<dyn IdentityService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            post_service: origin_content.extensions.resolve(),
+            comment_service: origin_content.extensions.resolve(),
+            follow_service: origin_content.extensions.resolve(),
+            notification_preference_service: origin_content.extensions.resolve(),
+            email_subscription_service: origin_content.extensions.resolve(),
+            identity_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
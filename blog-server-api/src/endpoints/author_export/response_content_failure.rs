@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorExportResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorExportResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorExportResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorExportResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorExportResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorExportResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_EXPORT_UNAUTHORIZED"
+            }
+            AuthorExportResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_EXPORT_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorExportResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorExportResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
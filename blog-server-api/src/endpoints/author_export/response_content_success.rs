@@ -0,0 +1,192 @@
+use blog_generic::entities::{Author as EAuthor, PublishType};
+use blog_server_services::traits::author_service::AuthorProfile;
+use blog_server_services::traits::comment_service::Comment;
+use blog_server_services::traits::email_subscription_service::EmailSubscription;
+use blog_server_services::traits::identity_service::AuthorIdentity;
+use blog_server_services::traits::notification_preference_service::{
+    DigestFrequency, NotificationChannel, NotificationEvent, NotificationPreferences,
+};
+use blog_server_services::traits::post_service::Post;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedAuthor {
+    #[serde(flatten)]
+    pub author: EAuthor,
+    #[serde(flatten)]
+    pub profile: AuthorProfile,
+    pub email_public: bool,
+    pub mobile_public: bool,
+}
+
+/// Posts of every publish type, with the stored content rather than the rendered one.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedPost {
+    pub id: u64,
+    pub title: String,
+    pub slug: String,
+    pub summary: String,
+    pub content: Option<String>,
+    pub image_url: Option<String>,
+    pub publish_type: PublishType,
+    pub lang: Option<String>,
+    pub tags: Vec<String>,
+    pub created_at: u64,
+}
+
+impl From<Post> for ExportedPost {
+    fn from(post: Post) -> Self {
+        ExportedPost {
+            id: post.id,
+            title: post.base.title,
+            slug: post.base.slug,
+            summary: post.base.summary,
+            content: post.base.content,
+            image_url: post.base.image_url,
+            publish_type: post.base.publish_type,
+            lang: post.base.lang,
+            tags: post.tags.into_iter().map(|t| t.title).collect(),
+            created_at: post.base.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedComment {
+    pub id: u64,
+    pub post_id: u64,
+    pub content: String,
+    pub published: bool,
+    pub created_at: u64,
+}
+
+impl From<Comment> for ExportedComment {
+    fn from(comment: Comment) -> Self {
+        ExportedComment {
+            id: comment.id,
+            post_id: comment.base.post_id,
+            content: comment.base.content,
+            published: comment.base.published == 1,
+            created_at: comment.base.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedFollows {
+    pub author_ids: Vec<u64>,
+    pub tag_ids: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedNotificationPreferences {
+    pub telegram: Vec<NotificationEvent>,
+    pub email: Vec<NotificationEvent>,
+    pub in_app: Vec<NotificationEvent>,
+    pub digest: DigestFrequency,
+    /// The bot-wide Telegram switch.
+    pub telegram_subscribed: bool,
+}
+
+impl From<(NotificationPreferences, bool)> for ExportedNotificationPreferences {
+    fn from((preferences, telegram_subscribed): (NotificationPreferences, bool)) -> Self {
+        ExportedNotificationPreferences {
+            telegram: preferences.events(NotificationChannel::Telegram),
+            email: preferences.events(NotificationChannel::Email),
+            in_app: preferences.events(NotificationChannel::InApp),
+            digest: preferences.digest_frequency(),
+            telegram_subscribed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedEmailSubscription {
+    pub email: String,
+    pub frequency: DigestFrequency,
+    pub confirmed: bool,
+    pub created_at: u64,
+}
+
+impl From<EmailSubscription> for ExportedEmailSubscription {
+    fn from(subscription: EmailSubscription) -> Self {
+        ExportedEmailSubscription {
+            email: subscription.base.email,
+            frequency: subscription.base.frequency,
+            confirmed: subscription.base.confirmed == 1,
+            created_at: subscription.base.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedIdentity {
+    pub provider: String,
+    pub subject: String,
+    pub created_at: u64,
+}
+
+impl From<AuthorIdentity> for ExportedIdentity {
+    fn from(identity: AuthorIdentity) -> Self {
+        ExportedIdentity {
+            provider: identity.base.provider,
+            subject: identity.base.subject,
+            created_at: identity.base.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorExportContainer {
+    pub author: ExportedAuthor,
+    pub posts: Vec<ExportedPost>,
+    pub comments: Vec<ExportedComment>,
+    pub follows: ExportedFollows,
+    pub notification_preferences: ExportedNotificationPreferences,
+    pub email_subscription: Option<ExportedEmailSubscription>,
+    pub identities: Vec<ExportedIdentity>,
+    pub exported_at: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorExportResponseContentSuccess {
+    container: AuthorExportContainer,
+}
+
+impl Into<AuthorExportResponseContentSuccess> for AuthorExportContainer {
+    fn into(self) -> AuthorExportResponseContentSuccess {
+        AuthorExportResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for AuthorExportResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorExportResponseContentSuccess {
+    type Data = AuthorExportContainer;
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_EXPORT_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("author data exported".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
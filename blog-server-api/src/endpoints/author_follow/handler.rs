@@ -0,0 +1,63 @@
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::AuthorFollowRequestContent;
+use super::response_content_failure::AuthorFollowResponseContentFailure;
+use super::response_content_failure::AuthorFollowResponseContentFailure::*;
+use super::response_content_success::AuthorFollowResponseContentSuccess;
+
+pub async fn http_handler_follow(
+    (request_content,): (AuthorFollowRequestContent,),
+) -> Result<AuthorFollowResponseContentSuccess, AuthorFollowResponseContentFailure> {
+    http_handler(request_content, true).await
+}
+
+pub async fn http_handler_unfollow(
+    (request_content,): (AuthorFollowRequestContent,),
+) -> Result<AuthorFollowResponseContentSuccess, AuthorFollowResponseContentFailure> {
+    http_handler(request_content, false).await
+}
+
+async fn http_handler(
+    AuthorFollowRequestContent {
+        id,
+        author_service,
+        follow_service,
+        auth_author_future,
+    }: AuthorFollowRequestContent,
+    follow: bool,
+) -> Result<AuthorFollowResponseContentSuccess, AuthorFollowResponseContentFailure> {
+    let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
+        reason: e.to_string(),
+    })?;
+
+    let follower = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    if !follower.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    if follower.id == id {
+        return Err(SelfFollow);
+    }
+
+    if follow {
+        author_service
+            .author_by_id(&id)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?
+            .ok_or(NotFound)?;
+
+        follow_service.follow_author(&follower.id, &id).await
+    } else {
+        follow_service.unfollow_author(&follower.id, &id).await
+    }
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(AuthorFollowResponseContentSuccess)
+}
@@ -0,0 +1,41 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
+use blog_server_services::traits::follow_service::FollowService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AuthorFollowRequestContent {
+    pub(super) id: String,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) follow_service: Arc<dyn FollowService>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorFollowRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn FollowService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            id: origin_content
+                .path
+                .get("id")
+                .map(|n| n.to_owned())
+                .unwrap_or_default(),
+            author_service: origin_content.extensions.resolve(),
+            follow_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorFollowResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    IncorrectIdFormat { reason: String },
+    NotFound,
+    SelfFollow,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorFollowResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorFollowResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorFollowResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AuthorFollowResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorFollowResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            AuthorFollowResponseContentFailure::SelfFollow => &StatusCode::BAD_REQUEST,
+            AuthorFollowResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorFollowResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorFollowResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_FOLLOW_UNAUTHORIZED"
+            }
+            AuthorFollowResponseContentFailure::Forbidden => "AUTHOR_FOLLOW_FORBIDDEN",
+            AuthorFollowResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                "AUTHOR_FOLLOW_INCORRECT_ID_FORMAT"
+            }
+            AuthorFollowResponseContentFailure::NotFound => "AUTHOR_FOLLOW_NOT_FOUND",
+            AuthorFollowResponseContentFailure::SelfFollow => "AUTHOR_FOLLOW_SELF_FOLLOW",
+            AuthorFollowResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_FOLLOW_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorFollowResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorFollowResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            AuthorFollowResponseContentFailure::IncorrectIdFormat { reason } => {
+                format!("incorrect value provided for author ID: {}", reason)
+            }
+            AuthorFollowResponseContentFailure::NotFound => "author not found".to_string(),
+            AuthorFollowResponseContentFailure::SelfFollow => {
+                "authors can't follow themselves".to_string()
+            }
+            AuthorFollowResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
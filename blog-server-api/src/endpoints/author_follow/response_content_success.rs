@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AuthorFollowResponseContentSuccess;
+
+impl ApiResponseContentBase for AuthorFollowResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorFollowResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_FOLLOW_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("author follow state updated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
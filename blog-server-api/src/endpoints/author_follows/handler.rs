@@ -0,0 +1,37 @@
+use super::request_content::AuthorFollowsRequestContent;
+use super::response_content_failure::AuthorFollowsResponseContentFailure;
+use super::response_content_failure::AuthorFollowsResponseContentFailure::*;
+use super::response_content_success::{
+    AuthorFollowsContainer, AuthorFollowsResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (AuthorFollowsRequestContent {
+        follow_service,
+        auth_author_future,
+    },): (AuthorFollowsRequestContent,),
+) -> Result<AuthorFollowsResponseContentSuccess, AuthorFollowsResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let author_ids = follow_service
+        .followed_author_ids(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let tag_ids = follow_service
+        .followed_tag_ids(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(AuthorFollowsContainer {
+        author_ids,
+        tag_ids,
+    }
+    .into())
+}
@@ -0,0 +1,33 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
+use blog_server_services::traits::follow_service::FollowService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AuthorFollowsRequestContent {
+    pub(super) follow_service: Arc<dyn FollowService>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorFollowsRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn FollowService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            follow_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorFollowsResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorFollowsResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorFollowsResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorFollowsResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorFollowsResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorFollowsResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_FOLLOWS_UNAUTHORIZED"
+            }
+            AuthorFollowsResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_FOLLOWS_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorFollowsResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorFollowsResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+/// Followed authors and tags, most recently followed first.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorFollowsContainer {
+    pub author_ids: Vec<u64>,
+    pub tag_ids: Vec<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorFollowsResponseContentSuccess {
+    container: AuthorFollowsContainer,
+}
+
+impl Into<AuthorFollowsResponseContentSuccess> for AuthorFollowsContainer {
+    fn into(self) -> AuthorFollowsResponseContentSuccess {
+        AuthorFollowsResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for AuthorFollowsResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorFollowsResponseContentSuccess {
+    type Data = AuthorFollowsContainer;
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_FOLLOWS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("followed authors and tags returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
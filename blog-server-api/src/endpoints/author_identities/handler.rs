@@ -0,0 +1,27 @@
+use super::request_content::AuthorIdentitiesRequestContent;
+use super::response_content_failure::AuthorIdentitiesResponseContentFailure;
+use super::response_content_failure::AuthorIdentitiesResponseContentFailure::*;
+use super::response_content_success::{AuthorIdentitiesResponseContentSuccess, LinkedIdentities};
+
+pub async fn http_handler(
+    (AuthorIdentitiesRequestContent {
+        identity_service,
+        auth_author_future,
+    },): (AuthorIdentitiesRequestContent,),
+) -> Result<AuthorIdentitiesResponseContentSuccess, AuthorIdentitiesResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let identities = identity_service
+        .identities_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(LinkedIdentities {
+        identities: identities.into_iter().map(|i| i.into()).collect(),
+    }
+    .into())
+}
@@ -0,0 +1,33 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
+use blog_server_services::traits::identity_service::IdentityService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AuthorIdentitiesRequestContent {
+    pub(super) identity_service: Arc<dyn IdentityService>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorIdentitiesRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn IdentityService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            identity_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorIdentitiesResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorIdentitiesResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorIdentitiesResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorIdentitiesResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorIdentitiesResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorIdentitiesResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_IDENTITIES_UNAUTHORIZED"
+            }
+            AuthorIdentitiesResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_IDENTITIES_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorIdentitiesResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorIdentitiesResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
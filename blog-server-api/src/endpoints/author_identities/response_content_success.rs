@@ -0,0 +1,63 @@
+use blog_server_services::traits::identity_service::AuthorIdentity;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedIdentity {
+    pub id: u64,
+    pub provider: String,
+    pub subject: String,
+    pub created_at: u64,
+}
+
+impl From<AuthorIdentity> for LinkedIdentity {
+    fn from(value: AuthorIdentity) -> Self {
+        LinkedIdentity {
+            id: value.id,
+            provider: value.base.provider,
+            subject: value.base.subject,
+            created_at: value.base.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedIdentities {
+    pub identities: Vec<LinkedIdentity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorIdentitiesResponseContentSuccess {
+    identities: LinkedIdentities,
+}
+
+impl Into<AuthorIdentitiesResponseContentSuccess> for LinkedIdentities {
+    fn into(self) -> AuthorIdentitiesResponseContentSuccess {
+        AuthorIdentitiesResponseContentSuccess { identities: self }
+    }
+}
+
+impl ApiResponseContentBase for AuthorIdentitiesResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorIdentitiesResponseContentSuccess {
+    type Data = LinkedIdentities;
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_IDENTITIES_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("linked identities returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.identities
+    }
+}
@@ -0,0 +1,271 @@
+use blog_server_services::traits::author_service::{Author, Capability};
+use blog_server_services::traits::social_service::{IdentityLink, SocialService};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+use super::request_content::*;
+use super::response_content_failure::AuthorIdentityLinkResponseContentFailure;
+use super::response_content_failure::AuthorIdentityLinkResponseContentFailure::*;
+use super::response_content_success::AuthorIdentityLinkResponseContentSuccess;
+
+use crate::utils::*;
+
+pub async fn http_handler_oidc(
+    (AuthorIdentityLinkOidcRequestContent {
+        provider,
+        oidc_callback,
+        oidc_service,
+        social_service,
+        auth_session_future,
+    },): (AuthorIdentityLinkOidcRequestContent,),
+) -> Result<AuthorIdentityLinkResponseContentSuccess, AuthorIdentityLinkResponseContentFailure> {
+    let oidc_callback = oidc_callback.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let author = authorized_author(auth_session_future).await?;
+
+    let social_author = social_auth::oidc(provider, oidc_callback, oidc_service).await;
+
+    link(author, social_author, social_service).await
+}
+
+#[cfg(feature = "yandex")]
+pub async fn http_handler_yandex(
+    (AuthorIdentityLinkYandexRequestContent {
+        login_yandex_question,
+        social_service,
+        auth_session_future,
+    },): (AuthorIdentityLinkYandexRequestContent,),
+) -> Result<AuthorIdentityLinkResponseContentSuccess, AuthorIdentityLinkResponseContentFailure> {
+    let login_yandex_question = login_yandex_question.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let author = authorized_author(auth_session_future).await?;
+
+    let social_author = social_auth::yandex(login_yandex_question).await;
+
+    link(author, social_author, social_service).await
+}
+
+#[cfg(feature = "telegram")]
+pub async fn http_handler_telegram(
+    (AuthorIdentityLinkTelegramRequestContent {
+        login_telegram_question,
+        social_service,
+        auth_session_future,
+    },): (AuthorIdentityLinkTelegramRequestContent,),
+) -> Result<AuthorIdentityLinkResponseContentSuccess, AuthorIdentityLinkResponseContentFailure> {
+    let login_telegram_question = login_telegram_question.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let author = authorized_author(auth_session_future).await?;
+
+    let social_author = social_auth::telegram(login_telegram_question);
+
+    link(author, social_author, social_service).await
+}
+
+/// Linked identities log in as the author, so only interactive sessions can add
+/// or remove them, not access tokens.
+async fn authorized_author(
+    auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+) -> Result<Author, AuthorIdentityLinkResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    Ok(author)
+}
+
+async fn link(
+    author: Author,
+    social_author: Result<social_auth::SocialAuthor, social_auth::Error>,
+    social_service: Arc<dyn SocialService>,
+) -> Result<AuthorIdentityLinkResponseContentSuccess, AuthorIdentityLinkResponseContentFailure> {
+    let social_author = social_author.map_err(|e| match e {
+        social_auth::Error::ProviderNotFound => ProviderNotFound,
+        social_auth::Error::InvalidFlow => InvalidFlow,
+        social_auth::Error::Provider(reason) => ProviderError { reason },
+    })?;
+
+    let identity_link = social_service
+        .link_identity(&author, &social_author.social_id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    match identity_link {
+        IdentityLink::Linked | IdentityLink::AlreadyLinked => {
+            Ok(AuthorIdentityLinkResponseContentSuccess)
+        }
+        IdentityLink::LinkedToAnotherAuthor { author_id: _ } => Err(LinkedToAnotherAuthor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::traits::author_service::{AuthorRole, BaseAuthor, BaseMinimalAuthor};
+    use blog_server_services::traits::identity_service::AuthorIdentity;
+    use blog_server_services::traits::social_service::SocialId;
+    use screw_components::dyn_result::DResult;
+
+    enum MockLink {
+        Linked,
+        LinkedToAnotherAuthor,
+    }
+
+    struct MockSocialService {
+        link: MockLink,
+    }
+
+    #[async_trait]
+    impl SocialService for MockSocialService {
+        async fn set_subscribe_for_author(&self, _author: &Author, _subscribe: &u8) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn process_auth_by_id(
+            &self,
+            _social_id: &SocialId,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<Author> {
+            unimplemented!()
+        }
+
+        async fn link_identity(
+            &self,
+            _author: &Author,
+            _social_id: &SocialId,
+        ) -> DResult<IdentityLink> {
+            Ok(match self.link {
+                MockLink::Linked => IdentityLink::Linked,
+                MockLink::LinkedToAnotherAuthor => {
+                    IdentityLink::LinkedToAnotherAuthor { author_id: 2 }
+                }
+            })
+        }
+
+        async fn unlink_identity(
+            &self,
+            _author: &Author,
+            _identity: &AuthorIdentity,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_author(password_hash: Option<String>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    fn social_author() -> Result<social_auth::SocialAuthor, social_auth::Error> {
+        Ok(social_auth::SocialAuthor {
+            social_id: SocialId::Identity {
+                provider: "google".into(),
+                subject: "42".into(),
+            },
+            base_minimal_author: BaseMinimalAuthor {
+                slug: "john".into(),
+                first_name: None,
+                last_name: None,
+                image_url: None,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn identity_is_linked() {
+        let result = link(
+            sample_author(None),
+            social_author(),
+            Arc::new(MockSocialService {
+                link: MockLink::Linked,
+            }),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn identity_of_another_author_is_not_linked() {
+        let result = link(
+            sample_author(None),
+            social_author(),
+            Arc::new(MockSocialService {
+                link: MockLink::LinkedToAnotherAuthor,
+            }),
+        )
+        .await;
+        assert!(matches!(result, Err(LinkedToAnotherAuthor)));
+    }
+
+    #[tokio::test]
+    async fn failed_provider_flow_links_nothing() {
+        let result = link(
+            sample_author(None),
+            Err(social_auth::Error::InvalidFlow),
+            Arc::new(MockSocialService {
+                link: MockLink::Linked,
+            }),
+        )
+        .await;
+        assert!(matches!(result, Err(InvalidFlow)));
+    }
+
+    #[tokio::test]
+    async fn blocked_author_links_nothing() {
+        let mut author = sample_author(None);
+        author.base.blocked = 1;
+        let result = authorized_author(Box::pin(async move {
+            Ok(auth::AuthSession {
+                author,
+                session_id: 1,
+                authenticated_at: 0,
+                expires_at: u64::MAX,
+            })
+        }))
+        .await;
+        assert!(matches!(result, Err(Forbidden)));
+    }
+}
@@ -0,0 +1,10 @@
+mod handler;
+mod request_content;
+mod response_content_failure;
+mod response_content_success;
+
+pub use handler::http_handler_oidc;
+#[cfg(feature = "telegram")]
+pub use handler::http_handler_telegram;
+#[cfg(feature = "yandex")]
+pub use handler::http_handler_yandex;
@@ -0,0 +1,88 @@
+use crate::extensions::Resolve;
+use crate::utils::{auth, oidc};
+use blog_generic::entities::{LoginTelegramQuestion, LoginYandexQuestion};
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::oidc_service::OidcService;
+use blog_server_services::traits::social_service::SocialService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use screw_components::dyn_result::DResult;
+use std::sync::Arc;
+
+pub struct AuthorIdentityLinkOidcRequestContent {
+    pub(super) provider: Option<String>,
+    pub(super) oidc_callback: DResult<oidc::Callback>,
+    pub(super) oidc_service: Arc<dyn OidcService>,
+    pub(super) social_service: Arc<dyn SocialService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorIdentityLinkOidcRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn OidcService>>
+        + Resolve<Arc<dyn SocialService>>,
+{
+    type Data = oidc::Callback;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            provider: origin_content.path.get("provider").map(|n| n.to_owned()),
+            oidc_callback: origin_content.data_result,
+            oidc_service: origin_content.extensions.resolve(),
+            social_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
+
+pub struct AuthorIdentityLinkYandexRequestContent {
+    pub(super) login_yandex_question: DResult<LoginYandexQuestion>,
+    pub(super) social_service: Arc<dyn SocialService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorIdentityLinkYandexRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn SocialService>>,
+{
+    type Data = LoginYandexQuestion;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            login_yandex_question: origin_content.data_result,
+            social_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
+
+pub struct AuthorIdentityLinkTelegramRequestContent {
+    pub(super) login_telegram_question: DResult<LoginTelegramQuestion>,
+    pub(super) social_service: Arc<dyn SocialService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorIdentityLinkTelegramRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn SocialService>>,
+{
+    type Data = LoginTelegramQuestion;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            login_telegram_question: origin_content.data_result,
+            social_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorIdentityLinkResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    Unauthorized { reason: String },
+    Forbidden,
+    ProviderNotFound,
+    InvalidFlow,
+    ProviderError { reason: String },
+    LinkedToAnotherAuthor,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorIdentityLinkResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorIdentityLinkResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorIdentityLinkResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorIdentityLinkResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AuthorIdentityLinkResponseContentFailure::ProviderNotFound => &StatusCode::NOT_FOUND,
+            AuthorIdentityLinkResponseContentFailure::InvalidFlow => &StatusCode::BAD_REQUEST,
+            AuthorIdentityLinkResponseContentFailure::ProviderError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorIdentityLinkResponseContentFailure::LinkedToAnotherAuthor => {
+                &StatusCode::CONFLICT
+            }
+            AuthorIdentityLinkResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorIdentityLinkResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorIdentityLinkResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "AUTHOR_IDENTITY_LINK_PARAMS_ERROR"
+            }
+            AuthorIdentityLinkResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_IDENTITY_LINK_UNAUTHORIZED"
+            }
+            AuthorIdentityLinkResponseContentFailure::Forbidden => "AUTHOR_IDENTITY_LINK_FORBIDDEN",
+            AuthorIdentityLinkResponseContentFailure::ProviderNotFound => {
+                "AUTHOR_IDENTITY_LINK_PROVIDER_NOT_FOUND"
+            }
+            AuthorIdentityLinkResponseContentFailure::InvalidFlow => {
+                "AUTHOR_IDENTITY_LINK_INVALID_FLOW"
+            }
+            AuthorIdentityLinkResponseContentFailure::ProviderError { reason: _ } => {
+                "AUTHOR_IDENTITY_LINK_PROVIDER_ERROR"
+            }
+            AuthorIdentityLinkResponseContentFailure::LinkedToAnotherAuthor => {
+                "AUTHOR_IDENTITY_LINK_LINKED_TO_ANOTHER_AUTHOR"
+            }
+            AuthorIdentityLinkResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_IDENTITY_LINK_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorIdentityLinkResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            AuthorIdentityLinkResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorIdentityLinkResponseContentFailure::Forbidden => {
+                "insufficient rights".to_string()
+            }
+            AuthorIdentityLinkResponseContentFailure::ProviderNotFound => {
+                "unknown login provider".to_string()
+            }
+            AuthorIdentityLinkResponseContentFailure::InvalidFlow => {
+                "login flow expired or doesn't match the callback".to_string()
+            }
+            AuthorIdentityLinkResponseContentFailure::ProviderError { reason } => {
+                format!("provider error: {}", reason)
+            }
+            AuthorIdentityLinkResponseContentFailure::LinkedToAnotherAuthor => {
+                "identity belongs to another author, ask an editor to merge the accounts"
+                    .to_string()
+            }
+            AuthorIdentityLinkResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
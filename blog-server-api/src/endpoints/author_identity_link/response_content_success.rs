@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AuthorIdentityLinkResponseContentSuccess;
+
+impl ApiResponseContentBase for AuthorIdentityLinkResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorIdentityLinkResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_IDENTITY_LINK_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("identity linked to the current author".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
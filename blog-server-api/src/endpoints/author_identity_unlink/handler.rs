@@ -0,0 +1,254 @@
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::AuthorIdentityUnlinkRequestContent;
+use super::response_content_failure::AuthorIdentityUnlinkResponseContentFailure;
+use super::response_content_failure::AuthorIdentityUnlinkResponseContentFailure::*;
+use super::response_content_success::AuthorIdentityUnlinkResponseContentSuccess;
+
+pub async fn http_handler(
+    (AuthorIdentityUnlinkRequestContent {
+        id,
+        identity_service,
+        social_service,
+        auth_session_future,
+    },): (AuthorIdentityUnlinkRequestContent,),
+) -> Result<AuthorIdentityUnlinkResponseContentSuccess, AuthorIdentityUnlinkResponseContentFailure>
+{
+    let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
+        reason: e.to_string(),
+    })?;
+
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    let identities = identity_service
+        .identities_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let identity = identities.iter().find(|i| i.id == id).ok_or(NotFound)?;
+
+    if author.base.password_hash.is_none() && identities.len() <= 1 {
+        return Err(LastLoginMethod);
+    }
+
+    social_service
+        .unlink_identity(&author, identity)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(AuthorIdentityUnlinkResponseContentSuccess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::request_content::AuthorIdentityUnlinkRequestContent;
+    use super::*;
+    use crate::utils::auth;
+    use async_trait::async_trait;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, BaseAuthor, BaseMinimalAuthor,
+    };
+    use blog_server_services::traits::identity_service::{
+        AuthorIdentity, BaseAuthorIdentity, IdentityService,
+    };
+    use blog_server_services::traits::social_service::{IdentityLink, SocialId, SocialService};
+    use screw_components::dyn_result::DResult;
+    use std::sync::{Arc, Mutex};
+
+    struct MockIdentityService {
+        identities: Vec<AuthorIdentity>,
+    }
+
+    #[async_trait]
+    impl IdentityService for MockIdentityService {
+        async fn identity_by_provider_and_subject(
+            &self,
+            _provider: &String,
+            _subject: &String,
+        ) -> DResult<Option<AuthorIdentity>> {
+            unimplemented!()
+        }
+
+        async fn identities_by_author_id(&self, author_id: &u64) -> DResult<Vec<AuthorIdentity>> {
+            Ok(self
+                .identities
+                .iter()
+                .filter(|i| i.base.author_id == *author_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn create_identity(&self, _identity: &BaseAuthorIdentity) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_identity(&self, _id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockSocialService {
+        unlinked: Mutex<Vec<u64>>,
+    }
+
+    #[async_trait]
+    impl SocialService for MockSocialService {
+        async fn set_subscribe_for_author(&self, _author: &Author, _subscribe: &u8) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn process_auth_by_id(
+            &self,
+            _social_id: &SocialId,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<Author> {
+            unimplemented!()
+        }
+
+        async fn link_identity(
+            &self,
+            _author: &Author,
+            _social_id: &SocialId,
+        ) -> DResult<IdentityLink> {
+            unimplemented!()
+        }
+
+        async fn unlink_identity(
+            &self,
+            _author: &Author,
+            identity: &AuthorIdentity,
+        ) -> DResult<()> {
+            self.unlinked.lock().unwrap().push(identity.id);
+            Ok(())
+        }
+    }
+
+    fn sample_author(password_hash: Option<String>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    fn identity(id: u64, author_id: u64) -> AuthorIdentity {
+        AuthorIdentity {
+            id,
+            base: BaseAuthorIdentity {
+                author_id,
+                provider: "google".into(),
+                subject: id.to_string(),
+                created_at: 0,
+            },
+        }
+    }
+
+    async fn unlink(
+        author: Author,
+        id: &str,
+        identities: Vec<AuthorIdentity>,
+    ) -> (
+        Result<
+            AuthorIdentityUnlinkResponseContentSuccess,
+            AuthorIdentityUnlinkResponseContentFailure,
+        >,
+        Vec<u64>,
+    ) {
+        let social_service = Arc::new(MockSocialService {
+            unlinked: Mutex::new(vec![]),
+        });
+        let result = http_handler((AuthorIdentityUnlinkRequestContent {
+            id: id.to_string(),
+            identity_service: Arc::new(MockIdentityService { identities }),
+            social_service: social_service.clone(),
+            auth_session_future: Box::pin(async move {
+                Ok(auth::AuthSession {
+                    author,
+                    session_id: 1,
+                    authenticated_at: 0,
+                    expires_at: u64::MAX,
+                })
+            }),
+        },))
+        .await;
+        let unlinked = social_service.unlinked.lock().unwrap().clone();
+        (result, unlinked)
+    }
+
+    #[tokio::test]
+    async fn identity_is_unlinked() {
+        let (result, unlinked) = unlink(
+            sample_author(None),
+            "10",
+            vec![identity(10, 1), identity(11, 1)],
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(unlinked, vec![10]);
+    }
+
+    #[tokio::test]
+    async fn identity_of_another_author_is_not_found() {
+        let (result, unlinked) = unlink(
+            sample_author(None),
+            "20",
+            vec![identity(10, 1), identity(11, 1), identity(20, 2)],
+        )
+        .await;
+        assert!(matches!(result, Err(NotFound)));
+        assert!(unlinked.is_empty());
+    }
+
+    #[tokio::test]
+    async fn last_login_method_is_kept() {
+        let (result, unlinked) = unlink(sample_author(None), "10", vec![identity(10, 1)]).await;
+        assert!(matches!(result, Err(LastLoginMethod)));
+        assert!(unlinked.is_empty());
+
+        let (result, unlinked) = unlink(
+            sample_author(Some("hash".into())),
+            "10",
+            vec![identity(10, 1)],
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(unlinked, vec![10]);
+    }
+}
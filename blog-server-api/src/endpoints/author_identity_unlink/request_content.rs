@@ -0,0 +1,40 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::identity_service::IdentityService;
+use blog_server_services::traits::social_service::SocialService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use std::sync::Arc;
+
+pub struct AuthorIdentityUnlinkRequestContent {
+    pub(super) id: String,
+    pub(super) identity_service: Arc<dyn IdentityService>,
+    pub(super) social_service: Arc<dyn SocialService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorIdentityUnlinkRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn IdentityService>>
+        + Resolve<Arc<dyn SocialService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            id: origin_content
+                .path
+                .get("id")
+                .map(|n| n.to_owned())
+                .unwrap_or_default(),
+            identity_service: origin_content.extensions.resolve(),
+            social_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorIdentityUnlinkResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    IncorrectIdFormat { reason: String },
+    NotFound,
+    LastLoginMethod,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorIdentityUnlinkResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorIdentityUnlinkResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorIdentityUnlinkResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AuthorIdentityUnlinkResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorIdentityUnlinkResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            AuthorIdentityUnlinkResponseContentFailure::LastLoginMethod => &StatusCode::CONFLICT,
+            AuthorIdentityUnlinkResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorIdentityUnlinkResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorIdentityUnlinkResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_IDENTITY_UNLINK_UNAUTHORIZED"
+            }
+            AuthorIdentityUnlinkResponseContentFailure::Forbidden => {
+                "AUTHOR_IDENTITY_UNLINK_FORBIDDEN"
+            }
+            AuthorIdentityUnlinkResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                "AUTHOR_IDENTITY_UNLINK_INCORRECT_ID_FORMAT"
+            }
+            AuthorIdentityUnlinkResponseContentFailure::NotFound => {
+                "AUTHOR_IDENTITY_UNLINK_NOT_FOUND"
+            }
+            AuthorIdentityUnlinkResponseContentFailure::LastLoginMethod => {
+                "AUTHOR_IDENTITY_UNLINK_LAST_LOGIN_METHOD"
+            }
+            AuthorIdentityUnlinkResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_IDENTITY_UNLINK_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorIdentityUnlinkResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorIdentityUnlinkResponseContentFailure::Forbidden => {
+                "insufficient rights".to_string()
+            }
+            AuthorIdentityUnlinkResponseContentFailure::IncorrectIdFormat { reason } => {
+                format!("incorrect value provided for identity ID: {}", reason)
+            }
+            AuthorIdentityUnlinkResponseContentFailure::NotFound => {
+                "identity not found".to_string()
+            }
+            AuthorIdentityUnlinkResponseContentFailure::LastLoginMethod => {
+                "the only way to log in can't be unlinked, set a password first".to_string()
+            }
+            AuthorIdentityUnlinkResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
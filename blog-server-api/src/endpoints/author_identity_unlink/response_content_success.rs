@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AuthorIdentityUnlinkResponseContentSuccess;
+
+impl ApiResponseContentBase for AuthorIdentityUnlinkResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorIdentityUnlinkResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_IDENTITY_UNLINK_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("identity unlinked".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
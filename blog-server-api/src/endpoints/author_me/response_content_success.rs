@@ -1,18 +1,47 @@
-use blog_generic::entities::AuthorContainer;
-use blog_server_services::traits::author_service::Author as ServiceAuthor;
+use blog_generic::entities::Author as EAuthor;
+use blog_server_services::traits::author_service::{
+    Author as ServiceAuthor, AuthorProfile, AuthorView,
+};
 use hyper::StatusCode;
 use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+/// The owner view of the author entity with the privacy flags and the extended profile.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfAuthor {
+    #[serde(flatten)]
+    pub author: EAuthor,
+    #[serde(flatten)]
+    pub profile: AuthorProfile,
+    pub email_public: bool,
+    pub mobile_public: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfAuthorContainer {
+    pub author: SelfAuthor,
+}
 
 #[derive(Debug, Clone)]
 pub struct AuthorMeResponseContentSuccess {
-    container: AuthorContainer,
+    container: SelfAuthorContainer,
 }
 
 impl Into<AuthorMeResponseContentSuccess> for ServiceAuthor {
     fn into(self) -> AuthorMeResponseContentSuccess {
+        let profile = self.profile();
+        let email_public = self.base.email_public == 1;
+        let mobile_public = self.base.mobile_public == 1;
+        let author = self.entity(AuthorView::Owner);
         AuthorMeResponseContentSuccess {
-            container: AuthorContainer {
-                author: self.into(),
+            container: SelfAuthorContainer {
+                author: SelfAuthor {
+                    author,
+                    profile,
+                    email_public,
+                    mobile_public,
+                },
             },
         }
     }
@@ -25,7 +54,7 @@ impl ApiResponseContentBase for AuthorMeResponseContentSuccess {
 }
 
 impl ApiResponseContentSuccess for AuthorMeResponseContentSuccess {
-    type Data = AuthorContainer;
+    type Data = SelfAuthorContainer;
 
     fn identifier(&self) -> &'static str {
         "AUTHOR_ME_OK"
@@ -0,0 +1,357 @@
+use blog_server_services::traits::audit_service::{AuditAction, AuditTarget, BaseAuditRecord};
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::AuthorMergeRequestContent;
+use super::response_content_failure::AuthorMergeResponseContentFailure;
+use super::response_content_failure::AuthorMergeResponseContentFailure::*;
+use super::response_content_success::AuthorMergeResponseContentSuccess;
+
+pub async fn http_handler(
+    (AuthorMergeRequestContent {
+        id,
+        author_merge_data,
+        author_service,
+        auth_author_future,
+    },): (AuthorMergeRequestContent,),
+) -> Result<AuthorMergeResponseContentSuccess, AuthorMergeResponseContentFailure> {
+    let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
+        reason: e.to_string(),
+    })?;
+
+    let target_id = author_merge_data
+        .map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?
+        .target_id;
+
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    if !author.can(Capability::ManageAuthors) {
+        return Err(Forbidden);
+    }
+
+    if id == target_id {
+        return Err(SameAuthor);
+    }
+
+    for author_id in [id, target_id] {
+        author_service
+            .author_by_id(&author_id)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?
+            .ok_or(NotFound)?;
+    }
+
+    author_service
+        .merge_authors(
+            &id,
+            &target_id,
+            &BaseAuditRecord::new(
+                author.id,
+                AuditAction::AuthorsMerged,
+                AuditTarget::Author,
+                id,
+                None,
+                Some(&serde_json::json!({ "merged_into": target_id })),
+            ),
+        )
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(AuthorMergeResponseContentSuccess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::request_content::AuthorMergeRequestData;
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        DeletedAuthorPosts,
+    };
+    use screw_components::dyn_result::DResult;
+    use std::sync::{Arc, Mutex};
+
+    struct MockAuthorService {
+        authors: Vec<Author>,
+        merges: Mutex<Vec<(u64, u64, BaseAuditRecord)>>,
+    }
+
+    #[async_trait]
+    impl AuthorService for MockAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, id: &u64) -> DResult<Option<Author>> {
+            Ok(self.authors.iter().find(|a| a.id == *id).cloned())
+        }
+
+        async fn author_by_slug(&self, _slug: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            source_id: &u64,
+            target_id: &u64,
+            audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            self.merges
+                .lock()
+                .unwrap()
+                .push((*source_id, *target_id, audit_record.clone()));
+            Ok(())
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_author(id: u64, role: AuthorRole) -> Author {
+        Author {
+            id,
+            base: BaseAuthor {
+                slug: format!("author{id}"),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash: None,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    async fn merge(
+        author_service: Arc<MockAuthorService>,
+        actor: Author,
+        id: &str,
+        target_id: u64,
+    ) -> Result<AuthorMergeResponseContentSuccess, AuthorMergeResponseContentFailure> {
+        http_handler((AuthorMergeRequestContent {
+            id: id.to_string(),
+            author_merge_data: Ok(AuthorMergeRequestData { target_id }),
+            author_service,
+            auth_author_future: Box::pin(async move { Ok(actor) }),
+        },))
+        .await
+    }
+
+    fn author_service() -> Arc<MockAuthorService> {
+        Arc::new(MockAuthorService {
+            authors: vec![
+                sample_author(1, AuthorRole::Admin),
+                sample_author(2, AuthorRole::Contributor),
+                sample_author(3, AuthorRole::Contributor),
+            ],
+            merges: Mutex::new(vec![]),
+        })
+    }
+
+    #[tokio::test]
+    async fn merge_is_recorded_along_with_the_merge() {
+        let service = author_service();
+        let result = merge(service.clone(), sample_author(1, AuthorRole::Admin), "2", 3).await;
+        assert!(result.is_ok());
+
+        let merges = service.merges.lock().unwrap();
+        assert_eq!(merges.len(), 1);
+        let (source_id, target_id, audit_record) = &merges[0];
+        assert_eq!((*source_id, *target_id), (2, 3));
+        assert_eq!(audit_record.actor_id, 1);
+        assert_eq!(audit_record.action, AuditAction::AuthorsMerged);
+        assert_eq!(audit_record.target_type, AuditTarget::Author);
+        assert_eq!(audit_record.target_id, 2);
+        assert_eq!(audit_record.after.as_deref(), Some(r#"{"merged_into":3}"#));
+    }
+
+    #[tokio::test]
+    async fn merge_needs_author_management() {
+        let service = author_service();
+        let result = merge(
+            service.clone(),
+            sample_author(2, AuthorRole::Contributor),
+            "2",
+            3,
+        )
+        .await;
+        assert!(matches!(result, Err(Forbidden)));
+        assert!(service.merges.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn merge_into_the_same_or_a_missing_author_is_rejected() {
+        let service = author_service();
+        let admin = sample_author(1, AuthorRole::Admin);
+        assert!(matches!(
+            merge(service.clone(), admin.clone(), "2", 2).await,
+            Err(SameAuthor)
+        ));
+        assert!(matches!(
+            merge(service.clone(), admin.clone(), "2", 4).await,
+            Err(NotFound)
+        ));
+        assert!(matches!(
+            merge(service.clone(), admin, "4", 2).await,
+            Err(NotFound)
+        ));
+        assert!(service.merges.lock().unwrap().is_empty());
+    }
+}
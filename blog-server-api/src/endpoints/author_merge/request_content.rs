@@ -0,0 +1,47 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::*;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorMergeRequestData {
+    /// Author that keeps everything, the one in the path is blocked afterwards.
+    pub target_id: u64,
+}
+
+pub struct AuthorMergeRequestContent {
+    pub(super) id: String,
+    pub(super) author_merge_data: DResult<AuthorMergeRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorMergeRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn AccessTokenService>>,
+{
+    type Data = AuthorMergeRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            id: origin_content
+                .path
+                .get("id")
+                .map(|n| n.to_owned())
+                .unwrap_or_default(),
+            author_merge_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
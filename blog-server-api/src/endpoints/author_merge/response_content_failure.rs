@@ -0,0 +1,86 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorMergeResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    Unauthorized { reason: String },
+    Forbidden,
+    IncorrectIdFormat { reason: String },
+    SameAuthor,
+    NotFound,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorMergeResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorMergeResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorMergeResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorMergeResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AuthorMergeResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorMergeResponseContentFailure::SameAuthor => &StatusCode::BAD_REQUEST,
+            AuthorMergeResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            AuthorMergeResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorMergeResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorMergeResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "AUTHOR_MERGE_PARAMS_ERROR"
+            }
+            AuthorMergeResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_MERGE_UNAUTHORIZED"
+            }
+            AuthorMergeResponseContentFailure::Forbidden => "AUTHOR_MERGE_FORBIDDEN",
+            AuthorMergeResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                "AUTHOR_MERGE_INCORRECT_ID_FORMAT"
+            }
+            AuthorMergeResponseContentFailure::SameAuthor => "AUTHOR_MERGE_SAME_AUTHOR",
+            AuthorMergeResponseContentFailure::NotFound => "AUTHOR_MERGE_NOT_FOUND",
+            AuthorMergeResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_MERGE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorMergeResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            AuthorMergeResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorMergeResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            AuthorMergeResponseContentFailure::IncorrectIdFormat { reason } => {
+                format!("incorrect value provided for author ID: {}", reason)
+            }
+            AuthorMergeResponseContentFailure::SameAuthor => {
+                "author can't be merged into itself".to_string()
+            }
+            AuthorMergeResponseContentFailure::NotFound => "author not found".to_string(),
+            AuthorMergeResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AuthorMergeResponseContentSuccess;
+
+impl ApiResponseContentBase for AuthorMergeResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorMergeResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_MERGE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("authors merged".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
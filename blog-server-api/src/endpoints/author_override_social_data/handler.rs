@@ -1,3 +1,5 @@
+use blog_server_services::traits::author_service::Capability;
+
 use super::request_content::AuthorOverrideSocialDataRequestContent;
 use super::response_content_failure::AuthorOverrideSocialDataResponseContentFailure;
 use super::response_content_failure::AuthorOverrideSocialDataResponseContentFailure::*;
@@ -35,7 +37,7 @@ async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::EditOwnProfile) {
         return Err(Forbidden);
     }
 
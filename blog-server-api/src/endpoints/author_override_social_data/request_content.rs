@@ -1,5 +1,6 @@
 use crate::extensions::Resolve;
 use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
 use blog_server_services::traits::author_service::*;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use screw_components::dyn_fn::DFuture;
@@ -12,7 +13,7 @@ pub struct AuthorOverrideSocialDataRequestContent {
 
 impl<Extensions> ApiRequestContent<Extensions> for AuthorOverrideSocialDataRequestContent
 where
-    Extensions: Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn AccessTokenService>>,
 {
     type Data = ();
 
@@ -22,6 +23,7 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -0,0 +1,82 @@
+use blog_server_services::traits::audit_service::{
+    AuditAction, AuditTarget, BaseAuditRecord, ModerationDecision,
+};
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::AuthorRoleRequestContent;
+use super::response_content_failure::AuthorRoleResponseContentFailure;
+use super::response_content_failure::AuthorRoleResponseContentFailure::*;
+use super::response_content_success::AuthorRoleResponseContentSuccess;
+
+pub async fn http_handler(
+    (AuthorRoleRequestContent {
+        id,
+        role_data,
+        author_service,
+        moderation_decision_service,
+        auth_author_future,
+    },): (AuthorRoleRequestContent,),
+) -> Result<AuthorRoleResponseContentSuccess, AuthorRoleResponseContentFailure> {
+    let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
+        reason: e.to_string(),
+    })?;
+
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    if !author.can(Capability::ManageRoles) {
+        return Err(Forbidden);
+    }
+
+    if author.id == id {
+        return Err(OwnRoleChange);
+    }
+
+    let role = role_data
+        .map_err(|e| ValidationError {
+            reason: e.to_string(),
+        })?
+        .role;
+
+    let target_author = author_service
+        .author_by_id(&id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(NotFound)?;
+
+    author_service
+        .set_author_role_by_id(
+            &id,
+            &role,
+            &BaseAuditRecord::new(
+                author.id,
+                AuditAction::AuthorRoleChanged,
+                AuditTarget::Author,
+                id,
+                Some(&serde_json::json!({ "role": target_author.base.role })),
+                Some(&serde_json::json!({ "role": role })),
+            ),
+        )
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let moderation_decision = ModerationDecision {
+        moderator_id: author.id,
+        author_id: id,
+        action: AuditAction::AuthorRoleChanged,
+        target_type: AuditTarget::Author,
+        target_id: id,
+    };
+    tokio::spawn(async move {
+        moderation_decision_service
+            .publish(moderation_decision)
+            .await
+    });
+
+    Ok(AuthorRoleResponseContentSuccess)
+}
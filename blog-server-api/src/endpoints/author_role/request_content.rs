@@ -0,0 +1,50 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::Publish;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::audit_service::ModerationDecision;
+use blog_server_services::traits::author_service::*;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+pub struct AuthorRoleRequestData {
+    pub role: AuthorRole,
+}
+
+pub struct AuthorRoleRequestContent {
+    pub(super) id: String,
+    pub(super) role_data: DResult<AuthorRoleRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) moderation_decision_service: Arc<dyn Publish<ModerationDecision>>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for AuthorRoleRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn Publish<ModerationDecision>>>,
+{
+    type Data = AuthorRoleRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            id: origin_content
+                .path
+                .get("id")
+                .map(|n| n.to_owned())
+                .unwrap_or_default(),
+            role_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            moderation_decision_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
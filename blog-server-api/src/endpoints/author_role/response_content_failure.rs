@@ -0,0 +1,88 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorRoleResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    OwnRoleChange,
+    DatabaseError { reason: String },
+    IncorrectIdFormat { reason: String },
+    ValidationError { reason: String },
+    NotFound,
+}
+
+impl ApiResponseContentBase for AuthorRoleResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorRoleResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorRoleResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            AuthorRoleResponseContentFailure::OwnRoleChange => &StatusCode::FORBIDDEN,
+            AuthorRoleResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            AuthorRoleResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorRoleResponseContentFailure::ValidationError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            AuthorRoleResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorRoleResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorRoleResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_ROLE_UNAUTHORIZED"
+            }
+            AuthorRoleResponseContentFailure::Forbidden => "AUTHOR_ROLE_FORBIDDEN",
+            AuthorRoleResponseContentFailure::OwnRoleChange => "AUTHOR_ROLE_OWN_ROLE_CHANGE",
+            AuthorRoleResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_ROLE_DATABASE_ERROR"
+            }
+            AuthorRoleResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                "AUTHOR_ROLE_INCORRECT_ID_FORMAT"
+            }
+            AuthorRoleResponseContentFailure::ValidationError { reason: _ } => {
+                "AUTHOR_ROLE_VALIDATION_ERROR"
+            }
+            AuthorRoleResponseContentFailure::NotFound => "AUTHOR_ROLE_NOT_FOUND",
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorRoleResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorRoleResponseContentFailure::Forbidden => String::from("insufficient rights"),
+            AuthorRoleResponseContentFailure::OwnRoleChange => {
+                String::from("own role can't be changed")
+            }
+            AuthorRoleResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            AuthorRoleResponseContentFailure::IncorrectIdFormat { reason } => {
+                format!("incorrect value provided for author ID: {}", reason)
+            }
+            AuthorRoleResponseContentFailure::ValidationError { reason } => {
+                format!("validation error: {}", reason)
+            }
+            AuthorRoleResponseContentFailure::NotFound => {
+                "author record not found in database".to_string()
+            }
+        })
+    }
+}
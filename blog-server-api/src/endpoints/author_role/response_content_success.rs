@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct AuthorRoleResponseContentSuccess;
+
+impl ApiResponseContentBase for AuthorRoleResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorRoleResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_ROLE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("author role changed".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
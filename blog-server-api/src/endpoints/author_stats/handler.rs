@@ -0,0 +1,24 @@
+use super::request_content::AuthorStatsRequestContent;
+use super::response_content_failure::AuthorStatsResponseContentFailure;
+use super::response_content_failure::AuthorStatsResponseContentFailure::*;
+use super::response_content_success::AuthorStatsResponseContentSuccess;
+
+pub async fn http_handler(
+    (AuthorStatsRequestContent {
+        author_stats_service,
+        auth_author_future,
+    },): (AuthorStatsRequestContent,),
+) -> Result<AuthorStatsResponseContentSuccess, AuthorStatsResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let stats = author_stats_service
+        .author_stats(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(stats.into())
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum AuthorStatsResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for AuthorStatsResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            AuthorStatsResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            AuthorStatsResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for AuthorStatsResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            AuthorStatsResponseContentFailure::Unauthorized { reason: _ } => {
+                "AUTHOR_STATS_UNAUTHORIZED"
+            }
+            AuthorStatsResponseContentFailure::DatabaseError { reason: _ } => {
+                "AUTHOR_STATS_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            AuthorStatsResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            AuthorStatsResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use blog_server_services::traits::author_stats_service::{AuthorStats, MonthlyCount};
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorPostsCounts {
+    pub published: u64,
+    pub unpublished: u64,
+    pub hidden: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorMonthlyCount {
+    pub month: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorStatsContainer {
+    pub posts: AuthorPostsCounts,
+    pub comments_received: u64,
+    pub published_by_month: Vec<AuthorMonthlyCount>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorStatsResponseContentSuccess {
+    container: AuthorStatsContainer,
+}
+
+impl Into<AuthorStatsResponseContentSuccess> for AuthorStats {
+    fn into(self) -> AuthorStatsResponseContentSuccess {
+        AuthorStatsResponseContentSuccess {
+            container: AuthorStatsContainer {
+                posts: AuthorPostsCounts {
+                    published: self.posts.published,
+                    unpublished: self.posts.unpublished,
+                    hidden: self.posts.hidden,
+                },
+                comments_received: self.comments_received,
+                published_by_month: self
+                    .published_by_month
+                    .into_iter()
+                    .map(|MonthlyCount { month, count }| AuthorMonthlyCount { month, count })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl ApiResponseContentBase for AuthorStatsResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for AuthorStatsResponseContentSuccess {
+    type Data = AuthorStatsContainer;
+
+    fn identifier(&self) -> &'static str {
+        "AUTHOR_STATS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("author stats returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
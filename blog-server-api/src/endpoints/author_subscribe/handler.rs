@@ -1,3 +1,5 @@
+use blog_server_services::traits::author_service::Capability;
+
 use super::request_content::AuthorSubscribeRequestContent;
 use super::response_content_failure::AuthorSubscribeResponseContentFailure;
 use super::response_content_failure::AuthorSubscribeResponseContentFailure::*;
@@ -33,7 +35,7 @@ async fn http_handler(
     })?;
 
     let is_same_user = logged_in_author.id == id;
-    let is_user_admin = logged_in_author.base.editor == 1;
+    let is_user_admin = logged_in_author.can(Capability::ManageAuthors);
 
     let subscriber_author = match (is_same_user, is_user_admin) {
         (true, _) => logged_in_author,
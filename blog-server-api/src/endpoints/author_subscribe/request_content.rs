@@ -1,5 +1,6 @@
 use crate::extensions::Resolve;
 use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
 use blog_server_services::traits::author_service::{Author, AuthorService};
 use blog_server_services::traits::social_service::SocialService;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
@@ -15,7 +16,9 @@ pub struct AuthorSubscribeRequestContent {
 
 impl<Extensions> ApiRequestContent<Extensions> for AuthorSubscribeRequestContent
 where
-    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn SocialService>>,
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn SocialService>>,
 {
     type Data = ();
 
@@ -31,6 +34,7 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -0,0 +1,413 @@
+use blog_server_services::traits::author_service::Capability;
+use password_hash::Error;
+
+use super::request_content::{ChangePasswordRequestContent, ChangePasswordRequestData};
+use super::response_content_failure::ChangePasswordResponseContentFailure;
+use super::response_content_failure::ChangePasswordResponseContentFailure::*;
+use super::response_content_success::ChangePasswordResponseContentSuccess;
+use crate::utils::{auth, password};
+
+pub async fn http_handler(
+    (ChangePasswordRequestContent {
+        change_password_data,
+        author_service,
+        session_service,
+        user_agent,
+        auth_session_future,
+    },): (ChangePasswordRequestContent,),
+) -> Result<ChangePasswordResponseContentSuccess, ChangePasswordResponseContentFailure> {
+    let auth_session = auth_session_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+    let is_recent_login = auth_session.is_recent_login();
+    let author = auth_session.author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    let ChangePasswordRequestData {
+        current_password,
+        new_password,
+    } = change_password_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    if let Some(password_hash) = &author.base.password_hash {
+        let current_password = current_password.ok_or(WrongPassword)?;
+        password::verify(&current_password, password_hash).map_err(|e| match e {
+            Error::Password => WrongPassword,
+            _ => PasswordVerificationError {
+                reason: e.to_string(),
+            },
+        })?;
+    } else if !is_recent_login {
+        // Nothing to check the first password against, so the social login itself
+        // has to be fresh: a stolen access token alone mustn't take the account over.
+        return Err(LoginRequired);
+    }
+
+    if !password::is_acceptable(&new_password) {
+        return Err(IncorrectPassword);
+    }
+
+    let new_password_hash = password::hash(&new_password).map_err(|e| PasswordHashingError {
+        reason: e.to_string(),
+    })?;
+
+    author_service
+        .set_author_password_hash_by_id(&author.id, &new_password_hash)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    // Every other device has to sign in with the new password.
+    session_service
+        .revoke_sessions_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let mut author = author;
+    author.base.password_hash = Some(new_password_hash);
+    author.base.must_change_password = 0;
+
+    let session_tokens = auth::start_session(author, user_agent, session_service)
+        .await
+        .map_err(|e| TokenGeneratingError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(session_tokens.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        DeletedAuthorPosts,
+    };
+    use blog_server_services::traits::session_service::{BaseSession, Session, SessionService};
+    use blog_server_services::utils::time_utils;
+    use screw_components::dyn_result::DResult;
+    use std::sync::{Arc, Mutex};
+
+    struct MockAuthorService {
+        password_hashes: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl AuthorService for MockAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, _id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_slug(&self, _slug: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            password_hash: &String,
+        ) -> DResult<()> {
+            self.password_hashes
+                .lock()
+                .unwrap()
+                .push(password_hash.clone());
+            Ok(())
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockSessionService;
+
+    #[async_trait]
+    impl SessionService for MockSessionService {
+        async fn create_session(&self, _session: &BaseSession) -> DResult<u64> {
+            Ok(1)
+        }
+
+        async fn active_sessions_by_author_id(&self, _author_id: &u64) -> DResult<Vec<Session>> {
+            unimplemented!()
+        }
+
+        async fn rotate_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+            _new_refresh_token_hash: &String,
+            _expires_at: &u64,
+        ) -> DResult<Option<Session>> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_rotated_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+        ) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_id(&self, _id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn revoke_sessions_by_author_id(&self, _author_id: &u64) -> DResult<()> {
+            Ok(())
+        }
+    }
+
+    fn sample_author(password_hash: Option<String>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    async fn change_password(
+        author: Author,
+        authenticated_at: u64,
+        current_password: Option<&str>,
+    ) -> (
+        Result<ChangePasswordResponseContentSuccess, ChangePasswordResponseContentFailure>,
+        Vec<String>,
+    ) {
+        let author_service = Arc::new(MockAuthorService {
+            password_hashes: Mutex::new(vec![]),
+        });
+        let result = http_handler((ChangePasswordRequestContent {
+            change_password_data: Ok(ChangePasswordRequestData {
+                current_password: current_password.map(str::to_string),
+                new_password: "new secret".into(),
+            }),
+            author_service: author_service.clone(),
+            session_service: Arc::new(MockSessionService),
+            user_agent: None,
+            auth_session_future: Box::pin(async move {
+                Ok(auth::AuthSession {
+                    author,
+                    session_id: 1,
+                    authenticated_at,
+                    expires_at: u64::MAX,
+                })
+            }),
+        },))
+        .await;
+        let password_hashes = author_service.password_hashes.lock().unwrap().clone();
+        (result, password_hashes)
+    }
+
+    #[tokio::test]
+    async fn wrong_current_password_is_rejected() {
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let now = time_utils::now_as_secs();
+        let (result, password_hashes) =
+            change_password(sample_author(Some(hash.clone())), now, Some("wrong")).await;
+        assert!(matches!(result, Err(WrongPassword)));
+        assert!(password_hashes.is_empty());
+
+        let (result, password_hashes) = change_password(sample_author(Some(hash)), now, None).await;
+        assert!(matches!(result, Err(WrongPassword)));
+        assert!(password_hashes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn current_password_allows_the_change() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let (result, password_hashes) =
+            change_password(sample_author(Some(hash)), 0, Some("secret")).await;
+        assert!(result.is_ok());
+        assert_eq!(password_hashes.len(), 1);
+        assert!(password::verify(&"new secret".to_string(), &password_hashes[0]).is_ok());
+    }
+
+    #[tokio::test]
+    async fn first_password_needs_a_recent_login() {
+        let an_hour_ago = time_utils::now_as_secs() - 60 * 60;
+        let (result, password_hashes) =
+            change_password(sample_author(None), an_hour_ago, None).await;
+        assert!(matches!(result, Err(LoginRequired)));
+        assert!(password_hashes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn first_password_is_set_right_after_login() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let (result, password_hashes) =
+            change_password(sample_author(None), time_utils::now_as_secs(), None).await;
+        assert!(result.is_ok());
+        assert_eq!(password_hashes.len(), 1);
+    }
+}
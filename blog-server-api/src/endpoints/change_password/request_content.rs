@@ -0,0 +1,44 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::session_service::SessionService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePasswordRequestData {
+    /// Not required for accounts that don't have a password yet.
+    pub current_password: Option<String>,
+    pub new_password: String,
+}
+
+pub struct ChangePasswordRequestContent {
+    pub(super) change_password_data: DResult<ChangePasswordRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+    pub(super) user_agent: Option<String>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for ChangePasswordRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn SessionService>>,
+{
+    type Data = ChangePasswordRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            change_password_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+            user_agent: auth::user_agent(&origin_content.http_parts),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
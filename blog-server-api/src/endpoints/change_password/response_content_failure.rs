@@ -0,0 +1,128 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum ChangePasswordResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    ParamsDecodeError { reason: String },
+    WrongPassword,
+    LoginRequired,
+    IncorrectPassword,
+    DatabaseError { reason: String },
+    PasswordVerificationError { reason: String },
+    PasswordHashingError { reason: String },
+    TokenGeneratingError { reason: String },
+}
+
+impl ApiResponseContentBase for ChangePasswordResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            ChangePasswordResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            ChangePasswordResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            ChangePasswordResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            ChangePasswordResponseContentFailure::WrongPassword => &StatusCode::FORBIDDEN,
+            ChangePasswordResponseContentFailure::LoginRequired => &StatusCode::FORBIDDEN,
+            ChangePasswordResponseContentFailure::IncorrectPassword => &StatusCode::BAD_REQUEST,
+            ChangePasswordResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ChangePasswordResponseContentFailure::PasswordVerificationError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ChangePasswordResponseContentFailure::PasswordHashingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            ChangePasswordResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for ChangePasswordResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            ChangePasswordResponseContentFailure::Unauthorized { reason: _ } => {
+                "CHANGE_PASSWORD_UNAUTHORIZED"
+            }
+            ChangePasswordResponseContentFailure::Forbidden => "CHANGE_PASSWORD_FORBIDDEN",
+            ChangePasswordResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "CHANGE_PASSWORD_PARAMS_ERROR"
+            }
+            ChangePasswordResponseContentFailure::WrongPassword => "CHANGE_PASSWORD_WRONG_PASSWORD",
+            ChangePasswordResponseContentFailure::LoginRequired => "CHANGE_PASSWORD_LOGIN_REQUIRED",
+            ChangePasswordResponseContentFailure::IncorrectPassword => {
+                "CHANGE_PASSWORD_INCORRECT_PASSWORD"
+            }
+            ChangePasswordResponseContentFailure::DatabaseError { reason: _ } => {
+                "CHANGE_PASSWORD_DATABASE_ERROR"
+            }
+            ChangePasswordResponseContentFailure::PasswordVerificationError { reason: _ } => {
+                "CHANGE_PASSWORD_PASSWORD_VERIFICATION_ERROR"
+            }
+            ChangePasswordResponseContentFailure::PasswordHashingError { reason: _ } => {
+                "CHANGE_PASSWORD_PASSWORD_HASHING_ERROR"
+            }
+            ChangePasswordResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "CHANGE_PASSWORD_TOKEN_GENERATING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            ChangePasswordResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            ChangePasswordResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            ChangePasswordResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            ChangePasswordResponseContentFailure::WrongPassword => {
+                "wrong current password passed to request".to_string()
+            }
+            ChangePasswordResponseContentFailure::LoginRequired => {
+                "sign in again to set the first password".to_string()
+            }
+            ChangePasswordResponseContentFailure::IncorrectPassword => {
+                "new password must be from 8 to 128 characters long".to_string()
+            }
+            ChangePasswordResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            ChangePasswordResponseContentFailure::PasswordVerificationError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("password verification error: {}", reason)
+                } else {
+                    "internal password verification error".to_string()
+                }
+            }
+            ChangePasswordResponseContentFailure::PasswordHashingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("password hashing error: {}", reason)
+                } else {
+                    "internal password hashing error".to_string()
+                }
+            }
+            ChangePasswordResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+        })
+    }
+}
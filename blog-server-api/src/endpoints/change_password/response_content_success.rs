@@ -0,0 +1,38 @@
+use crate::utils::auth::SessionTokens;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct ChangePasswordResponseContentSuccess {
+    session_tokens: SessionTokens,
+}
+
+impl Into<ChangePasswordResponseContentSuccess> for SessionTokens {
+    fn into(self) -> ChangePasswordResponseContentSuccess {
+        ChangePasswordResponseContentSuccess {
+            session_tokens: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for ChangePasswordResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for ChangePasswordResponseContentSuccess {
+    type Data = SessionTokens;
+
+    fn identifier(&self) -> &'static str {
+        "CHANGE_PASSWORD_SUCCESS"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("password changed, other sessions revoked and new tokens generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.session_tokens
+    }
+}
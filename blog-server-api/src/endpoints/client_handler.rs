@@ -1,5 +1,7 @@
 use crate::endpoints::*;
 use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::*;
 use blog_server_services::traits::author_service::*;
 use blog_server_services::traits::entity_post_service::*;
 use blog_server_services::traits::post_service::*;
@@ -84,6 +86,7 @@ const META_RULES: &[MetaRule] = &[
 
 pub async fn client_handler<
     Extensions: Resolve<std::sync::Arc<dyn AuthorService>>
+        + Resolve<std::sync::Arc<dyn AccessTokenService>>
         + Resolve<std::sync::Arc<dyn PostService>>
         + Resolve<std::sync::Arc<dyn EntityPostService>>,
 >(
@@ -91,8 +94,18 @@ pub async fn client_handler<
 ) -> Response {
     let (before, after) = INDEX_HTML.split_once(APP_TAG_PREFIX).unwrap();
 
+    let auth_author = auth::author_by_headers(
+        request.origin.http.method(),
+        request.origin.http.headers(),
+        request.origin.extensions.resolve(),
+        request.origin.extensions.resolve(),
+    )
+    .await
+    .ok();
+    let is_personalized = auth_author.is_some();
+
     let status = status(&request).await;
-    let app_content = app_content::<_, DefaultPageProcessor>(&request).await;
+    let app_content = app_content::<_, DefaultPageProcessor>(&request, auth_author).await;
 
     let path = request.path.as_str();
     let render_path = if path.starts_with('/') && !path.contains('?') && !path.contains('#') {
@@ -107,12 +120,16 @@ pub async fn client_handler<
 
     let page = update_meta(format!("{before}{APP_TAG_PREFIX}{rendered}{after}"));
 
+    let mut http = hyper::Response::builder()
+        .status(status)
+        .header("Content-Type", "text/html")
+        .header("Vary", "Cookie");
+    if is_personalized {
+        http = http.header("Cache-Control", "private, no-store");
+    }
+
     Response {
-        http: hyper::Response::builder()
-            .status(status)
-            .header("Content-Type", "text/html")
-            .body(screw_core::body::full(page))
-            .unwrap(),
+        http: http.body(screw_core::body::full(page)).unwrap(),
     }
 }
 
@@ -175,6 +192,7 @@ async fn status<Extensions>(
 // TODO: to think, if it's not a cringe
 async fn app_content<Extensions, PP>(
     request: &router::RoutedRequest<Request<Extensions>>,
+    auth_author: Option<blog_server_services::traits::author_service::Author>,
 ) -> Option<AppContent>
 where
     Extensions: Resolve<std::sync::Arc<dyn AuthorService>>
@@ -193,7 +211,7 @@ where
     match Route::recognize_path(request.path.as_str())? {
         Route::Post { slug: _, id } | Route::EditPost { id } => {
             encoded(
-                post::direct_handler(id.to_string(), ext.resolve(), ext.resolve()),
+                post::direct_handler(id.to_string(), auth_author, ext.resolve(), ext.resolve()),
                 |c| c.post,
             )
             .await
@@ -1,17 +1,20 @@
 use blog_generic::entities::{CommentsContainer, TotalOffsetLimitContainer};
+use blog_server_services::utils::cursor::Cursor;
 
 use super::request_content::CommentsRequestContent;
 use super::response_content_failure::CommentsResponseContentFailure;
 use super::response_content_failure::CommentsResponseContentFailure::*;
-use super::response_content_success::CommentsResponseContentSuccess;
+use super::response_content_success::{CommentsCursorContainer, CommentsResponseContentSuccess};
 
 pub async fn http_handler(
     (CommentsRequestContent {
         post_id,
         offset,
+        cursor,
         limit,
         comment_service,
         entity_comment_service,
+        auth_author_future,
     },): (CommentsRequestContent,),
 ) -> Result<CommentsResponseContentSuccess, CommentsResponseContentFailure> {
     let post_id = post_id.parse::<u64>().map_err(|e| IncorrectIdFormat {
@@ -21,6 +24,37 @@ pub async fn http_handler(
     let offset = offset.unwrap_or(0).max(0);
     let limit = limit.unwrap_or(200).max(0).min(200);
 
+    let viewer = auth_author_future.await.ok();
+
+    if let Some(cursor) = cursor {
+        let cursor = if cursor.is_empty() {
+            None
+        } else {
+            Some(Cursor::decode(&cursor).ok_or(IncorrectCursor)?)
+        };
+
+        let answer = comment_service
+            .comments_by_post_id_after_cursor(&post_id, cursor.as_ref(), &limit)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+
+        let comments_entities = entity_comment_service
+            .comments_entities(answer.comments, viewer.as_ref())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+
+        return Ok(CommentsCursorContainer {
+            comments: comments_entities,
+            next_cursor: answer.next_cursor.map(|c| c.encode()),
+            limit,
+        }
+        .into());
+    }
+
     let (comments_result, total_result) = tokio::join!(
         comment_service.comments_by_post_id(&post_id, &offset, &limit),
         comment_service.comments_count_by_post_id(&post_id),
@@ -35,7 +69,7 @@ pub async fn http_handler(
     })?;
 
     let comments_entities = entity_comment_service
-        .comments_entities(comments)
+        .comments_entities(comments, viewer.as_ref())
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
@@ -1,20 +1,31 @@
 use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
 use blog_server_services::traits::comment_service::*;
 use blog_server_services::traits::entity_comment_service::*;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
 use std::sync::Arc;
 
 pub struct CommentsRequestContent {
     pub(super) post_id: String,
     pub(super) offset: Option<u64>,
+    /// Present (even empty) switches the listing to cursor pagination.
+    pub(super) cursor: Option<String>,
     pub(super) limit: Option<u64>,
     pub(super) comment_service: Arc<dyn CommentService>,
     pub(super) entity_comment_service: Arc<dyn EntityCommentService>,
+    /// Optional, only decides which author fields are shown.
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for CommentsRequestContent
 where
-    Extensions: Resolve<Arc<dyn CommentService>> + Resolve<Arc<dyn EntityCommentService>>,
+    Extensions: Resolve<Arc<dyn CommentService>>
+        + Resolve<Arc<dyn EntityCommentService>>
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>,
 {
     type Data = ();
 
@@ -30,6 +41,7 @@ where
                 .get("offset")
                 .map(|v| v.parse().ok())
                 .flatten(),
+            cursor: origin_content.query.get("cursor").map(|v| v.to_owned()),
             limit: origin_content
                 .query
                 .get("limit")
@@ -37,6 +49,11 @@ where
                 .flatten(),
             comment_service: origin_content.extensions.resolve(),
             entity_comment_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
         }
     }
 }
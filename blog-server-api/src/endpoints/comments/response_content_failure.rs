@@ -4,6 +4,7 @@ use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
 pub enum CommentsResponseContentFailure {
     DatabaseError { reason: String },
     IncorrectIdFormat { reason: String },
+    IncorrectCursor,
 }
 
 impl ApiResponseContentBase for CommentsResponseContentFailure {
@@ -15,6 +16,7 @@ impl ApiResponseContentBase for CommentsResponseContentFailure {
             CommentsResponseContentFailure::IncorrectIdFormat { reason: _ } => {
                 &StatusCode::BAD_REQUEST
             }
+            CommentsResponseContentFailure::IncorrectCursor => &StatusCode::BAD_REQUEST,
         }
     }
 }
@@ -28,6 +30,7 @@ impl ApiResponseContentFailure for CommentsResponseContentFailure {
             CommentsResponseContentFailure::IncorrectIdFormat { reason: _ } => {
                 "COMMENTS_INCORRECT_ID_FORMAT"
             }
+            CommentsResponseContentFailure::IncorrectCursor => "COMMENTS_INCORRECT_CURSOR",
         }
     }
 
@@ -43,6 +46,9 @@ impl ApiResponseContentFailure for CommentsResponseContentFailure {
             CommentsResponseContentFailure::IncorrectIdFormat { reason } => {
                 format!("incorrect value provided for post ID: {}", reason)
             }
+            CommentsResponseContentFailure::IncorrectCursor => {
+                String::from("incorrect value provided for cursor")
+            }
         })
     }
 }
@@ -1,15 +1,41 @@
-use blog_generic::entities::CommentsContainer;
+use blog_generic::entities::{Comment, CommentsContainer};
 use hyper::StatusCode;
 use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsCursorContainer {
+    pub comments: Vec<Comment>,
+    pub next_cursor: Option<String>,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum CommentsResponseContainer {
+    Offset(CommentsContainer),
+    Cursor(CommentsCursorContainer),
+}
 
 #[derive(Debug, Clone)]
 pub struct CommentsResponseContentSuccess {
-    pub(super) container: CommentsContainer,
+    pub(super) container: CommentsResponseContainer,
 }
 
 impl Into<CommentsResponseContentSuccess> for CommentsContainer {
     fn into(self) -> CommentsResponseContentSuccess {
-        CommentsResponseContentSuccess { container: self }
+        CommentsResponseContentSuccess {
+            container: CommentsResponseContainer::Offset(self),
+        }
+    }
+}
+
+impl Into<CommentsResponseContentSuccess> for CommentsCursorContainer {
+    fn into(self) -> CommentsResponseContentSuccess {
+        CommentsResponseContentSuccess {
+            container: CommentsResponseContainer::Cursor(self),
+        }
     }
 }
 
@@ -20,7 +46,7 @@ impl ApiResponseContentBase for CommentsResponseContentSuccess {
 }
 
 impl ApiResponseContentSuccess for CommentsResponseContentSuccess {
-    type Data = CommentsContainer;
+    type Data = CommentsResponseContainer;
 
     fn identifier(&self) -> &'static str {
         "COMMENTS_OK"
@@ -1,3 +1,6 @@
+use blog_server_services::traits::author_service::Capability;
+use blog_server_services::traits::comment_service::CommentPublished;
+
 use super::request_content::CreateCommentRequestContent;
 use super::response_content_failure::CreateCommentContentFailure;
 use super::response_content_failure::CreateCommentContentFailure::*;
@@ -7,6 +10,7 @@ pub async fn http_handler(
     (CreateCommentRequestContent {
         new_comment_data,
         comment_service,
+        comment_published_service,
         auth_author_future,
     },): (CreateCommentRequestContent,),
 ) -> Result<CreateCommentContentSuccess, CreateCommentContentFailure> {
@@ -14,7 +18,7 @@ pub async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::CreateComments) {
         return Err(CreatingForbidden);
     }
 
@@ -34,12 +38,21 @@ pub async fn http_handler(
         });
     }
 
-    let _ = comment_service
+    let post_id = base_comment.post_id;
+
+    let comment_id = comment_service
         .create_comment(&From::from((author.id, base_comment)))
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
         })?;
 
+    let comment_published = CommentPublished {
+        comment_id,
+        post_id,
+        author_id: author.id,
+    };
+    tokio::spawn(async move { comment_published_service.publish(comment_published).await });
+
     Ok(CreateCommentContentSuccess)
 }
@@ -1,8 +1,10 @@
 use crate::{extensions::Resolve, utils::auth};
 use blog_generic::entities::CommonComment;
 use blog_server_services::traits::{
+    Publish,
+    access_token_service::AccessTokenService,
     author_service::{Author, AuthorService},
-    comment_service::CommentService,
+    comment_service::{CommentPublished, CommentService},
 };
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
@@ -11,12 +13,16 @@ use std::sync::Arc;
 pub struct CreateCommentRequestContent {
     pub(super) new_comment_data: DResult<CommonComment>,
     pub(super) comment_service: Arc<dyn CommentService>,
+    pub(super) comment_published_service: Arc<dyn Publish<CommentPublished>>,
     pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for CreateCommentRequestContent
 where
-    Extensions: Resolve<Arc<dyn CommentService>> + Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn CommentService>>
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn Publish<CommentPublished>>>,
 {
     type Data = CommonComment;
 
@@ -24,9 +30,11 @@ where
         Self {
             new_comment_data: origin_content.data_result,
             comment_service: origin_content.extensions.resolve(),
+            comment_published_service: origin_content.extensions.resolve(),
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -1,5 +1,6 @@
 use blog_generic::entities::PublishType;
 use blog_generic::events::NewPostPublished;
+use blog_server_services::traits::author_service::Capability;
 use validator::Validate;
 
 use super::request_content::CreatePostRequestContent;
@@ -20,7 +21,7 @@ pub async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::WritePosts) {
         return Err(CreatingForbidden);
     }
 
@@ -34,7 +35,7 @@ pub async fn http_handler(
         });
     }
 
-    if author.base.editor == 0 && base_post.publish_type.is_published() {
+    if !author.can(Capability::PublishPosts) && base_post.publish_type.is_published() {
         return Err(ValidationError {
             reason: "publishing not allowed for you".to_owned(),
         });
@@ -72,7 +73,7 @@ pub async fn http_handler(
         .ok_or(InsertFailed)?;
 
     let created_post_entity = entity_post_service
-        .posts_entities(vec![created_post])
+        .posts_entities(vec![created_post], Some(&author))
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
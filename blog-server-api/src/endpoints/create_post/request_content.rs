@@ -2,6 +2,7 @@ use crate::{extensions::Resolve, utils::auth};
 use blog_generic::{entities::CommonPost, events::NewPostPublished};
 use blog_server_services::traits::{
     Publish,
+    access_token_service::AccessTokenService,
     author_service::{Author, AuthorService},
     entity_post_service::EntityPostService,
     post_service::PostService,
@@ -22,6 +23,7 @@ impl<Extensions> ApiRequestContent<Extensions> for CreatePostRequestContent
 where
     Extensions: Resolve<Arc<dyn PostService>>
         + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
         + Resolve<Arc<dyn EntityPostService>>
         + Resolve<Arc<dyn Publish<NewPostPublished>>>,
 {
@@ -35,6 +37,7 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
             new_post_service: origin_content.extensions.resolve(),
         }
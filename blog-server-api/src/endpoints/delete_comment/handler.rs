@@ -1,3 +1,8 @@
+use blog_server_services::traits::audit_service::{
+    AuditAction, AuditTarget, BaseAuditRecord, ModerationDecision,
+};
+use blog_server_services::traits::author_service::Capability;
+
 use super::request_content::DeleteCommentRequestContent;
 use super::response_content_failure::DeleteCommentResponseContentFailure;
 use super::response_content_failure::DeleteCommentResponseContentFailure::*;
@@ -7,6 +12,7 @@ pub async fn http_handler(
     (DeleteCommentRequestContent {
         id,
         comment_service,
+        moderation_decision_service,
         auth_author_future,
     },): (DeleteCommentRequestContent,),
 ) -> Result<DeleteCommentResponseContentSuccess, DeleteCommentResponseContentFailure> {
@@ -18,7 +24,7 @@ pub async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::CreateComments) {
         return Err(EditingForbidden);
     }
 
@@ -30,16 +36,44 @@ pub async fn http_handler(
         })?
         .ok_or(NotFound)?;
 
-    if !(comment.base.author_id == author.id || author.base.editor == 1) {
+    if !(comment.base.author_id == author.id || author.can(Capability::ModerateComments)) {
         return Err(EditingForbidden);
     }
 
+    let is_moderated = comment.base.author_id != author.id;
+
+    let audit_record = is_moderated.then(|| {
+        BaseAuditRecord::new(
+            author.id,
+            AuditAction::CommentDeleted,
+            AuditTarget::Comment,
+            id,
+            Some(&comment),
+            None,
+        )
+    });
+
     comment_service
-        .mark_deleted_by_id(&id)
+        .mark_deleted_by_id(&id, audit_record.as_ref())
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
         })?;
 
+    if is_moderated {
+        let moderation_decision = ModerationDecision {
+            moderator_id: author.id,
+            author_id: comment.base.author_id,
+            action: AuditAction::CommentDeleted,
+            target_type: AuditTarget::Comment,
+            target_id: id,
+        };
+        tokio::spawn(async move {
+            moderation_decision_service
+                .publish(moderation_decision)
+                .await
+        });
+    }
+
     Ok(DeleteCommentResponseContentSuccess)
 }
@@ -1,3 +1,6 @@
+use blog_server_services::traits::Publish;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::audit_service::ModerationDecision;
 use blog_server_services::traits::author_service::{Author, AuthorService};
 use blog_server_services::traits::comment_service::CommentService;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
@@ -9,12 +12,16 @@ use crate::{extensions::Resolve, utils::auth};
 pub struct DeleteCommentRequestContent {
     pub(super) id: String,
     pub(super) comment_service: Arc<dyn CommentService>,
+    pub(super) moderation_decision_service: Arc<dyn Publish<ModerationDecision>>,
     pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for DeleteCommentRequestContent
 where
-    Extensions: Resolve<Arc<dyn CommentService>> + Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn CommentService>>
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn Publish<ModerationDecision>>>,
 {
     type Data = ();
 
@@ -26,9 +33,11 @@ where
                 .map(|n| n.to_owned())
                 .unwrap_or_default(),
             comment_service: origin_content.extensions.resolve(),
+            moderation_decision_service: origin_content.extensions.resolve(),
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -1,3 +1,5 @@
+use blog_server_services::traits::author_service::Capability;
+
 use super::request_content::DeletePostRequestContent;
 use super::response_content_failure::DeletePostResponseContentFailure;
 use super::response_content_failure::DeletePostResponseContentFailure::*;
@@ -19,7 +21,7 @@ pub async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::WritePosts) {
         return Err(EditingForbidden);
     }
 
@@ -31,7 +33,7 @@ pub async fn http_handler(
         })?
         .ok_or(NotFound)?;
 
-    if !(post.base.author_id == author.id || author.base.editor == 1) {
+    if !(post.base.author_id == author.id || author.can(Capability::ManageAnyPost)) {
         return Err(if post.base.publish_type.is_published() {
             EditingForbidden
         } else {
@@ -39,7 +41,7 @@ pub async fn http_handler(
         });
     }
 
-    if post.base.publish_type.is_published() && author.base.editor == 0 {
+    if post.base.publish_type.is_published() && !author.can(Capability::PublishPosts) {
         return Err(EditingForbidden);
     }
 
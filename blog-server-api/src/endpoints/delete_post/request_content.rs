@@ -1,3 +1,4 @@
+use blog_server_services::traits::access_token_service::AccessTokenService;
 use blog_server_services::traits::author_service::{Author, AuthorService};
 use blog_server_services::traits::comment_service::CommentService;
 use blog_server_services::traits::post_service::PostService;
@@ -18,6 +19,7 @@ impl<Extensions> ApiRequestContent<Extensions> for DeletePostRequestContent
 where
     Extensions: Resolve<Arc<dyn PostService>>
         + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
         + Resolve<Arc<dyn CommentService>>,
 {
     type Data = ();
@@ -34,6 +36,7 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -0,0 +1,265 @@
+use blog_server_services::traits::mail_service::Mail;
+use blog_server_services::traits::notification_preference_service::DigestFrequency;
+
+use super::request_content::{EmailSubscribeRequestContent, EmailSubscribeRequestData};
+use super::response_content_failure::EmailSubscribeResponseContentFailure;
+use super::response_content_failure::EmailSubscribeResponseContentFailure::*;
+use super::response_content_success::EmailSubscribeResponseContentSuccess;
+use crate::utils::{email_subscription, request_throttle};
+
+const EMAIL_MAX_LENGTH: usize = 50;
+/// Subscription requests from one address within the throttling window.
+const IP_MAX_REQUESTS: u64 = 10;
+/// Confirmation emails one email can be sent within the window, whoever asks.
+const EMAIL_MAX_REQUESTS: u64 = 3;
+
+// Answers the same way whether the email is already subscribed or not,
+// so the endpoint can't be used to find out subscribed emails.
+pub async fn http_handler(
+    (EmailSubscribeRequestContent {
+        subscribe_data,
+        email_subscription_service,
+        mail_service,
+        request_throttle_service,
+        client_ip,
+    },): (EmailSubscribeRequestContent,),
+) -> Result<EmailSubscribeResponseContentSuccess, EmailSubscribeResponseContentFailure> {
+    let retry_after = request_throttle::retry_after(
+        format!("email_subscribe:ip:{client_ip}"),
+        IP_MAX_REQUESTS,
+        request_throttle_service.clone(),
+    )
+    .await
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    let EmailSubscribeRequestData { email, frequency } =
+        subscribe_data.map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?;
+
+    let email = email.trim().to_lowercase();
+    if email.len() > EMAIL_MAX_LENGTH || !validator::validate_email(&email) {
+        return Err(IncorrectEmail);
+    }
+
+    let frequency = match DigestFrequency::parse(frequency.trim()) {
+        Some(frequency @ (DigestFrequency::Daily | DigestFrequency::Weekly)) => frequency,
+        _ => return Err(IncorrectFrequency),
+    };
+
+    let retry_after = request_throttle::retry_after(
+        format!("email_subscribe:email:{email}"),
+        EMAIL_MAX_REQUESTS,
+        request_throttle_service,
+    )
+    .await
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    let subscription = email_subscription_service
+        .create_email_subscription(&email, &frequency)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if subscription.base.confirmed == 1 {
+        return Ok(EmailSubscribeResponseContentSuccess);
+    }
+
+    let confirm_url =
+        email_subscription::confirm_url(&subscription).map_err(|e| TokenGeneratingError {
+            reason: e.to_string(),
+        })?;
+
+    let mail = Mail {
+        to: email,
+        subject: "Confirm your subscription".to_string(),
+        body: format!(
+            "Someone subscribed this email to the {frequency} digest of new posts at {site_url}.\n\n\
+            To start receiving it, follow the link within two days:\n\
+            {confirm_url}\n\n\
+            If it wasn't you, just ignore this email.",
+            frequency = frequency.as_str(),
+            site_url = &*crate::SITE_URL,
+        ),
+        html: None,
+    };
+
+    if let Err(err) = mail_service.send(mail).await {
+        println!("Failed to send subscription confirmation email: {err}");
+    }
+
+    Ok(EmailSubscribeResponseContentSuccess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::impls::create_memory_request_throttle_service;
+    use blog_server_services::traits::email_subscription_service::{
+        BaseEmailSubscription, EmailSubscription, EmailSubscriptionService,
+    };
+    use blog_server_services::traits::mail_service::MailService;
+    use blog_server_services::traits::request_throttle_service::RequestThrottleService;
+    use screw_components::dyn_result::DResult;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Every email is already subscribed, so nothing is mailed.
+    struct MockEmailSubscriptionService {
+        created: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl EmailSubscriptionService for MockEmailSubscriptionService {
+        async fn create_email_subscription(
+            &self,
+            email: &String,
+            frequency: &DigestFrequency,
+        ) -> DResult<EmailSubscription> {
+            self.created.fetch_add(1, Ordering::SeqCst);
+            Ok(EmailSubscription {
+                id: 1,
+                base: BaseEmailSubscription {
+                    email: email.clone(),
+                    frequency: *frequency,
+                    confirmed: 1,
+                    created_at: 0,
+                    confirmed_at: Some(0),
+                    last_sent_at: Some(0),
+                },
+            })
+        }
+        async fn email_subscription_by_id(&self, _: &u64) -> DResult<Option<EmailSubscription>> {
+            unimplemented!()
+        }
+        async fn email_subscription_by_email(
+            &self,
+            _: &String,
+        ) -> DResult<Option<EmailSubscription>> {
+            unimplemented!()
+        }
+        async fn confirm_email_subscription_by_id(&self, _: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+        async fn delete_email_subscription_by_id(&self, _: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+        async fn claim_due_email_subscriptions(
+            &self,
+            _: &DigestFrequency,
+            _: &u64,
+            _: &u64,
+        ) -> DResult<Vec<EmailSubscription>> {
+            unimplemented!()
+        }
+    }
+
+    struct MockMailService;
+
+    #[async_trait]
+    impl MailService for MockMailService {
+        async fn send(&self, _: Mail) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    async fn subscribe(
+        email: &str,
+        client_ip: &str,
+        email_subscription_service: Arc<MockEmailSubscriptionService>,
+        request_throttle_service: Arc<dyn RequestThrottleService>,
+    ) -> Result<EmailSubscribeResponseContentSuccess, EmailSubscribeResponseContentFailure> {
+        http_handler((EmailSubscribeRequestContent {
+            subscribe_data: Ok(EmailSubscribeRequestData {
+                email: email.to_string(),
+                frequency: "daily".to_string(),
+            }),
+            email_subscription_service,
+            mail_service: Arc::new(MockMailService),
+            request_throttle_service,
+            client_ip: client_ip.to_string(),
+        },))
+        .await
+    }
+
+    fn email_subscription_service() -> Arc<MockEmailSubscriptionService> {
+        Arc::new(MockEmailSubscriptionService {
+            created: AtomicUsize::new(0),
+        })
+    }
+
+    #[tokio::test]
+    async fn one_address_is_throttled() {
+        let service = email_subscription_service();
+        let throttle = create_memory_request_throttle_service();
+        for i in 0..IP_MAX_REQUESTS {
+            let email = format!("reader{i}@example.com");
+            let result = subscribe(&email, "10.0.0.1", service.clone(), throttle.clone()).await;
+            assert!(result.is_ok());
+        }
+        let result = subscribe(
+            "other@example.com",
+            "10.0.0.1",
+            service.clone(),
+            throttle.clone(),
+        )
+        .await;
+        assert!(matches!(result, Err(Blocked { retry_after }) if retry_after > 0));
+        assert_eq!(
+            service.created.load(Ordering::SeqCst),
+            IP_MAX_REQUESTS as usize
+        );
+
+        let result = subscribe("other@example.com", "10.0.0.2", service, throttle).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn one_email_is_throttled_from_every_address() {
+        let service = email_subscription_service();
+        let throttle = create_memory_request_throttle_service();
+        for i in 0..EMAIL_MAX_REQUESTS {
+            let ip = format!("10.0.0.{i}");
+            let result =
+                subscribe("Reader@example.com", &ip, service.clone(), throttle.clone()).await;
+            assert!(result.is_ok());
+        }
+        let result = subscribe("reader@example.com ", "10.0.1.1", service.clone(), throttle).await;
+        assert!(matches!(result, Err(Blocked { .. })));
+        assert_eq!(
+            service.created.load(Ordering::SeqCst),
+            EMAIL_MAX_REQUESTS as usize
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_requests_count_against_the_address() {
+        let service = email_subscription_service();
+        let throttle = create_memory_request_throttle_service();
+        for _ in 0..IP_MAX_REQUESTS {
+            let result = subscribe(
+                "not an email",
+                "10.0.0.1",
+                service.clone(),
+                throttle.clone(),
+            )
+            .await;
+            assert!(matches!(result, Err(IncorrectEmail)));
+        }
+        let result = subscribe("reader@example.com", "10.0.0.1", service.clone(), throttle).await;
+        assert!(matches!(result, Err(Blocked { .. })));
+        assert_eq!(service.created.load(Ordering::SeqCst), 0);
+    }
+}
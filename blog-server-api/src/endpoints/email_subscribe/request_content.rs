@@ -0,0 +1,49 @@
+use crate::extensions::{Resolve, TrustedProxies};
+use crate::utils::auth;
+use blog_server_services::traits::email_subscription_service::EmailSubscriptionService;
+use blog_server_services::traits::mail_service::MailService;
+use blog_server_services::traits::request_throttle_service::RequestThrottleService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSubscribeRequestData {
+    pub email: String,
+    /// `daily` or `weekly`.
+    pub frequency: String,
+}
+
+pub struct EmailSubscribeRequestContent {
+    pub(super) subscribe_data: DResult<EmailSubscribeRequestData>,
+    pub(super) email_subscription_service: Arc<dyn EmailSubscriptionService>,
+    pub(super) mail_service: Arc<dyn MailService>,
+    pub(super) request_throttle_service: Arc<dyn RequestThrottleService>,
+    pub(super) client_ip: String,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for EmailSubscribeRequestContent
+where
+    Extensions: Resolve<Arc<dyn EmailSubscriptionService>>
+        + Resolve<Arc<dyn MailService>>
+        + Resolve<Arc<dyn RequestThrottleService>>
+        + Resolve<Arc<TrustedProxies>>,
+{
+    type Data = EmailSubscribeRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            subscribe_data: origin_content.data_result,
+            email_subscription_service: origin_content.extensions.resolve(),
+            mail_service: origin_content.extensions.resolve(),
+            request_throttle_service: origin_content.extensions.resolve(),
+            client_ip: auth::client_ip(
+                &origin_content.http_parts,
+                &origin_content.remote_addr,
+                origin_content.extensions.resolve(),
+            ),
+        }
+    }
+}
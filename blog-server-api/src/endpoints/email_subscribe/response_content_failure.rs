@@ -0,0 +1,91 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum EmailSubscribeResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    IncorrectEmail,
+    IncorrectFrequency,
+    DatabaseError { reason: String },
+    TokenGeneratingError { reason: String },
+    Blocked { retry_after: u64 },
+}
+
+impl ApiResponseContentBase for EmailSubscribeResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            EmailSubscribeResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            EmailSubscribeResponseContentFailure::IncorrectEmail => &StatusCode::BAD_REQUEST,
+            EmailSubscribeResponseContentFailure::IncorrectFrequency => &StatusCode::BAD_REQUEST,
+            EmailSubscribeResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            EmailSubscribeResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            EmailSubscribeResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for EmailSubscribeResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            EmailSubscribeResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "EMAIL_SUBSCRIBE_PARAMS_ERROR"
+            }
+            EmailSubscribeResponseContentFailure::IncorrectEmail => {
+                "EMAIL_SUBSCRIBE_INCORRECT_EMAIL"
+            }
+            EmailSubscribeResponseContentFailure::IncorrectFrequency => {
+                "EMAIL_SUBSCRIBE_INCORRECT_FREQUENCY"
+            }
+            EmailSubscribeResponseContentFailure::DatabaseError { reason: _ } => {
+                "EMAIL_SUBSCRIBE_DATABASE_ERROR"
+            }
+            EmailSubscribeResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "EMAIL_SUBSCRIBE_TOKEN_GENERATING_ERROR"
+            }
+            EmailSubscribeResponseContentFailure::Blocked { retry_after: _ } => {
+                "EMAIL_SUBSCRIBE_BLOCKED"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            EmailSubscribeResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            EmailSubscribeResponseContentFailure::IncorrectEmail => {
+                "email is incorrect".to_string()
+            }
+            EmailSubscribeResponseContentFailure::IncorrectFrequency => {
+                "frequency should be daily or weekly".to_string()
+            }
+            EmailSubscribeResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            EmailSubscribeResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+            EmailSubscribeResponseContentFailure::Blocked { retry_after } => {
+                format!(
+                    "too many subscription requests, retry in {} seconds",
+                    retry_after
+                )
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct EmailSubscribeResponseContentSuccess;
+
+impl ApiResponseContentBase for EmailSubscribeResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for EmailSubscribeResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "EMAIL_SUBSCRIBE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("confirmation link sent unless the email is already subscribed".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
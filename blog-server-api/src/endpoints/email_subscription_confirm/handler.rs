@@ -0,0 +1,46 @@
+use super::request_content::EmailSubscriptionConfirmRequestContent;
+use super::response_content_failure::EmailSubscriptionConfirmResponseContentFailure;
+use super::response_content_failure::EmailSubscriptionConfirmResponseContentFailure::*;
+use super::response_content_success::EmailSubscriptionConfirmResponseContentSuccess;
+use crate::utils::email_subscription;
+
+pub async fn http_handler(
+    (EmailSubscriptionConfirmRequestContent {
+        token,
+        email_subscription_service,
+    },): (EmailSubscriptionConfirmRequestContent,),
+) -> Result<
+    EmailSubscriptionConfirmResponseContentSuccess,
+    EmailSubscriptionConfirmResponseContentFailure,
+> {
+    let token = token.filter(|t| !t.is_empty()).ok_or(TokenEmpty)?;
+
+    let subscription = email_subscription::subscription_by_confirm_token(
+        &token,
+        email_subscription_service.clone(),
+    )
+    .await
+    .map_err(|e| match e {
+        email_subscription::Error::Token(e) => TokenInvalid {
+            reason: e.to_string(),
+        },
+        email_subscription::Error::DatabaseError(e) => DatabaseError {
+            reason: e.to_string(),
+        },
+        email_subscription::Error::SubscriptionNotFound => NotFound,
+    })?;
+
+    // Following the link again shouldn't restart the digest period.
+    if subscription.base.confirmed == 1 {
+        return Ok(EmailSubscriptionConfirmResponseContentSuccess);
+    }
+
+    email_subscription_service
+        .confirm_email_subscription_by_id(&subscription.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(EmailSubscriptionConfirmResponseContentSuccess)
+}
@@ -0,0 +1,23 @@
+use crate::extensions::Resolve;
+use blog_server_services::traits::email_subscription_service::EmailSubscriptionService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use std::sync::Arc;
+
+pub struct EmailSubscriptionConfirmRequestContent {
+    pub(super) token: Option<String>,
+    pub(super) email_subscription_service: Arc<dyn EmailSubscriptionService>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for EmailSubscriptionConfirmRequestContent
+where
+    Extensions: Resolve<Arc<dyn EmailSubscriptionService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            token: origin_content.query.get("token").map(|v| v.to_owned()),
+            email_subscription_service: origin_content.extensions.resolve(),
+        }
+    }
+}
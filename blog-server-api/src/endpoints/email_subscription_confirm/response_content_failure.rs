@@ -0,0 +1,64 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum EmailSubscriptionConfirmResponseContentFailure {
+    TokenEmpty,
+    TokenInvalid { reason: String },
+    NotFound,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for EmailSubscriptionConfirmResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            EmailSubscriptionConfirmResponseContentFailure::TokenEmpty => &StatusCode::BAD_REQUEST,
+            EmailSubscriptionConfirmResponseContentFailure::TokenInvalid { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            EmailSubscriptionConfirmResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            EmailSubscriptionConfirmResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for EmailSubscriptionConfirmResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            EmailSubscriptionConfirmResponseContentFailure::TokenEmpty => {
+                "EMAIL_SUBSCRIPTION_CONFIRM_TOKEN_EMPTY"
+            }
+            EmailSubscriptionConfirmResponseContentFailure::TokenInvalid { reason: _ } => {
+                "EMAIL_SUBSCRIPTION_CONFIRM_TOKEN_INVALID"
+            }
+            EmailSubscriptionConfirmResponseContentFailure::NotFound => {
+                "EMAIL_SUBSCRIPTION_CONFIRM_NOT_FOUND"
+            }
+            EmailSubscriptionConfirmResponseContentFailure::DatabaseError { reason: _ } => {
+                "EMAIL_SUBSCRIPTION_CONFIRM_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            EmailSubscriptionConfirmResponseContentFailure::TokenEmpty => {
+                "token is empty in query".to_string()
+            }
+            EmailSubscriptionConfirmResponseContentFailure::TokenInvalid { reason } => {
+                format!("token error: {}", reason)
+            }
+            EmailSubscriptionConfirmResponseContentFailure::NotFound => {
+                "subscription not found".to_string()
+            }
+            EmailSubscriptionConfirmResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct EmailSubscriptionConfirmResponseContentSuccess;
+
+impl ApiResponseContentBase for EmailSubscriptionConfirmResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for EmailSubscriptionConfirmResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "EMAIL_SUBSCRIPTION_CONFIRM_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("email subscription confirmed".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
@@ -0,0 +1,95 @@
+use hyper::StatusCode;
+use hyper::header::{CACHE_CONTROL, CONTENT_TYPE};
+
+use screw_core::request::*;
+use screw_core::response::*;
+use screw_core::routing::*;
+
+/// What a page asks to do with the subscription of its link.
+struct SubscriptionAction {
+    title: &'static str,
+    button: &'static str,
+    done: &'static str,
+    api_path: &'static str,
+}
+
+const CONFIRM: SubscriptionAction = SubscriptionAction {
+    title: "Confirm your subscription",
+    button: "Confirm",
+    done: "Subscription confirmed, the first digest comes after the next period.",
+    api_path: "/api/subscription/email/confirm",
+};
+
+const UNSUBSCRIBE: SubscriptionAction = SubscriptionAction {
+    title: "Unsubscribe from the digest",
+    button: "Unsubscribe",
+    done: "You are unsubscribed, no more digests will be sent.",
+    api_path: "/api/subscription/email/unsubscribe",
+};
+
+/// JWT characters only, so the token can be put into the page as is.
+fn is_token(token: &str) -> bool {
+    !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+fn page(action: &SubscriptionAction, token: Option<&String>) -> Response {
+    let (status, body) = match token.filter(|t| is_token(t)) {
+        Some(token) => (
+            StatusCode::OK,
+            format!(
+                "<p><button id=action>{button}</button></p>\
+                <p id=result></p>\
+                <script>\
+                document.getElementById('action').onclick = async (e) => {{\
+                    e.target.disabled = true;\
+                    const response = await fetch('{api_path}?token={token}', {{ method: 'POST' }});\
+                    document.getElementById('result').textContent = response.ok\
+                        ? '{done}'\
+                        : 'Something went wrong, the link may have expired.';\
+                }};\
+                </script>",
+                button = action.button,
+                api_path = action.api_path,
+                done = action.done,
+            ),
+        ),
+        None => (
+            StatusCode::BAD_REQUEST,
+            "<p>The link is incomplete.</p>".to_string(),
+        ),
+    };
+    let html = format!(
+        "<!DOCTYPE html><html><head><meta charset=utf-8>\
+        <meta name=viewport content=\"width=device-width, initial-scale=1\">\
+        <meta name=robots content=noindex>\
+        <title>{title}</title></head>\
+        <body><h1>{title}</h1>{body}</body></html>",
+        title = action.title,
+    );
+    Response {
+        http: hyper::Response::builder()
+            .status(status)
+            .header(CONTENT_TYPE, "text/html; charset=utf-8")
+            .header(CACHE_CONTROL, "no-store")
+            .body(screw_core::body::full(html.into_bytes()))
+            .unwrap(),
+    }
+}
+
+/// Target of the link in the confirmation email. Mail scanners and link previews
+/// follow links, so the page only asks, the subscription is confirmed by its POST.
+pub async fn email_subscription_confirm_page_handler<Extensions>(
+    request: router::RoutedRequest<Request<Extensions>>,
+) -> Response {
+    page(&CONFIRM, request.query.get("token"))
+}
+
+/// Target of the unsubscribe link in digests, same as the confirmation page.
+pub async fn email_unsubscribe_page_handler<Extensions>(
+    request: router::RoutedRequest<Request<Extensions>>,
+) -> Response {
+    page(&UNSUBSCRIBE, request.query.get("token"))
+}
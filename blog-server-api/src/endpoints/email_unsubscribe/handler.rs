@@ -0,0 +1,38 @@
+use super::request_content::EmailUnsubscribeRequestContent;
+use super::response_content_failure::EmailUnsubscribeResponseContentFailure;
+use super::response_content_failure::EmailUnsubscribeResponseContentFailure::*;
+use super::response_content_success::EmailUnsubscribeResponseContentSuccess;
+use crate::utils::email_subscription;
+
+pub async fn http_handler(
+    (EmailUnsubscribeRequestContent {
+        token,
+        email_subscription_service,
+    },): (EmailUnsubscribeRequestContent,),
+) -> Result<EmailUnsubscribeResponseContentSuccess, EmailUnsubscribeResponseContentFailure> {
+    let token = token.filter(|t| !t.is_empty()).ok_or(TokenEmpty)?;
+
+    let subscription = email_subscription::subscription_by_unsubscribe_token(
+        &token,
+        email_subscription_service.clone(),
+    )
+    .await
+    .map_err(|e| match e {
+        email_subscription::Error::Token(e) => TokenInvalid {
+            reason: e.to_string(),
+        },
+        email_subscription::Error::DatabaseError(e) => DatabaseError {
+            reason: e.to_string(),
+        },
+        email_subscription::Error::SubscriptionNotFound => NotFound,
+    })?;
+
+    email_subscription_service
+        .delete_email_subscription_by_id(&subscription.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(EmailUnsubscribeResponseContentSuccess)
+}
@@ -0,0 +1,60 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum EmailUnsubscribeResponseContentFailure {
+    TokenEmpty,
+    TokenInvalid { reason: String },
+    NotFound,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for EmailUnsubscribeResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            EmailUnsubscribeResponseContentFailure::TokenEmpty => &StatusCode::BAD_REQUEST,
+            EmailUnsubscribeResponseContentFailure::TokenInvalid { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            EmailUnsubscribeResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            EmailUnsubscribeResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for EmailUnsubscribeResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            EmailUnsubscribeResponseContentFailure::TokenEmpty => "EMAIL_UNSUBSCRIBE_TOKEN_EMPTY",
+            EmailUnsubscribeResponseContentFailure::TokenInvalid { reason: _ } => {
+                "EMAIL_UNSUBSCRIBE_TOKEN_INVALID"
+            }
+            EmailUnsubscribeResponseContentFailure::NotFound => "EMAIL_UNSUBSCRIBE_NOT_FOUND",
+            EmailUnsubscribeResponseContentFailure::DatabaseError { reason: _ } => {
+                "EMAIL_UNSUBSCRIBE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            EmailUnsubscribeResponseContentFailure::TokenEmpty => {
+                "token is empty in query".to_string()
+            }
+            EmailUnsubscribeResponseContentFailure::TokenInvalid { reason } => {
+                format!("token error: {}", reason)
+            }
+            EmailUnsubscribeResponseContentFailure::NotFound => {
+                "subscription not found".to_string()
+            }
+            EmailUnsubscribeResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct EmailUnsubscribeResponseContentSuccess;
+
+impl ApiResponseContentBase for EmailUnsubscribeResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for EmailUnsubscribeResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "EMAIL_UNSUBSCRIBE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("email unsubscribed".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
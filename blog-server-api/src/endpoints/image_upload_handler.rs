@@ -0,0 +1,192 @@
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use hyper::StatusCode;
+use hyper::header::{CACHE_CONTROL, CONTENT_TYPE};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::*;
+use blog_server_services::traits::image_storage_service::ImageStorageService;
+use blog_server_services::utils::image_signer::{ImageVariant, signed_image_url};
+use blog_server_services::utils::image_upload;
+
+use screw_core::request::*;
+use screw_core::response::*;
+use screw_core::routing::*;
+
+const AVATAR_FIELD: &str = "avatar";
+
+/// Room for the multipart boundaries and headers on top of the image itself.
+const FORM_OVERHEAD: usize = 64 * 1024;
+
+fn json_response(status: StatusCode, value: serde_json::Value) -> Response {
+    Response {
+        http: hyper::Response::builder()
+            .status(status)
+            .header(CONTENT_TYPE, "application/json")
+            .body(screw_core::body::full(value.to_string().into_bytes()))
+            .unwrap(),
+    }
+}
+
+fn failure_response(status: StatusCode, reason: impl ToString) -> Response {
+    json_response(status, serde_json::json!({ "reason": reason.to_string() }))
+}
+
+fn empty_response(status: StatusCode) -> Response {
+    Response {
+        http: hyper::Response::builder()
+            .status(status)
+            .body(screw_core::body::empty())
+            .unwrap(),
+    }
+}
+
+/// Takes a `multipart/form-data` body with an `avatar` file, stores the image without
+/// its metadata and makes it the author's avatar. Answers with the stored URL and
+/// the signed one clients should render.
+pub async fn avatar_upload_handler<Extensions>(
+    request: router::RoutedRequest<Request<Extensions>>,
+) -> Response
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn ImageStorageService>>,
+{
+    let author_service: Arc<dyn AuthorService> = request.origin.extensions.resolve();
+    let access_token_service: Arc<dyn AccessTokenService> = request.origin.extensions.resolve();
+    let image_storage_service: Arc<dyn ImageStorageService> = request.origin.extensions.resolve();
+    let (parts, body) = request.origin.http.into_parts();
+
+    let Ok(author) = auth::author_by_headers(
+        &parts.method,
+        &parts.headers,
+        author_service.clone(),
+        access_token_service,
+    )
+    .await
+    else {
+        return failure_response(StatusCode::UNAUTHORIZED, "unauthorized error");
+    };
+
+    if !author.can(Capability::EditOwnProfile) {
+        return failure_response(StatusCode::FORBIDDEN, "insufficient rights to edit author");
+    }
+
+    let Some(boundary) = parts
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| multer::parse_boundary(v).ok())
+    else {
+        return failure_response(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "multipart/form-data body expected",
+        );
+    };
+
+    let form = match Limited::new(body, image_upload::MAX_SIZE + FORM_OVERHEAD)
+        .collect()
+        .await
+    {
+        Ok(form) => form.to_bytes(),
+        Err(e) if e.downcast_ref::<LengthLimitError>().is_some() => {
+            return failure_response(StatusCode::PAYLOAD_TOO_LARGE, image_upload::Error::TooLarge);
+        }
+        Err(e) => return failure_response(StatusCode::BAD_REQUEST, e),
+    };
+
+    let mut multipart = multer::Multipart::with_reader(Cursor::new(form), boundary);
+    let mut image = None;
+    while let Ok(Some(field)) = multipart.next_field().await {
+        if field.name() == Some(AVATAR_FIELD) {
+            image = field.bytes().await.ok();
+            break;
+        }
+    }
+    let Some(image) = image else {
+        return failure_response(
+            StatusCode::BAD_REQUEST,
+            format!("`{AVATAR_FIELD}` file field expected"),
+        );
+    };
+
+    // Decoding and encoding a large image takes a while, it mustn't hold up other requests.
+    let sanitized = tokio::task::spawn_blocking(move || image_upload::sanitize(&image)).await;
+    let Ok(sanitized) = sanitized else {
+        return failure_response(StatusCode::INTERNAL_SERVER_ERROR, "image processing error");
+    };
+    let (image, format) = match sanitized {
+        Ok(sanitized) => sanitized,
+        Err(e @ image_upload::Error::TooLarge) => {
+            return failure_response(StatusCode::PAYLOAD_TOO_LARGE, e);
+        }
+        Err(e @ image_upload::Error::UnsupportedType) => {
+            return failure_response(StatusCode::UNSUPPORTED_MEDIA_TYPE, e);
+        }
+        Err(e) => return failure_response(StatusCode::BAD_REQUEST, e),
+    };
+
+    let image_url = match image_storage_service.store_image(&image, format).await {
+        Ok(image_url) => image_url,
+        Err(_) => {
+            return failure_response(StatusCode::INTERNAL_SERVER_ERROR, "image storage error");
+        }
+    };
+
+    // Same as a manual profile edit, social logins won't bring their avatar back.
+    let updated = author_service
+        .update_minimal_custom_author_by_id(
+            &author.id,
+            &BaseMinimalAuthor {
+                slug: author.base.slug,
+                first_name: author.base.first_name,
+                last_name: author.base.last_name,
+                image_url: Some(image_url.clone()),
+            },
+        )
+        .await;
+    if updated.is_err() {
+        return failure_response(StatusCode::INTERNAL_SERVER_ERROR, "internal database error");
+    }
+
+    json_response(
+        StatusCode::CREATED,
+        serde_json::json!({
+            "imageUrl": image_url,
+            "processedImageUrl": signed_image_url(&image_url, ImageVariant::Small),
+        }),
+    )
+}
+
+/// Serves images kept by the local storage. Names are content hashes, so the
+/// files never change and may be cached for good.
+pub async fn uploaded_image_handler<Extensions: Resolve<Arc<dyn ImageStorageService>>>(
+    request: router::RoutedRequest<Request<Extensions>>,
+) -> Response {
+    let image_storage_service: Arc<dyn ImageStorageService> = request.origin.extensions.resolve();
+    let name = request
+        .origin
+        .http
+        .uri()
+        .path()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    match image_storage_service.image_by_name(&name).await {
+        Ok(Some(image)) => Response {
+            http: hyper::Response::builder()
+                .header(CONTENT_TYPE, image.format.content_type())
+                .header(CACHE_CONTROL, "public, max-age=31536000, immutable")
+                .header("X-Content-Type-Options", "nosniff")
+                .body(screw_core::body::full(image.data))
+                .unwrap(),
+        },
+        Ok(None) => empty_response(StatusCode::NOT_FOUND),
+        Err(_) => empty_response(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -1,23 +1,20 @@
-use chrono::{DateTime, Days, Utc};
-use once_cell::sync::Lazy;
-use std::collections::{HashMap, HashSet};
-use tokio::sync::Mutex;
-
 use super::request_content::LoginRequestContent;
 use super::response_content_failure::LoginResponseContentFailure;
 use super::response_content_failure::LoginResponseContentFailure::*;
-use super::response_content_success::LoginResponseContentSuccess;
-use crate::utils::{auth, password};
+use super::response_content_success::{LoginResponseContentSuccess, TwoFactorChallenge};
+use crate::utils::{auth, login_attempts, password, two_factor};
 use blog_generic::entities::LoginQuestion;
 use password_hash::Error;
 
-static LOGIN_TRY_STORAGE: Lazy<Mutex<HashMap<u64, HashSet<DateTime<Utc>>>>> =
-    Lazy::new(|| Mutex::new(Default::default()));
-
 pub async fn http_handler(
     (LoginRequestContent {
         login_question,
         author_service,
+        session_service,
+        two_factor_service,
+        login_attempt_service,
+        user_agent,
+        client_ip,
     },): (LoginRequestContent,),
 ) -> Result<LoginResponseContentSuccess, LoginResponseContentFailure> {
     let LoginQuestion { slug, password } = login_question.map_err(|e| ParamsDecodeError {
@@ -36,55 +33,81 @@ pub async fn http_handler(
         })?
         .ok_or(NotFound)?;
 
-    let mut login_try_storage = LOGIN_TRY_STORAGE.lock().await;
-    let now = Utc::now();
+    let retry_after =
+        login_attempts::begin_attempt(&author.id, &client_ip, login_attempt_service.clone())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
 
-    if let Some(login_try) = login_try_storage.remove(&author.id) {
-        let yesterday = now - Days::new(1);
-        let login_try_actual = login_try
-            .into_iter()
-            .filter(|t| t > &yesterday)
-            .collect::<HashSet<_>>();
-        let login_try_actual_len = login_try_actual.len();
-        (*login_try_storage).insert(author.id, login_try_actual);
-        if login_try_actual_len > 20 {
-            return Err(Blocked);
-        }
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
     }
 
     let Some(password_hash) = &author.base.password_hash else {
         return Err(WrongPassword);
     };
 
-    password::verify(&password, password_hash).map_err(|e| {
-        if let Some(login_try) = login_try_storage.get_mut(&author.id) {
-            login_try.insert(now);
-        } else {
-            (*login_try_storage).insert(author.id, HashSet::from_iter(vec![now]));
-        }
-        match e {
+    if let Err(e) = password::verify(&password, password_hash) {
+        return Err(match e {
             Error::Password => WrongPassword,
             _ => PasswordVerificationError {
                 reason: e.to_string(),
             },
+        });
+    }
+
+    let author_totp = two_factor::enabled_totp(&author.id, two_factor_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    // Failures are kept until the second factor is passed too.
+    if author_totp.is_some() {
+        let challenge_token =
+            auth::two_factor_challenge_token(&author).map_err(|e| TokenGeneratingError {
+                reason: e.to_string(),
+            })?;
+        return Ok(TwoFactorChallenge {
+            challenge_token,
+            expires_in: auth::TWO_FACTOR_CHALLENGE_TOKEN_LIFETIME,
         }
-    })?;
+        .into());
+    }
 
-    let token = auth::token(author).map_err(|e| TokenGeneratingError {
-        reason: e.to_string(),
-    })?;
+    login_attempts::register_success(&author.id, &client_ip, login_attempt_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let session_tokens = auth::start_session(author, user_agent, session_service)
+        .await
+        .map_err(|e| TokenGeneratingError {
+            reason: e.to_string(),
+        })?;
 
-    Ok(token.into())
+    Ok(session_tokens.into())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::response_content_success::LoginResponseData;
     use super::*;
     use async_trait::async_trait;
+    use blog_server_services::impls::create_memory_login_attempt_service;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
     use blog_server_services::traits::author_service::{
-        Author, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        DeletedAuthorPosts,
     };
+    use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+    use blog_server_services::traits::session_service::{BaseSession, Session, SessionService};
+    use blog_server_services::traits::two_factor_service::{AuthorTotp, TwoFactorService};
+    use screw_api::response::ApiResponseContentSuccess;
     use screw_components::dyn_result::DResult;
+    use std::future::Future;
     use std::sync::Arc;
 
     enum MockBehavior {
@@ -145,6 +168,10 @@ mod tests {
             unimplemented!()
         }
 
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
         async fn set_author_override_social_data_by_id(
             &self,
             _id: &u64,
@@ -180,6 +207,15 @@ mod tests {
             unimplemented!()
         }
 
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
         async fn update_secondary_author_by_id(
             &self,
             _id: &u64,
@@ -188,7 +224,29 @@ mod tests {
             unimplemented!()
         }
 
-        async fn set_author_blocked_by_id(&self, _id: &u64, _is_blocked: &u8) -> DResult<()> {
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
             unimplemented!()
         }
 
@@ -199,6 +257,106 @@ mod tests {
         ) -> DResult<()> {
             unimplemented!()
         }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockSessionService;
+
+    #[async_trait]
+    impl SessionService for MockSessionService {
+        async fn create_session(&self, _session: &BaseSession) -> DResult<u64> {
+            Ok(1)
+        }
+
+        async fn active_sessions_by_author_id(&self, _author_id: &u64) -> DResult<Vec<Session>> {
+            unimplemented!()
+        }
+
+        async fn rotate_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+            _new_refresh_token_hash: &String,
+            _expires_at: &u64,
+        ) -> DResult<Option<Session>> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_rotated_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+        ) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_id(&self, _id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn revoke_sessions_by_author_id(&self, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockTwoFactorService {
+        totp: Option<AuthorTotp>,
+    }
+
+    #[async_trait]
+    impl TwoFactorService for MockTwoFactorService {
+        async fn totp_by_author_id(&self, _author_id: &u64) -> DResult<Option<AuthorTotp>> {
+            Ok(self.totp.clone())
+        }
+
+        async fn set_pending_totp(&self, _author_id: &u64, _secret: &String) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn enable_totp(
+            &self,
+            _author_id: &u64,
+            _recovery_code_hashes: &Vec<String>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn use_totp_step(&self, _author_id: &u64, _step: &u64) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn use_recovery_code(&self, _author_id: &u64, _code_hash: &String) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn unused_recovery_codes_count(&self, _author_id: &u64) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn disable_totp(&self, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
     }
 
     fn sample_author(password_hash: Option<String>) -> Author {
@@ -215,23 +373,26 @@ mod tests {
                 registered_at: 0,
                 status: None,
                 image_url: None,
-                editor: 0,
+                role: AuthorRole::Contributor,
                 blocked: 0,
                 yandex_id: None,
                 telegram_id: None,
                 notification_subscribed: None,
                 override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
             },
+            token_scopes: None,
         }
     }
 
-    async fn reset_storage() {
-        LOGIN_TRY_STORAGE.lock().await.clear();
-    }
-
     #[tokio::test]
     async fn empty_slug_returns_error() {
-        reset_storage().await;
         let service = Arc::new(MockAuthorService {
             behavior: MockBehavior::Success(None),
         });
@@ -241,6 +402,11 @@ mod tests {
                 password: String::new(),
             }),
             author_service: service,
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+            login_attempt_service: create_memory_login_attempt_service(),
+            user_agent: None,
+            client_ip: "127.0.0.1".into(),
         },))
         .await;
         assert!(matches!(result, Err(SlugEmpty)));
@@ -248,7 +414,6 @@ mod tests {
 
     #[tokio::test]
     async fn not_found_returns_error() {
-        reset_storage().await;
         let service = Arc::new(MockAuthorService {
             behavior: MockBehavior::Success(None),
         });
@@ -258,6 +423,11 @@ mod tests {
                 password: "pwd".into(),
             }),
             author_service: service,
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+            login_attempt_service: create_memory_login_attempt_service(),
+            user_agent: None,
+            client_ip: "127.0.0.1".into(),
         },))
         .await;
         assert!(matches!(result, Err(NotFound)));
@@ -265,7 +435,6 @@ mod tests {
 
     #[tokio::test]
     async fn db_error_propagates() {
-        reset_storage().await;
         let service = Arc::new(MockAuthorService {
             behavior: MockBehavior::Error,
         });
@@ -275,6 +444,11 @@ mod tests {
                 password: "pwd".into(),
             }),
             author_service: service,
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+            login_attempt_service: create_memory_login_attempt_service(),
+            user_agent: None,
+            client_ip: "127.0.0.1".into(),
         },))
         .await;
         assert!(matches!(result, Err(DatabaseError { .. })));
@@ -282,7 +456,6 @@ mod tests {
 
     #[tokio::test]
     async fn wrong_password_returns_error() {
-        reset_storage().await;
         let hash = password::hash(&"secret".to_string()).unwrap();
         let service = Arc::new(MockAuthorService {
             behavior: MockBehavior::Success(Some(sample_author(Some(hash)))),
@@ -293,6 +466,11 @@ mod tests {
                 password: "wrong".into(),
             }),
             author_service: service,
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+            login_attempt_service: create_memory_login_attempt_service(),
+            user_agent: None,
+            client_ip: "127.0.0.1".into(),
         },))
         .await;
         assert!(matches!(result, Err(WrongPassword)));
@@ -300,7 +478,6 @@ mod tests {
 
     #[tokio::test]
     async fn success_returns_token() {
-        reset_storage().await;
         unsafe { std::env::set_var("JWT_SECRET", "secret") };
         let hash = password::hash(&"secret".to_string()).unwrap();
         let service = Arc::new(MockAuthorService {
@@ -312,8 +489,192 @@ mod tests {
                 password: "secret".into(),
             }),
             author_service: service,
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+            login_attempt_service: create_memory_login_attempt_service(),
+            user_agent: None,
+            client_ip: "127.0.0.1".into(),
         },))
         .await;
-        assert!(matches!(result, Ok(_)));
+        let Ok(success) = result else {
+            panic!("login should succeed");
+        };
+        let LoginResponseData::Session(session_tokens) = success.data() else {
+            panic!("login without 2FA should return session tokens");
+        };
+        assert!(session_tokens.refresh_token.starts_with("1."));
+    }
+
+    #[tokio::test]
+    async fn enabled_totp_returns_challenge() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let service = Arc::new(MockAuthorService {
+            behavior: MockBehavior::Success(Some(sample_author(Some(hash.clone())))),
+        });
+        let result = http_handler((LoginRequestContent {
+            login_question: Ok(LoginQuestion {
+                slug: "john".into(),
+                password: "secret".into(),
+            }),
+            author_service: service,
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService {
+                totp: Some(AuthorTotp {
+                    author_id: 1,
+                    secret: "MZXW6YTBOI".into(),
+                    enabled: 1,
+                    last_used_step: None,
+                    created_at: 0,
+                }),
+            }),
+            login_attempt_service: create_memory_login_attempt_service(),
+            user_agent: None,
+            client_ip: "127.0.0.1".into(),
+        },))
+        .await;
+        let Ok(success) = result else {
+            panic!("password step should succeed");
+        };
+        assert!(matches!(
+            success.data(),
+            LoginResponseData::TwoFactorChallenge(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn repeated_wrong_passwords_are_throttled_per_address() {
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let service = Arc::new(MockAuthorService {
+            behavior: MockBehavior::Success(Some(sample_author(Some(hash)))),
+        });
+        let login_attempt_service = create_memory_login_attempt_service();
+        let login = |password: &str, client_ip: &str| {
+            http_handler((LoginRequestContent {
+                login_question: Ok(LoginQuestion {
+                    slug: "john".into(),
+                    password: password.into(),
+                }),
+                author_service: service.clone(),
+                session_service: Arc::new(MockSessionService),
+                two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+                login_attempt_service: login_attempt_service.clone(),
+                user_agent: None,
+                client_ip: client_ip.into(),
+            },))
+        };
+        for _ in 0..5 {
+            assert!(matches!(
+                login("wrong", "10.0.0.1").await,
+                Err(WrongPassword)
+            ));
+        }
+        assert!(matches!(
+            login("secret", "10.0.0.1").await,
+            Err(Blocked { retry_after: _ })
+        ));
+        assert!(matches!(
+            login("wrong", "10.0.0.2").await,
+            Err(WrongPassword)
+        ));
+    }
+
+    fn passwordless_login(
+        login_attempt_service: Arc<dyn LoginAttemptService>,
+        client_ip: String,
+    ) -> impl Future<Output = Result<LoginResponseContentSuccess, LoginResponseContentFailure>>
+    {
+        http_handler((LoginRequestContent {
+            login_question: Ok(LoginQuestion {
+                slug: "john".into(),
+                password: "guess".into(),
+            }),
+            author_service: Arc::new(MockAuthorService {
+                behavior: MockBehavior::Success(Some(sample_author(None))),
+            }),
+            session_service: Arc::new(MockSessionService),
+            two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+            login_attempt_service,
+            user_agent: None,
+            client_ip,
+        },))
+    }
+
+    #[tokio::test]
+    async fn failures_from_many_addresses_are_throttled_per_author() {
+        let login_attempt_service = create_memory_login_attempt_service();
+        for address in 1..=5 {
+            for _ in 0..4 {
+                assert!(matches!(
+                    passwordless_login(login_attempt_service.clone(), format!("10.0.0.{address}"))
+                        .await,
+                    Err(WrongPassword)
+                ));
+            }
+        }
+        assert!(matches!(
+            passwordless_login(login_attempt_service, "10.0.0.6".into()).await,
+            Err(Blocked { retry_after: _ })
+        ));
+    }
+
+    #[tokio::test]
+    async fn owner_address_is_not_throttled_by_failures_from_elsewhere() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let hash = password::hash(&"secret".to_string()).unwrap();
+        let service = Arc::new(MockAuthorService {
+            behavior: MockBehavior::Success(Some(sample_author(Some(hash)))),
+        });
+        let login_attempt_service = create_memory_login_attempt_service();
+        let login = |password: &str, client_ip: &str| {
+            http_handler((LoginRequestContent {
+                login_question: Ok(LoginQuestion {
+                    slug: "john".into(),
+                    password: password.into(),
+                }),
+                author_service: service.clone(),
+                session_service: Arc::new(MockSessionService),
+                two_factor_service: Arc::new(MockTwoFactorService { totp: None }),
+                login_attempt_service: login_attempt_service.clone(),
+                user_agent: None,
+                client_ip: client_ip.into(),
+            },))
+        };
+        assert!(login("secret", "10.0.1.1").await.is_ok());
+        for address in 1..=5 {
+            for _ in 0..4 {
+                assert!(matches!(
+                    login("wrong", &format!("10.0.0.{address}")).await,
+                    Err(WrongPassword)
+                ));
+            }
+        }
+        assert!(matches!(
+            login("secret", "10.0.0.6").await,
+            Err(Blocked { retry_after: _ })
+        ));
+        assert!(login("secret", "10.0.1.1").await.is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_attempts_are_counted_before_the_check() {
+        let login_attempt_service = create_memory_login_attempt_service();
+        let attempts: Vec<_> = (0..20)
+            .map(|_| {
+                tokio::spawn(passwordless_login(
+                    login_attempt_service.clone(),
+                    "10.0.0.1".into(),
+                ))
+            })
+            .collect();
+        let mut wrong_passwords = 0;
+        for attempt in attempts {
+            match attempt.await.unwrap() {
+                Err(WrongPassword) => wrong_passwords += 1,
+                Err(Blocked { retry_after: _ }) => {}
+                _ => panic!("attempt should fail"),
+            }
+        }
+        assert!((1..=5).contains(&wrong_passwords));
     }
 }
@@ -1,6 +1,10 @@
-use crate::extensions::Resolve;
+use crate::extensions::{Resolve, TrustedProxies};
+use crate::utils::auth;
 use blog_generic::entities::LoginQuestion;
 use blog_server_services::traits::author_service::*;
+use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+use blog_server_services::traits::session_service::SessionService;
+use blog_server_services::traits::two_factor_service::TwoFactorService;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use screw_components::dyn_result::DResult;
 use std::sync::Arc;
@@ -8,11 +12,20 @@ use std::sync::Arc;
 pub struct LoginRequestContent {
     pub(super) login_question: DResult<LoginQuestion>,
     pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+    pub(super) two_factor_service: Arc<dyn TwoFactorService>,
+    pub(super) login_attempt_service: Arc<dyn LoginAttemptService>,
+    pub(super) user_agent: Option<String>,
+    pub(super) client_ip: String,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for LoginRequestContent
 where
-    Extensions: Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn SessionService>>
+        + Resolve<Arc<dyn TwoFactorService>>
+        + Resolve<Arc<dyn LoginAttemptService>>
+        + Resolve<Arc<TrustedProxies>>,
 {
     type Data = LoginQuestion;
 
@@ -20,6 +33,15 @@ where
         Self {
             login_question: origin_content.data_result,
             author_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+            two_factor_service: origin_content.extensions.resolve(),
+            login_attempt_service: origin_content.extensions.resolve(),
+            user_agent: auth::user_agent(&origin_content.http_parts),
+            client_ip: auth::client_ip(
+                &origin_content.http_parts,
+                &origin_content.remote_addr,
+                origin_content.extensions.resolve(),
+            ),
         }
     }
 }
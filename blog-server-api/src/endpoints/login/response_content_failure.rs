@@ -9,7 +9,7 @@ pub enum LoginResponseContentFailure {
     PasswordVerificationError { reason: String },
     WrongPassword,
     TokenGeneratingError { reason: String },
-    Blocked,
+    Blocked { retry_after: u64 },
 }
 
 impl ApiResponseContentBase for LoginResponseContentFailure {
@@ -30,7 +30,9 @@ impl ApiResponseContentBase for LoginResponseContentFailure {
             LoginResponseContentFailure::TokenGeneratingError { reason: _ } => {
                 &StatusCode::INTERNAL_SERVER_ERROR
             }
-            LoginResponseContentFailure::Blocked => &StatusCode::FORBIDDEN,
+            LoginResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
         }
     }
 }
@@ -49,7 +51,7 @@ impl ApiResponseContentFailure for LoginResponseContentFailure {
             LoginResponseContentFailure::TokenGeneratingError { reason: _ } => {
                 "LOGIN_TOKEN_GENERATING_ERROR"
             }
-            LoginResponseContentFailure::Blocked => "LOGIN_BLOCKED",
+            LoginResponseContentFailure::Blocked { retry_after: _ } => "LOGIN_BLOCKED",
         }
     }
 
@@ -86,7 +88,9 @@ impl ApiResponseContentFailure for LoginResponseContentFailure {
                     "internal token generating error".to_string()
                 }
             }
-            LoginResponseContentFailure::Blocked => "lots of login attempts".to_string(),
+            LoginResponseContentFailure::Blocked { retry_after } => {
+                format!("too many login attempts, retry in {} seconds", retry_after)
+            }
         })
     }
 }
@@ -1,16 +1,40 @@
-use blog_generic::entities::LoginAnswer;
+use crate::utils::auth::SessionTokens;
 use hyper::StatusCode;
 use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorChallenge {
+    /// Exchanged for session tokens together with a code at `/api/login/2fa`.
+    pub challenge_token: String,
+    pub expires_in: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum LoginResponseData {
+    Session(SessionTokens),
+    TwoFactorChallenge(TwoFactorChallenge),
+}
 
 #[derive(Debug, Clone)]
 pub struct LoginResponseContentSuccess {
-    login_answer: LoginAnswer,
+    data: LoginResponseData,
 }
 
-impl Into<LoginResponseContentSuccess> for String {
+impl Into<LoginResponseContentSuccess> for SessionTokens {
     fn into(self) -> LoginResponseContentSuccess {
         LoginResponseContentSuccess {
-            login_answer: LoginAnswer { token: self },
+            data: LoginResponseData::Session(self),
+        }
+    }
+}
+
+impl Into<LoginResponseContentSuccess> for TwoFactorChallenge {
+    fn into(self) -> LoginResponseContentSuccess {
+        LoginResponseContentSuccess {
+            data: LoginResponseData::TwoFactorChallenge(self),
         }
     }
 }
@@ -22,17 +46,28 @@ impl ApiResponseContentBase for LoginResponseContentSuccess {
 }
 
 impl ApiResponseContentSuccess for LoginResponseContentSuccess {
-    type Data = LoginAnswer;
+    type Data = LoginResponseData;
 
     fn identifier(&self) -> &'static str {
-        "LOGIN_SUCCESS"
+        match self.data {
+            LoginResponseData::Session(_) => "LOGIN_SUCCESS",
+            LoginResponseData::TwoFactorChallenge(_) => "LOGIN_TWO_FACTOR_REQUIRED",
+        }
     }
 
     fn description(&self) -> Option<String> {
-        Some("login success and token generated".to_string())
+        Some(
+            match self.data {
+                LoginResponseData::Session(_) => "login success and session tokens generated",
+                LoginResponseData::TwoFactorChallenge(_) => {
+                    "password accepted, second factor code required"
+                }
+            }
+            .to_string(),
+        )
     }
 
     fn data(&self) -> &Self::Data {
-        &self.login_answer
+        &self.data
     }
 }
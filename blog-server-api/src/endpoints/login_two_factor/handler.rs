@@ -0,0 +1,75 @@
+use super::request_content::{LoginTwoFactorRequestContent, LoginTwoFactorRequestData};
+use super::response_content_failure::LoginTwoFactorResponseContentFailure;
+use super::response_content_failure::LoginTwoFactorResponseContentFailure::*;
+use super::response_content_success::LoginTwoFactorResponseContentSuccess;
+use crate::utils::{auth, login_attempts, two_factor};
+
+pub async fn http_handler(
+    (LoginTwoFactorRequestContent {
+        login_two_factor_data,
+        author_service,
+        session_service,
+        two_factor_service,
+        login_attempt_service,
+        user_agent,
+        client_ip,
+    },): (LoginTwoFactorRequestContent,),
+) -> Result<LoginTwoFactorResponseContentSuccess, LoginTwoFactorResponseContentFailure> {
+    let LoginTwoFactorRequestData {
+        challenge_token,
+        code,
+    } = login_two_factor_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let author = auth::author_by_two_factor_challenge_token(&challenge_token, author_service)
+        .await
+        .map_err(|e| match e {
+            auth::Error::DatabaseError(e) => DatabaseError {
+                reason: e.to_string(),
+            },
+            _ => ChallengeInvalid,
+        })?;
+
+    let retry_after =
+        login_attempts::begin_attempt(&author.id, &client_ip, login_attempt_service.clone())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    let author_totp = two_factor::enabled_totp(&author.id, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(ChallengeInvalid)?;
+
+    let is_code_valid = two_factor::verify_code(&author_totp, &code, two_factor_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if !is_code_valid {
+        return Err(WrongCode);
+    }
+
+    login_attempts::register_success(&author.id, &client_ip, login_attempt_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let session_tokens = auth::start_session(author, user_agent, session_service)
+        .await
+        .map_err(|e| TokenGeneratingError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(session_tokens.into())
+}
@@ -0,0 +1,55 @@
+use crate::extensions::{Resolve, TrustedProxies};
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+use blog_server_services::traits::session_service::SessionService;
+use blog_server_services::traits::two_factor_service::TwoFactorService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginTwoFactorRequestData {
+    pub challenge_token: String,
+    /// Current TOTP code or one of the recovery codes.
+    pub code: String,
+}
+
+pub struct LoginTwoFactorRequestContent {
+    pub(super) login_two_factor_data: DResult<LoginTwoFactorRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+    pub(super) two_factor_service: Arc<dyn TwoFactorService>,
+    pub(super) login_attempt_service: Arc<dyn LoginAttemptService>,
+    pub(super) user_agent: Option<String>,
+    pub(super) client_ip: String,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for LoginTwoFactorRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn SessionService>>
+        + Resolve<Arc<dyn TwoFactorService>>
+        + Resolve<Arc<dyn LoginAttemptService>>
+        + Resolve<Arc<TrustedProxies>>,
+{
+    type Data = LoginTwoFactorRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            login_two_factor_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+            two_factor_service: origin_content.extensions.resolve(),
+            login_attempt_service: origin_content.extensions.resolve(),
+            user_agent: auth::user_agent(&origin_content.http_parts),
+            client_ip: auth::client_ip(
+                &origin_content.http_parts,
+                &origin_content.remote_addr,
+                origin_content.extensions.resolve(),
+            ),
+        }
+    }
+}
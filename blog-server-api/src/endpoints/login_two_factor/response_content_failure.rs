@@ -0,0 +1,86 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum LoginTwoFactorResponseContentFailure {
+    DatabaseError { reason: String },
+    ParamsDecodeError { reason: String },
+    ChallengeInvalid,
+    WrongCode,
+    Blocked { retry_after: u64 },
+    TokenGeneratingError { reason: String },
+}
+
+impl ApiResponseContentBase for LoginTwoFactorResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            LoginTwoFactorResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            LoginTwoFactorResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            LoginTwoFactorResponseContentFailure::ChallengeInvalid => &StatusCode::UNAUTHORIZED,
+            LoginTwoFactorResponseContentFailure::WrongCode => &StatusCode::FORBIDDEN,
+            LoginTwoFactorResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
+            LoginTwoFactorResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for LoginTwoFactorResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            LoginTwoFactorResponseContentFailure::DatabaseError { reason: _ } => {
+                "LOGIN_TWO_FACTOR_DATABASE_ERROR"
+            }
+            LoginTwoFactorResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "LOGIN_TWO_FACTOR_PARAMS_ERROR"
+            }
+            LoginTwoFactorResponseContentFailure::ChallengeInvalid => {
+                "LOGIN_TWO_FACTOR_CHALLENGE_INVALID"
+            }
+            LoginTwoFactorResponseContentFailure::WrongCode => "LOGIN_TWO_FACTOR_WRONG_CODE",
+            LoginTwoFactorResponseContentFailure::Blocked { retry_after: _ } => {
+                "LOGIN_TWO_FACTOR_BLOCKED"
+            }
+            LoginTwoFactorResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "LOGIN_TWO_FACTOR_TOKEN_GENERATING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            LoginTwoFactorResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            LoginTwoFactorResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            LoginTwoFactorResponseContentFailure::ChallengeInvalid => {
+                "challenge token is invalid or expired".to_string()
+            }
+            LoginTwoFactorResponseContentFailure::WrongCode => {
+                "wrong two-factor code passed to request".to_string()
+            }
+            LoginTwoFactorResponseContentFailure::Blocked { retry_after } => {
+                format!("too many login attempts, retry in {} seconds", retry_after)
+            }
+            LoginTwoFactorResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::utils::auth::SessionTokens;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct LoginTwoFactorResponseContentSuccess {
+    session_tokens: SessionTokens,
+}
+
+impl Into<LoginTwoFactorResponseContentSuccess> for SessionTokens {
+    fn into(self) -> LoginTwoFactorResponseContentSuccess {
+        LoginTwoFactorResponseContentSuccess {
+            session_tokens: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for LoginTwoFactorResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for LoginTwoFactorResponseContentSuccess {
+    type Data = SessionTokens;
+
+    fn identifier(&self) -> &'static str {
+        "LOGIN_TWO_FACTOR_SUCCESS"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("second factor accepted and session tokens generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.session_tokens
+    }
+}
@@ -0,0 +1,24 @@
+use super::request_content::LogoutRequestContent;
+use super::response_content_failure::LogoutResponseContentFailure;
+use super::response_content_failure::LogoutResponseContentFailure::*;
+use super::response_content_success::LogoutResponseContentSuccess;
+
+pub async fn http_handler(
+    (LogoutRequestContent {
+        session_service,
+        auth_session_future,
+    },): (LogoutRequestContent,),
+) -> Result<LogoutResponseContentSuccess, LogoutResponseContentFailure> {
+    let auth_session = auth_session_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    session_service
+        .revoke_session_by_id(&auth_session.session_id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(LogoutResponseContentSuccess)
+}
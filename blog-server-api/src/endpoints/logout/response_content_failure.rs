@@ -0,0 +1,46 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum LogoutResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for LogoutResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            LogoutResponseContentFailure::Unauthorized { reason: _ } => &StatusCode::UNAUTHORIZED,
+            LogoutResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for LogoutResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            LogoutResponseContentFailure::Unauthorized { reason: _ } => "LOGOUT_UNAUTHORIZED",
+            LogoutResponseContentFailure::DatabaseError { reason: _ } => "LOGOUT_DATABASE_ERROR",
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            LogoutResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            LogoutResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
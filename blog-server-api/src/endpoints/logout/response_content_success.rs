@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct LogoutResponseContentSuccess;
+
+impl ApiResponseContentBase for LogoutResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for LogoutResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "LOGOUT_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("current session revoked".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
@@ -0,0 +1,24 @@
+use super::request_content::LogoutAllRequestContent;
+use super::response_content_failure::LogoutAllResponseContentFailure;
+use super::response_content_failure::LogoutAllResponseContentFailure::*;
+use super::response_content_success::LogoutAllResponseContentSuccess;
+
+pub async fn http_handler(
+    (LogoutAllRequestContent {
+        session_service,
+        auth_session_future,
+    },): (LogoutAllRequestContent,),
+) -> Result<LogoutAllResponseContentSuccess, LogoutAllResponseContentFailure> {
+    let auth_session = auth_session_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    session_service
+        .revoke_sessions_by_author_id(&auth_session.author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(LogoutAllResponseContentSuccess)
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum LogoutAllResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for LogoutAllResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            LogoutAllResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            LogoutAllResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for LogoutAllResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            LogoutAllResponseContentFailure::Unauthorized { reason: _ } => {
+                "LOGOUT_ALL_UNAUTHORIZED"
+            }
+            LogoutAllResponseContentFailure::DatabaseError { reason: _ } => {
+                "LOGOUT_ALL_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            LogoutAllResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            LogoutAllResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
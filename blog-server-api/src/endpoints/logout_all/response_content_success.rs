@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct LogoutAllResponseContentSuccess;
+
+impl ApiResponseContentBase for LogoutAllResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for LogoutAllResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "LOGOUT_ALL_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("all sessions of author revoked".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
@@ -1,9 +1,24 @@
+pub mod access_token_create;
+pub mod access_token_revoke;
+pub mod access_tokens;
+pub mod audit;
 pub mod author;
 pub mod author_block;
+pub mod author_delete;
+pub mod author_export;
+pub mod author_follow;
+pub mod author_follows;
+pub mod author_identities;
+pub mod author_identity_link;
+pub mod author_identity_unlink;
 pub mod author_me;
+pub mod author_merge;
 pub mod author_override_social_data;
+pub mod author_role;
+pub mod author_stats;
 pub mod author_subscribe;
 pub mod authors;
+pub mod change_password;
 #[cfg(feature = "chatgpt")]
 pub mod chatgpt;
 #[cfg(feature = "ssr")]
@@ -13,16 +28,44 @@ pub mod create_comment;
 pub mod create_post;
 pub mod delete_comment;
 pub mod delete_post;
+pub mod email_subscribe;
+pub mod email_subscription_confirm;
+mod email_subscription_page_handler;
+pub mod email_unsubscribe;
+mod image_upload_handler;
 pub mod login;
+pub mod login_two_factor;
+pub mod logout;
+pub mod logout_all;
+pub mod notification_preferences;
+pub mod notification_preferences_update;
+pub mod notifications;
+pub mod notifications_read;
+pub mod notifications_unread;
+pub mod oidc_authorize;
+pub mod oidc_login;
+pub mod oidc_providers;
+pub mod password_reset;
+pub mod password_reset_request;
 pub mod post;
 pub mod post_recommendation;
 pub mod post_update_recommended;
 pub mod posts;
+pub mod refresh;
+pub mod register;
+#[cfg(feature = "ssr")]
+mod session_cookie_handler;
+pub mod sessions;
 #[cfg(feature = "ssr")]
 mod sitemap_handler;
 pub mod tag;
+pub mod tag_follow;
 #[cfg(feature = "telegram")]
 pub mod telegram_login;
+pub mod two_factor_disable;
+pub mod two_factor_enable;
+pub mod two_factor_setup;
+pub mod two_factor_status;
 pub mod update_minimal_author;
 pub mod update_post;
 pub mod update_secondary_author;
@@ -31,5 +74,9 @@ pub mod yandex_login;
 
 #[cfg(feature = "ssr")]
 pub use client_handler::*;
+pub use email_subscription_page_handler::*;
+pub use image_upload_handler::*;
+#[cfg(feature = "ssr")]
+pub use session_cookie_handler::*;
 #[cfg(feature = "ssr")]
 pub use sitemap_handler::*;
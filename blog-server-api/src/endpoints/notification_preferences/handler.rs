@@ -0,0 +1,29 @@
+use super::request_content::NotificationPreferencesRequestContent;
+use super::response_content_failure::NotificationPreferencesResponseContentFailure;
+use super::response_content_failure::NotificationPreferencesResponseContentFailure::*;
+use super::response_content_success::{
+    NotificationPreferencesContainer, NotificationPreferencesResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (NotificationPreferencesRequestContent {
+        notification_preference_service,
+        auth_author_future,
+    },): (NotificationPreferencesRequestContent,),
+) -> Result<
+    NotificationPreferencesResponseContentSuccess,
+    NotificationPreferencesResponseContentFailure,
+> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let preferences = notification_preference_service
+        .notification_preferences_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(NotificationPreferencesContainer::from(preferences).into())
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum NotificationPreferencesResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for NotificationPreferencesResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            NotificationPreferencesResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            NotificationPreferencesResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for NotificationPreferencesResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            NotificationPreferencesResponseContentFailure::Unauthorized { reason: _ } => {
+                "NOTIFICATION_PREFERENCES_UNAUTHORIZED"
+            }
+            NotificationPreferencesResponseContentFailure::DatabaseError { reason: _ } => {
+                "NOTIFICATION_PREFERENCES_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            NotificationPreferencesResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            NotificationPreferencesResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
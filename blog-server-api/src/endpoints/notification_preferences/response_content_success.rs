@@ -0,0 +1,60 @@
+use blog_server_services::traits::notification_preference_service::{
+    DigestFrequency, NotificationChannel, NotificationEvent, NotificationPreferences,
+};
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+/// Events enabled for each channel.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferencesContainer {
+    pub telegram: Vec<NotificationEvent>,
+    pub email: Vec<NotificationEvent>,
+    pub in_app: Vec<NotificationEvent>,
+    pub digest: DigestFrequency,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesContainer {
+    fn from(value: NotificationPreferences) -> Self {
+        NotificationPreferencesContainer {
+            telegram: value.events(NotificationChannel::Telegram),
+            email: value.events(NotificationChannel::Email),
+            in_app: value.events(NotificationChannel::InApp),
+            digest: value.digest_frequency(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationPreferencesResponseContentSuccess {
+    container: NotificationPreferencesContainer,
+}
+
+impl Into<NotificationPreferencesResponseContentSuccess> for NotificationPreferencesContainer {
+    fn into(self) -> NotificationPreferencesResponseContentSuccess {
+        NotificationPreferencesResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for NotificationPreferencesResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for NotificationPreferencesResponseContentSuccess {
+    type Data = NotificationPreferencesContainer;
+
+    fn identifier(&self) -> &'static str {
+        "NOTIFICATION_PREFERENCES_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("notification preferences returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
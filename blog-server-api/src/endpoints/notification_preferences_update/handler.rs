@@ -0,0 +1,68 @@
+use blog_server_services::traits::author_service::Capability;
+use blog_server_services::traits::notification_preference_service::NotificationEvent;
+
+use super::request_content::{
+    NotificationPreferencesUpdateRequestContent, NotificationPreferencesUpdateRequestData,
+};
+use super::response_content_failure::NotificationPreferencesUpdateResponseContentFailure;
+use super::response_content_failure::NotificationPreferencesUpdateResponseContentFailure::*;
+use super::response_content_success::{
+    NotificationPreferencesUpdateContainer, NotificationPreferencesUpdateResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (NotificationPreferencesUpdateRequestContent {
+        notification_preferences_update_data,
+        notification_preference_service,
+        auth_author_future,
+    },): (NotificationPreferencesUpdateRequestContent,),
+) -> Result<
+    NotificationPreferencesUpdateResponseContentSuccess,
+    NotificationPreferencesUpdateResponseContentFailure,
+> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    let NotificationPreferencesUpdateRequestData {
+        telegram,
+        email,
+        in_app,
+        digest,
+    } = notification_preferences_update_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let mut preferences = notification_preference_service
+        .notification_preferences_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if let Some(telegram) = telegram {
+        preferences.telegram_events = NotificationEvent::join(&telegram);
+    }
+    if let Some(email) = email {
+        preferences.email_events = NotificationEvent::join(&email);
+    }
+    if let Some(in_app) = in_app {
+        preferences.in_app_events = NotificationEvent::join(&in_app);
+    }
+    if let Some(digest) = digest {
+        preferences.digest = digest.as_str().to_string();
+    }
+
+    notification_preference_service
+        .set_notification_preferences(&preferences)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(NotificationPreferencesUpdateContainer::from(preferences).into())
+}
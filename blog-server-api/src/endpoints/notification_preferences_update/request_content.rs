@@ -0,0 +1,50 @@
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::author_service::{Author, AuthorService};
+use blog_server_services::traits::notification_preference_service::{
+    DigestFrequency, NotificationEvent, NotificationPreferenceService,
+};
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_fn::DFuture;
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Omitted fields keep their current values.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferencesUpdateRequestData {
+    pub telegram: Option<Vec<NotificationEvent>>,
+    pub email: Option<Vec<NotificationEvent>>,
+    pub in_app: Option<Vec<NotificationEvent>>,
+    pub digest: Option<DigestFrequency>,
+}
+
+pub struct NotificationPreferencesUpdateRequestContent {
+    pub(super) notification_preferences_update_data:
+        DResult<NotificationPreferencesUpdateRequestData>,
+    pub(super) notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for NotificationPreferencesUpdateRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn NotificationPreferenceService>>,
+{
+    type Data = NotificationPreferencesUpdateRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            notification_preferences_update_data: origin_content.data_result,
+            notification_preference_service: origin_content.extensions.resolve(),
+            auth_author_future: Box::pin(auth::author(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
+            )),
+        }
+    }
+}
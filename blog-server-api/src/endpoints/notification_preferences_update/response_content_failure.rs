@@ -0,0 +1,72 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum NotificationPreferencesUpdateResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    Unauthorized { reason: String },
+    Forbidden,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for NotificationPreferencesUpdateResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            NotificationPreferencesUpdateResponseContentFailure::ParamsDecodeError {
+                reason: _,
+            } => &StatusCode::BAD_REQUEST,
+            NotificationPreferencesUpdateResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            NotificationPreferencesUpdateResponseContentFailure::Forbidden => {
+                &StatusCode::FORBIDDEN
+            }
+            NotificationPreferencesUpdateResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for NotificationPreferencesUpdateResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            NotificationPreferencesUpdateResponseContentFailure::ParamsDecodeError {
+                reason: _,
+            } => "NOTIFICATION_PREFERENCES_UPDATE_PARAMS_DECODE_ERROR",
+            NotificationPreferencesUpdateResponseContentFailure::Unauthorized { reason: _ } => {
+                "NOTIFICATION_PREFERENCES_UPDATE_UNAUTHORIZED"
+            }
+            NotificationPreferencesUpdateResponseContentFailure::Forbidden => {
+                "NOTIFICATION_PREFERENCES_UPDATE_FORBIDDEN"
+            }
+            NotificationPreferencesUpdateResponseContentFailure::DatabaseError { reason: _ } => {
+                "NOTIFICATION_PREFERENCES_UPDATE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            NotificationPreferencesUpdateResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            NotificationPreferencesUpdateResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            NotificationPreferencesUpdateResponseContentFailure::Forbidden => {
+                "insufficient rights".to_string()
+            }
+            NotificationPreferencesUpdateResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
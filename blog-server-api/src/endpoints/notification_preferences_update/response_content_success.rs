@@ -0,0 +1,62 @@
+use blog_server_services::traits::notification_preference_service::{
+    DigestFrequency, NotificationChannel, NotificationEvent, NotificationPreferences,
+};
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+/// Events enabled for each channel, after the update.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferencesUpdateContainer {
+    pub telegram: Vec<NotificationEvent>,
+    pub email: Vec<NotificationEvent>,
+    pub in_app: Vec<NotificationEvent>,
+    pub digest: DigestFrequency,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesUpdateContainer {
+    fn from(value: NotificationPreferences) -> Self {
+        NotificationPreferencesUpdateContainer {
+            telegram: value.events(NotificationChannel::Telegram),
+            email: value.events(NotificationChannel::Email),
+            in_app: value.events(NotificationChannel::InApp),
+            digest: value.digest_frequency(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationPreferencesUpdateResponseContentSuccess {
+    container: NotificationPreferencesUpdateContainer,
+}
+
+impl Into<NotificationPreferencesUpdateResponseContentSuccess>
+    for NotificationPreferencesUpdateContainer
+{
+    fn into(self) -> NotificationPreferencesUpdateResponseContentSuccess {
+        NotificationPreferencesUpdateResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for NotificationPreferencesUpdateResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for NotificationPreferencesUpdateResponseContentSuccess {
+    type Data = NotificationPreferencesUpdateContainer;
+
+    fn identifier(&self) -> &'static str {
+        "NOTIFICATION_PREFERENCES_UPDATE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("notification preferences updated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
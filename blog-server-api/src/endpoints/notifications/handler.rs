@@ -0,0 +1,49 @@
+use super::request_content::NotificationsRequestContent;
+use super::response_content_failure::NotificationsResponseContentFailure;
+use super::response_content_failure::NotificationsResponseContentFailure::*;
+use super::response_content_success::{
+    NotificationsContainer, NotificationsResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (NotificationsRequestContent {
+        offset,
+        limit,
+        notification_service,
+        auth_author_future,
+    },): (NotificationsRequestContent,),
+) -> Result<NotificationsResponseContentSuccess, NotificationsResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let offset = offset.unwrap_or(0).max(0);
+    let limit = limit.unwrap_or(50).max(0).min(50);
+
+    let (notifications_result, total_result, unread_result) = tokio::join!(
+        notification_service.notifications_by_author_id(&author.id, &offset, &limit),
+        notification_service.notifications_count_by_author_id(&author.id),
+        notification_service.unread_notifications_count_by_author_id(&author.id),
+    );
+
+    let notifications = notifications_result.map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    let total = total_result.map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    let unread = unread_result.map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(NotificationsContainer {
+        notifications: notifications.into_iter().map(|n| n.into()).collect(),
+        total,
+        unread,
+        offset,
+        limit,
+    }
+    .into())
+}
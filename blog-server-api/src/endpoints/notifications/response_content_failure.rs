@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum NotificationsResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for NotificationsResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            NotificationsResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            NotificationsResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for NotificationsResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            NotificationsResponseContentFailure::Unauthorized { reason: _ } => {
+                "NOTIFICATIONS_UNAUTHORIZED"
+            }
+            NotificationsResponseContentFailure::DatabaseError { reason: _ } => {
+                "NOTIFICATIONS_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            NotificationsResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            NotificationsResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
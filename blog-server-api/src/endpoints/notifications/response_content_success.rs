@@ -0,0 +1,75 @@
+use blog_server_services::traits::audit_service::AuditAction;
+use blog_server_services::traits::notification_preference_service::NotificationEvent;
+use blog_server_services::traits::notification_service::Notification;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationEntity {
+    pub id: u64,
+    pub event: NotificationEvent,
+    pub actor_id: u64,
+    pub action: Option<AuditAction>,
+    pub link: Option<String>,
+    pub created_at: u64,
+    pub read: bool,
+}
+
+impl From<Notification> for NotificationEntity {
+    fn from(notification: Notification) -> Self {
+        NotificationEntity {
+            id: notification.id,
+            event: notification.base.event,
+            actor_id: notification.base.actor_id,
+            action: notification.base.action,
+            link: notification.base.link,
+            created_at: notification.base.created_at,
+            read: notification.base.read == 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsContainer {
+    pub notifications: Vec<NotificationEntity>,
+    pub total: u64,
+    pub unread: u64,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationsResponseContentSuccess {
+    container: NotificationsContainer,
+}
+
+impl Into<NotificationsResponseContentSuccess> for NotificationsContainer {
+    fn into(self) -> NotificationsResponseContentSuccess {
+        NotificationsResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for NotificationsResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for NotificationsResponseContentSuccess {
+    type Data = NotificationsContainer;
+
+    fn identifier(&self) -> &'static str {
+        "NOTIFICATIONS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("notifications returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
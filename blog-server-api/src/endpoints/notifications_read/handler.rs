@@ -0,0 +1,41 @@
+use super::request_content::{NotificationsReadRequestContent, NotificationsReadRequestData};
+use super::response_content_failure::NotificationsReadResponseContentFailure;
+use super::response_content_failure::NotificationsReadResponseContentFailure::*;
+use super::response_content_success::{
+    NotificationsReadContainer, NotificationsReadResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (NotificationsReadRequestContent {
+        notifications_read_data,
+        notification_service,
+        auth_author_future,
+    },): (NotificationsReadRequestContent,),
+) -> Result<NotificationsReadResponseContentSuccess, NotificationsReadResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let NotificationsReadRequestData { ids } =
+        notifications_read_data.map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?;
+
+    let marked = match ids {
+        Some(ids) => {
+            notification_service
+                .mark_notifications_read(&author.id, &ids)
+                .await
+        }
+        None => {
+            notification_service
+                .mark_all_notifications_read(&author.id)
+                .await
+        }
+    }
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(NotificationsReadContainer { marked }.into())
+}
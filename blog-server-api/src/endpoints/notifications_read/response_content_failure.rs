@@ -0,0 +1,62 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum NotificationsReadResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for NotificationsReadResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            NotificationsReadResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            NotificationsReadResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            NotificationsReadResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for NotificationsReadResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            NotificationsReadResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "NOTIFICATIONS_READ_PARAMS_DECODE_ERROR"
+            }
+            NotificationsReadResponseContentFailure::Unauthorized { reason: _ } => {
+                "NOTIFICATIONS_READ_UNAUTHORIZED"
+            }
+            NotificationsReadResponseContentFailure::DatabaseError { reason: _ } => {
+                "NOTIFICATIONS_READ_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            NotificationsReadResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            NotificationsReadResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            NotificationsReadResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
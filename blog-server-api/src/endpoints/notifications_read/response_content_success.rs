@@ -0,0 +1,43 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsReadContainer {
+    /// Notifications that were unread before the request.
+    pub marked: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationsReadResponseContentSuccess {
+    container: NotificationsReadContainer,
+}
+
+impl Into<NotificationsReadResponseContentSuccess> for NotificationsReadContainer {
+    fn into(self) -> NotificationsReadResponseContentSuccess {
+        NotificationsReadResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for NotificationsReadResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for NotificationsReadResponseContentSuccess {
+    type Data = NotificationsReadContainer;
+
+    fn identifier(&self) -> &'static str {
+        "NOTIFICATIONS_READ_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("notifications marked as read".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
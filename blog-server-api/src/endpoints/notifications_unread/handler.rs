@@ -0,0 +1,26 @@
+use super::request_content::NotificationsUnreadRequestContent;
+use super::response_content_failure::NotificationsUnreadResponseContentFailure;
+use super::response_content_failure::NotificationsUnreadResponseContentFailure::*;
+use super::response_content_success::{
+    NotificationsUnreadContainer, NotificationsUnreadResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (NotificationsUnreadRequestContent {
+        notification_service,
+        auth_author_future,
+    },): (NotificationsUnreadRequestContent,),
+) -> Result<NotificationsUnreadResponseContentSuccess, NotificationsUnreadResponseContentFailure> {
+    let author = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let count = notification_service
+        .unread_notifications_count_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(NotificationsUnreadContainer { count }.into())
+}
@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum NotificationsUnreadResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for NotificationsUnreadResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            NotificationsUnreadResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            NotificationsUnreadResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for NotificationsUnreadResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            NotificationsUnreadResponseContentFailure::Unauthorized { reason: _ } => {
+                "NOTIFICATIONS_UNREAD_UNAUTHORIZED"
+            }
+            NotificationsUnreadResponseContentFailure::DatabaseError { reason: _ } => {
+                "NOTIFICATIONS_UNREAD_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            NotificationsUnreadResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            NotificationsUnreadResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsUnreadContainer {
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationsUnreadResponseContentSuccess {
+    container: NotificationsUnreadContainer,
+}
+
+impl Into<NotificationsUnreadResponseContentSuccess> for NotificationsUnreadContainer {
+    fn into(self) -> NotificationsUnreadResponseContentSuccess {
+        NotificationsUnreadResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for NotificationsUnreadResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for NotificationsUnreadResponseContentSuccess {
+    type Data = NotificationsUnreadContainer;
+
+    fn identifier(&self) -> &'static str {
+        "NOTIFICATIONS_UNREAD_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("unread notifications counted".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
@@ -0,0 +1,42 @@
+use super::request_content::OidcAuthorizeRequestContent;
+use super::response_content_failure::OidcAuthorizeResponseContentFailure;
+use super::response_content_failure::OidcAuthorizeResponseContentFailure::*;
+use super::response_content_success::{OidcAuthorization, OidcAuthorizeResponseContentSuccess};
+use crate::utils::oidc;
+
+pub async fn http_handler(
+    (OidcAuthorizeRequestContent {
+        provider,
+        oidc_service,
+    },): (OidcAuthorizeRequestContent,),
+) -> Result<OidcAuthorizeResponseContentSuccess, OidcAuthorizeResponseContentFailure> {
+    let provider = provider
+        .filter(|p| oidc_service.provider(p).is_some())
+        .ok_or(ProviderNotFound)?;
+
+    let flow = oidc::Flow::start(&provider);
+
+    let authorization_url = oidc_service
+        .authorization_url(
+            &provider,
+            &flow.redirect_uri(),
+            &flow.state,
+            &flow.nonce(),
+            &flow.code_verifier(),
+        )
+        .await
+        .map_err(|e| ProviderError {
+            reason: e.to_string(),
+        })?;
+
+    let flow_token = flow.token().map_err(|e| TokenGeneratingError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(OidcAuthorization {
+        authorization_url,
+        flow_token,
+        expires_in: oidc::FLOW_TOKEN_LIFETIME,
+    }
+    .into())
+}
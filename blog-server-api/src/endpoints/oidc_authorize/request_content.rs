@@ -0,0 +1,23 @@
+use crate::extensions::Resolve;
+use blog_server_services::traits::oidc_service::OidcService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use std::sync::Arc;
+
+pub struct OidcAuthorizeRequestContent {
+    pub(super) provider: Option<String>,
+    pub(super) oidc_service: Arc<dyn OidcService>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for OidcAuthorizeRequestContent
+where
+    Extensions: Resolve<Arc<dyn OidcService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            provider: origin_content.path.get("provider").map(|n| n.to_owned()),
+            oidc_service: origin_content.extensions.resolve(),
+        }
+    }
+}
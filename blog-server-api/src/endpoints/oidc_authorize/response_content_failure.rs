@@ -0,0 +1,60 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum OidcAuthorizeResponseContentFailure {
+    ProviderNotFound,
+    ProviderError { reason: String },
+    TokenGeneratingError { reason: String },
+}
+
+impl ApiResponseContentBase for OidcAuthorizeResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            OidcAuthorizeResponseContentFailure::ProviderNotFound => &StatusCode::NOT_FOUND,
+            OidcAuthorizeResponseContentFailure::ProviderError { reason: _ } => {
+                &StatusCode::BAD_GATEWAY
+            }
+            OidcAuthorizeResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for OidcAuthorizeResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            OidcAuthorizeResponseContentFailure::ProviderNotFound => {
+                "OIDC_AUTHORIZE_PROVIDER_NOT_FOUND"
+            }
+            OidcAuthorizeResponseContentFailure::ProviderError { reason: _ } => {
+                "OIDC_AUTHORIZE_PROVIDER_ERROR"
+            }
+            OidcAuthorizeResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "OIDC_AUTHORIZE_TOKEN_GENERATING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            OidcAuthorizeResponseContentFailure::ProviderNotFound => {
+                "unknown login provider".to_string()
+            }
+            OidcAuthorizeResponseContentFailure::ProviderError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("provider error: {}", reason)
+                } else {
+                    "internal provider error".to_string()
+                }
+            }
+            OidcAuthorizeResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+        })
+    }
+}
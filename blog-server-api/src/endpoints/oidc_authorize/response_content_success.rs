@@ -0,0 +1,48 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OidcAuthorization {
+    /// Where the client should send the user.
+    pub authorization_url: String,
+    /// Kept by the client and posted back together with the code.
+    pub flow_token: String,
+    pub expires_in: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcAuthorizeResponseContentSuccess {
+    authorization: OidcAuthorization,
+}
+
+impl Into<OidcAuthorizeResponseContentSuccess> for OidcAuthorization {
+    fn into(self) -> OidcAuthorizeResponseContentSuccess {
+        OidcAuthorizeResponseContentSuccess {
+            authorization: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for OidcAuthorizeResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for OidcAuthorizeResponseContentSuccess {
+    type Data = OidcAuthorization;
+
+    fn identifier(&self) -> &'static str {
+        "OIDC_AUTHORIZE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("authorization url and flow token generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.authorization
+    }
+}
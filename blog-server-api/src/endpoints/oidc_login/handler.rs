@@ -0,0 +1,44 @@
+use super::request_content::OidcLoginRequestContent;
+use super::response_content_failure::OidcLoginResponseContentFailure;
+use super::response_content_failure::OidcLoginResponseContentFailure::*;
+use super::response_content_success::OidcLoginResponseContentSuccess;
+
+use crate::utils::*;
+
+pub async fn http_handler(
+    (OidcLoginRequestContent {
+        provider,
+        oidc_login_data,
+        oidc_service,
+        social_service,
+        session_service,
+        user_agent,
+    },): (OidcLoginRequestContent,),
+) -> Result<OidcLoginResponseContentSuccess, OidcLoginResponseContentFailure> {
+    let oidc_login_data = oidc_login_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let social_author = social_auth::oidc(provider, oidc_login_data, oidc_service)
+        .await
+        .map_err(|e| match e {
+            social_auth::Error::ProviderNotFound => ProviderNotFound,
+            social_auth::Error::InvalidFlow => InvalidFlow,
+            social_auth::Error::Provider(reason) => ProviderError { reason },
+        })?;
+
+    let author = social_service
+        .process_auth_by_id(&social_author.social_id, &social_author.base_minimal_author)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let session_tokens = auth::start_session(author, user_agent, session_service)
+        .await
+        .map_err(|e| TokenGeneratingError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(session_tokens.into())
+}
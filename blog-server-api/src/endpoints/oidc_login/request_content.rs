@@ -0,0 +1,37 @@
+use crate::extensions::Resolve;
+use crate::utils::{auth, oidc};
+use blog_server_services::traits::oidc_service::OidcService;
+use blog_server_services::traits::session_service::SessionService;
+use blog_server_services::traits::social_service::SocialService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use std::sync::Arc;
+
+pub struct OidcLoginRequestContent {
+    pub(super) provider: Option<String>,
+    pub(super) oidc_login_data: DResult<oidc::Callback>,
+    pub(super) oidc_service: Arc<dyn OidcService>,
+    pub(super) social_service: Arc<dyn SocialService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+    pub(super) user_agent: Option<String>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for OidcLoginRequestContent
+where
+    Extensions: Resolve<Arc<dyn OidcService>>
+        + Resolve<Arc<dyn SocialService>>
+        + Resolve<Arc<dyn SessionService>>,
+{
+    type Data = oidc::Callback;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            provider: origin_content.path.get("provider").map(|n| n.to_owned()),
+            oidc_login_data: origin_content.data_result,
+            oidc_service: origin_content.extensions.resolve(),
+            social_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+            user_agent: auth::user_agent(&origin_content.http_parts),
+        }
+    }
+}
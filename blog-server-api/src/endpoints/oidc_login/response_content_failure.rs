@@ -0,0 +1,88 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum OidcLoginResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    ProviderNotFound,
+    InvalidFlow,
+    ProviderError { reason: String },
+    DatabaseError { reason: String },
+    TokenGeneratingError { reason: String },
+}
+
+impl ApiResponseContentBase for OidcLoginResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            OidcLoginResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            OidcLoginResponseContentFailure::ProviderNotFound => &StatusCode::NOT_FOUND,
+            OidcLoginResponseContentFailure::InvalidFlow => &StatusCode::BAD_REQUEST,
+            OidcLoginResponseContentFailure::ProviderError { reason: _ } => {
+                &StatusCode::BAD_GATEWAY
+            }
+            OidcLoginResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            OidcLoginResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for OidcLoginResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            OidcLoginResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "OIDC_LOGIN_PARAMS_ERROR"
+            }
+            OidcLoginResponseContentFailure::ProviderNotFound => "OIDC_LOGIN_PROVIDER_NOT_FOUND",
+            OidcLoginResponseContentFailure::InvalidFlow => "OIDC_LOGIN_INVALID_FLOW",
+            OidcLoginResponseContentFailure::ProviderError { reason: _ } => {
+                "OIDC_LOGIN_PROVIDER_ERROR"
+            }
+            OidcLoginResponseContentFailure::DatabaseError { reason: _ } => {
+                "OIDC_LOGIN_DATABASE_ERROR"
+            }
+            OidcLoginResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "OIDC_LOGIN_TOKEN_GENERATING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            OidcLoginResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            OidcLoginResponseContentFailure::ProviderNotFound => {
+                "unknown login provider".to_string()
+            }
+            OidcLoginResponseContentFailure::InvalidFlow => {
+                "login flow expired or doesn't match the callback".to_string()
+            }
+            OidcLoginResponseContentFailure::ProviderError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("provider error: {}", reason)
+                } else {
+                    "internal provider error".to_string()
+                }
+            }
+            OidcLoginResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            OidcLoginResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+        })
+    }
+}
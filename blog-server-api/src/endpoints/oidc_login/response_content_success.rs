@@ -0,0 +1,38 @@
+use crate::utils::auth::SessionTokens;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct OidcLoginResponseContentSuccess {
+    session_tokens: SessionTokens,
+}
+
+impl Into<OidcLoginResponseContentSuccess> for SessionTokens {
+    fn into(self) -> OidcLoginResponseContentSuccess {
+        OidcLoginResponseContentSuccess {
+            session_tokens: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for OidcLoginResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for OidcLoginResponseContentSuccess {
+    type Data = SessionTokens;
+
+    fn identifier(&self) -> &'static str {
+        "OIDC_LOGIN_SUCCESS"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("oidc login success and session tokens generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.session_tokens
+    }
+}
@@ -0,0 +1,18 @@
+use super::request_content::OidcProvidersRequestContent;
+use super::response_content_failure::OidcProvidersResponseContentFailure;
+use super::response_content_success::{
+    OidcProvider, OidcProviders, OidcProvidersResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (OidcProvidersRequestContent { oidc_service },): (OidcProvidersRequestContent,),
+) -> Result<OidcProvidersResponseContentSuccess, OidcProvidersResponseContentFailure> {
+    Ok(OidcProviders {
+        providers: oidc_service
+            .providers()
+            .into_iter()
+            .map(|(name, title)| OidcProvider { name, title })
+            .collect(),
+    }
+    .into())
+}
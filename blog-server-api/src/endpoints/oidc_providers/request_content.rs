@@ -0,0 +1,21 @@
+use crate::extensions::Resolve;
+use blog_server_services::traits::oidc_service::OidcService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use std::sync::Arc;
+
+pub struct OidcProvidersRequestContent {
+    pub(super) oidc_service: Arc<dyn OidcService>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for OidcProvidersRequestContent
+where
+    Extensions: Resolve<Arc<dyn OidcService>>,
+{
+    type Data = ();
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            oidc_service: origin_content.extensions.resolve(),
+        }
+    }
+}
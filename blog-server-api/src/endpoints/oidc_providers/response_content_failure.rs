@@ -0,0 +1,21 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+/// Listing configured providers can't fail.
+pub enum OidcProvidersResponseContentFailure {}
+
+impl ApiResponseContentBase for OidcProvidersResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match *self {}
+    }
+}
+
+impl ApiResponseContentFailure for OidcProvidersResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match *self {}
+    }
+
+    fn reason(&self) -> Option<String> {
+        match *self {}
+    }
+}
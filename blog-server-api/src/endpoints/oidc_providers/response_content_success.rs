@@ -0,0 +1,49 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OidcProvider {
+    pub name: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OidcProviders {
+    pub providers: Vec<OidcProvider>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcProvidersResponseContentSuccess {
+    providers: OidcProviders,
+}
+
+impl Into<OidcProvidersResponseContentSuccess> for OidcProviders {
+    fn into(self) -> OidcProvidersResponseContentSuccess {
+        OidcProvidersResponseContentSuccess { providers: self }
+    }
+}
+
+impl ApiResponseContentBase for OidcProvidersResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for OidcProvidersResponseContentSuccess {
+    type Data = OidcProviders;
+
+    fn identifier(&self) -> &'static str {
+        "OIDC_PROVIDERS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("configured login providers returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.providers
+    }
+}
@@ -0,0 +1,370 @@
+use super::request_content::{PasswordResetRequestContent, PasswordResetRequestData};
+use super::response_content_failure::PasswordResetResponseContentFailure;
+use super::response_content_failure::PasswordResetResponseContentFailure::*;
+use super::response_content_success::PasswordResetResponseContentSuccess;
+use crate::utils::{auth, password};
+
+pub async fn http_handler(
+    (PasswordResetRequestContent {
+        reset_data,
+        author_service,
+        session_service,
+    },): (PasswordResetRequestContent,),
+) -> Result<PasswordResetResponseContentSuccess, PasswordResetResponseContentFailure> {
+    let PasswordResetRequestData {
+        token,
+        new_password,
+    } = reset_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let author = auth::author_by_password_reset_token(&token, author_service.clone())
+        .await
+        .map_err(|e| match e {
+            auth::Error::DatabaseError(e) => DatabaseError {
+                reason: e.to_string(),
+            },
+            e => TokenInvalid {
+                reason: e.to_string(),
+            },
+        })?;
+
+    if !password::is_acceptable(&new_password) {
+        return Err(IncorrectPassword);
+    }
+
+    let new_password_hash = password::hash(&new_password).map_err(|e| PasswordHashingError {
+        reason: e.to_string(),
+    })?;
+
+    author_service
+        .set_author_password_hash_by_id(&author.id, &new_password_hash)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    session_service
+        .revoke_sessions_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(PasswordResetResponseContentSuccess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        DeletedAuthorPosts,
+    };
+    use blog_server_services::traits::session_service::{BaseSession, Session, SessionService};
+    use screw_components::dyn_result::DResult;
+    use std::sync::{Arc, Mutex};
+
+    struct MockAuthorService {
+        author: Mutex<Author>,
+    }
+
+    #[async_trait]
+    impl AuthorService for MockAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, _id: &u64) -> DResult<Option<Author>> {
+            Ok(Some(self.author.lock().unwrap().clone()))
+        }
+
+        async fn author_by_slug(&self, _slug: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            password_hash: &String,
+        ) -> DResult<()> {
+            self.author.lock().unwrap().base.password_hash = Some(password_hash.clone());
+            Ok(())
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockSessionService;
+
+    #[async_trait]
+    impl SessionService for MockSessionService {
+        async fn create_session(&self, _session: &BaseSession) -> DResult<u64> {
+            Ok(1)
+        }
+
+        async fn active_sessions_by_author_id(&self, _author_id: &u64) -> DResult<Vec<Session>> {
+            unimplemented!()
+        }
+
+        async fn rotate_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+            _new_refresh_token_hash: &String,
+            _expires_at: &u64,
+        ) -> DResult<Option<Session>> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_rotated_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+        ) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_id(&self, _id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn revoke_sessions_by_author_id(&self, _author_id: &u64) -> DResult<()> {
+            Ok(())
+        }
+    }
+
+    fn sample_author(password_hash: Option<String>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    async fn reset(
+        token: &str,
+        author_service: Arc<MockAuthorService>,
+    ) -> Result<PasswordResetResponseContentSuccess, PasswordResetResponseContentFailure> {
+        http_handler((PasswordResetRequestContent {
+            reset_data: Ok(PasswordResetRequestData {
+                token: token.to_string(),
+                new_password: "new secret".into(),
+            }),
+            author_service,
+            session_service: Arc::new(MockSessionService),
+        },))
+        .await
+    }
+
+    #[tokio::test]
+    async fn token_resets_the_password_once() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let author = sample_author(Some(password::hash(&"secret".to_string()).unwrap()));
+        let token = auth::password_reset_token(&author).unwrap();
+        let author_service = Arc::new(MockAuthorService {
+            author: Mutex::new(author),
+        });
+
+        assert!(reset(&token, author_service.clone()).await.is_ok());
+        let password_hash = author_service
+            .author
+            .lock()
+            .unwrap()
+            .base
+            .password_hash
+            .clone();
+        assert!(password::verify(&"new secret".to_string(), &password_hash.unwrap()).is_ok());
+
+        assert!(matches!(
+            reset(&token, author_service).await,
+            Err(TokenInvalid { reason: _ })
+        ));
+    }
+
+    #[tokio::test]
+    async fn token_is_rejected_after_a_password_change() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let author = sample_author(Some(password::hash(&"secret".to_string()).unwrap()));
+        let token = auth::password_reset_token(&author).unwrap();
+        let changed = sample_author(Some(password::hash(&"changed".to_string()).unwrap()));
+        let author_service = Arc::new(MockAuthorService {
+            author: Mutex::new(changed),
+        });
+        assert!(matches!(
+            reset(&token, author_service).await,
+            Err(TokenInvalid { reason: _ })
+        ));
+    }
+
+    #[tokio::test]
+    async fn garbage_token_is_rejected() {
+        let author_service = Arc::new(MockAuthorService {
+            author: Mutex::new(sample_author(None)),
+        });
+        assert!(matches!(
+            reset("garbage", author_service).await,
+            Err(TokenInvalid { reason: _ })
+        ));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::extensions::Resolve;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::session_service::SessionService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordResetRequestData {
+    pub token: String,
+    pub new_password: String,
+}
+
+pub struct PasswordResetRequestContent {
+    pub(super) reset_data: DResult<PasswordResetRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for PasswordResetRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn SessionService>>,
+{
+    type Data = PasswordResetRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            reset_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum PasswordResetResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    TokenInvalid { reason: String },
+    IncorrectPassword,
+    DatabaseError { reason: String },
+    PasswordHashingError { reason: String },
+}
+
+impl ApiResponseContentBase for PasswordResetResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            PasswordResetResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            PasswordResetResponseContentFailure::TokenInvalid { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            PasswordResetResponseContentFailure::IncorrectPassword => &StatusCode::BAD_REQUEST,
+            PasswordResetResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            PasswordResetResponseContentFailure::PasswordHashingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for PasswordResetResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            PasswordResetResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "PASSWORD_RESET_PARAMS_ERROR"
+            }
+            PasswordResetResponseContentFailure::TokenInvalid { reason: _ } => {
+                "PASSWORD_RESET_TOKEN_INVALID"
+            }
+            PasswordResetResponseContentFailure::IncorrectPassword => {
+                "PASSWORD_RESET_INCORRECT_PASSWORD"
+            }
+            PasswordResetResponseContentFailure::DatabaseError { reason: _ } => {
+                "PASSWORD_RESET_DATABASE_ERROR"
+            }
+            PasswordResetResponseContentFailure::PasswordHashingError { reason: _ } => {
+                "PASSWORD_RESET_PASSWORD_HASHING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            PasswordResetResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            PasswordResetResponseContentFailure::TokenInvalid { reason } => {
+                format!("reset token is invalid or expired: {}", reason)
+            }
+            PasswordResetResponseContentFailure::IncorrectPassword => {
+                "new password must be from 8 to 128 characters long".to_string()
+            }
+            PasswordResetResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            PasswordResetResponseContentFailure::PasswordHashingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("password hashing error: {}", reason)
+                } else {
+                    "internal password hashing error".to_string()
+                }
+            }
+        })
+    }
+}
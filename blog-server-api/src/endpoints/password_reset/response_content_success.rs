@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetResponseContentSuccess;
+
+impl ApiResponseContentBase for PasswordResetResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for PasswordResetResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "PASSWORD_RESET_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("password changed and all sessions revoked".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
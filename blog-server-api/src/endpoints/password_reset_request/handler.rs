@@ -0,0 +1,495 @@
+use blog_server_services::traits::mail_service::Mail;
+
+use super::request_content::PasswordResetRequestRequestContent;
+use super::response_content_failure::PasswordResetRequestResponseContentFailure;
+use super::response_content_failure::PasswordResetRequestResponseContentFailure::*;
+use super::response_content_success::PasswordResetRequestResponseContentSuccess;
+use crate::utils::{auth, request_throttle};
+
+/// Reset requests from one address within the throttling window.
+const IP_MAX_REQUESTS: u64 = 10;
+/// Reset requests for one login within the window, whoever asks.
+const LOGIN_MAX_REQUESTS: u64 = 3;
+/// Reset emails one author can be sent within the window, asked by slug or by email.
+const AUTHOR_MAX_EMAILS: u64 = 3;
+
+// Answers the same way whether the author exists or not,
+// so the endpoint can't be used to find out registered emails.
+pub async fn http_handler(
+    (PasswordResetRequestRequestContent {
+        reset_request_data,
+        author_service,
+        mail_service,
+        request_throttle_service,
+        client_ip,
+    },): (PasswordResetRequestRequestContent,),
+) -> Result<PasswordResetRequestResponseContentSuccess, PasswordResetRequestResponseContentFailure>
+{
+    let retry_after = request_throttle::retry_after(
+        format!("password_reset:ip:{client_ip}"),
+        IP_MAX_REQUESTS,
+        request_throttle_service.clone(),
+    )
+    .await
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    let login = reset_request_data
+        .map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?
+        .login
+        .trim()
+        .to_owned();
+
+    if login.is_empty() {
+        return Err(LoginEmpty);
+    }
+
+    // Counted whether the author exists or not, so being blocked tells nothing either.
+    let retry_after = request_throttle::retry_after(
+        format!("password_reset:login:{}", login.to_lowercase()),
+        LOGIN_MAX_REQUESTS,
+        request_throttle_service.clone(),
+    )
+    .await
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    let author = if login.contains('@') {
+        author_service.author_by_email(&login).await
+    } else {
+        author_service.author_by_slug(&login).await
+    }
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    let Some((author, email)) = author.and_then(|a| {
+        let email = a.base.email.clone()?;
+        Some((a, email))
+    }) else {
+        return Ok(PasswordResetRequestResponseContentSuccess);
+    };
+
+    // Quietly skipped, an error here would tell the login belongs to an author.
+    let retry_after = request_throttle::retry_after(
+        format!("password_reset:author:{}", author.id),
+        AUTHOR_MAX_EMAILS,
+        request_throttle_service,
+    )
+    .await
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+    if retry_after.is_some() {
+        return Ok(PasswordResetRequestResponseContentSuccess);
+    }
+
+    let token = auth::password_reset_token(&author).map_err(|e| TokenGeneratingError {
+        reason: e.to_string(),
+    })?;
+
+    let mail = Mail {
+        to: email,
+        subject: "Password reset".to_string(),
+        body: format!(
+            "Someone requested a password reset for the account \"{slug}\".\n\n\
+            To set a new password, follow the link within an hour:\n\
+            {site_url}/password/reset?token={token}\n\n\
+            If it wasn't you, just ignore this email.",
+            slug = author.base.slug,
+            site_url = &*crate::SITE_URL,
+        ),
+        html: None,
+    };
+
+    // Sent in the background, waiting for the mail server would give away
+    // that the login exists by the response time.
+    tokio::spawn(async move {
+        if let Err(err) = mail_service.send(mail).await {
+            println!("Failed to send password reset email: {err}");
+        }
+    });
+
+    Ok(PasswordResetRequestResponseContentSuccess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::request_content::PasswordResetRequestRequestData;
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::impls::create_memory_request_throttle_service;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        DeletedAuthorPosts,
+    };
+    use blog_server_services::traits::mail_service::MailService;
+    use blog_server_services::traits::request_throttle_service::RequestThrottleService;
+    use screw_components::dyn_result::DResult;
+    use std::sync::Arc;
+    use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+
+    struct MockAuthorService {
+        author: Option<Author>,
+    }
+
+    #[async_trait]
+    impl AuthorService for MockAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, _id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_slug(&self, _slug: &String) -> DResult<Option<Author>> {
+            Ok(self.author.clone())
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            Ok(self.author.clone())
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockMailService {
+        sent: UnboundedSender<Mail>,
+    }
+
+    #[async_trait]
+    impl MailService for MockMailService {
+        async fn send(&self, mail: Mail) -> DResult<()> {
+            self.sent.send(mail).map_err(|e| e.to_string())?;
+            Ok(())
+        }
+    }
+
+    fn sample_author() -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: Some("john@example.com".into()),
+                password_hash: None,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    async fn request_reset(
+        login: &str,
+        client_ip: &str,
+        author: Option<Author>,
+        mail_service: Arc<MockMailService>,
+        request_throttle_service: Arc<dyn RequestThrottleService>,
+    ) -> Result<
+        PasswordResetRequestResponseContentSuccess,
+        PasswordResetRequestResponseContentFailure,
+    > {
+        http_handler((PasswordResetRequestRequestContent {
+            reset_request_data: Ok(PasswordResetRequestRequestData {
+                login: login.to_string(),
+            }),
+            author_service: Arc::new(MockAuthorService { author }),
+            mail_service,
+            request_throttle_service,
+            client_ip: client_ip.to_string(),
+        },))
+        .await
+    }
+
+    #[tokio::test]
+    async fn unknown_and_known_logins_get_the_same_answer() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let (sent, mut mails) = unbounded_channel();
+        let mail_service = Arc::new(MockMailService { sent });
+        let request_throttle_service = create_memory_request_throttle_service();
+        assert!(
+            request_reset(
+                "nobody",
+                "10.0.0.1",
+                None,
+                mail_service.clone(),
+                request_throttle_service.clone(),
+            )
+            .await
+            .is_ok()
+        );
+        assert!(
+            request_reset(
+                "john",
+                "10.0.0.1",
+                Some(sample_author()),
+                mail_service,
+                request_throttle_service,
+            )
+            .await
+            .is_ok()
+        );
+        let mail = mails.recv().await.unwrap();
+        assert_eq!(mail.to, "john@example.com");
+        assert!(mails.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn login_is_throttled_across_addresses() {
+        let (sent, _mails) = unbounded_channel();
+        let mail_service = Arc::new(MockMailService { sent });
+        let request_throttle_service = create_memory_request_throttle_service();
+        for address in 1..=3 {
+            assert!(
+                request_reset(
+                    "nobody",
+                    &format!("10.0.0.{address}"),
+                    None,
+                    mail_service.clone(),
+                    request_throttle_service.clone(),
+                )
+                .await
+                .is_ok()
+            );
+        }
+        assert!(matches!(
+            request_reset(
+                "Nobody",
+                "10.0.0.4",
+                None,
+                mail_service,
+                request_throttle_service,
+            )
+            .await,
+            Err(Blocked { retry_after: _ })
+        ));
+    }
+
+    #[tokio::test]
+    async fn address_is_throttled_across_logins() {
+        let (sent, _mails) = unbounded_channel();
+        let mail_service = Arc::new(MockMailService { sent });
+        let request_throttle_service = create_memory_request_throttle_service();
+        for login in 0..10 {
+            assert!(
+                request_reset(
+                    &format!("nobody{login}"),
+                    "10.0.0.1",
+                    None,
+                    mail_service.clone(),
+                    request_throttle_service.clone(),
+                )
+                .await
+                .is_ok()
+            );
+        }
+        assert!(matches!(
+            request_reset(
+                "john",
+                "10.0.0.1",
+                None,
+                mail_service,
+                request_throttle_service,
+            )
+            .await,
+            Err(Blocked { retry_after: _ })
+        ));
+    }
+
+    #[tokio::test]
+    async fn author_gets_limited_emails_by_slug_and_email_together() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let (sent, mut mails) = unbounded_channel();
+        let mail_service = Arc::new(MockMailService { sent });
+        let request_throttle_service = create_memory_request_throttle_service();
+        for login in ["john", "john@example.com"] {
+            for address in 1..=3 {
+                assert!(
+                    request_reset(
+                        login,
+                        &format!("10.0.0.{address}"),
+                        Some(sample_author()),
+                        mail_service.clone(),
+                        request_throttle_service.clone(),
+                    )
+                    .await
+                    .is_ok()
+                );
+            }
+        }
+        drop(mail_service);
+        let mut mails_count = 0;
+        while mails.recv().await.is_some() {
+            mails_count += 1;
+        }
+        assert_eq!(mails_count, AUTHOR_MAX_EMAILS);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::extensions::{Resolve, TrustedProxies};
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::mail_service::MailService;
+use blog_server_services::traits::request_throttle_service::RequestThrottleService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordResetRequestRequestData {
+    /// Author slug or email.
+    pub login: String,
+}
+
+pub struct PasswordResetRequestRequestContent {
+    pub(super) reset_request_data: DResult<PasswordResetRequestRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) mail_service: Arc<dyn MailService>,
+    pub(super) request_throttle_service: Arc<dyn RequestThrottleService>,
+    pub(super) client_ip: String,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for PasswordResetRequestRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn MailService>>
+        + Resolve<Arc<dyn RequestThrottleService>>
+        + Resolve<Arc<TrustedProxies>>,
+{
+    type Data = PasswordResetRequestRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            reset_request_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            mail_service: origin_content.extensions.resolve(),
+            request_throttle_service: origin_content.extensions.resolve(),
+            client_ip: auth::client_ip(
+                &origin_content.http_parts,
+                &origin_content.remote_addr,
+                origin_content.extensions.resolve(),
+            ),
+        }
+    }
+}
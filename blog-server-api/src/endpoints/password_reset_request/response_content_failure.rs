@@ -0,0 +1,83 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum PasswordResetRequestResponseContentFailure {
+    ParamsDecodeError { reason: String },
+    LoginEmpty,
+    DatabaseError { reason: String },
+    TokenGeneratingError { reason: String },
+    Blocked { retry_after: u64 },
+}
+
+impl ApiResponseContentBase for PasswordResetRequestResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            PasswordResetRequestResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            PasswordResetRequestResponseContentFailure::LoginEmpty => &StatusCode::BAD_REQUEST,
+            PasswordResetRequestResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            PasswordResetRequestResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            PasswordResetRequestResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for PasswordResetRequestResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            PasswordResetRequestResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "PASSWORD_RESET_REQUEST_PARAMS_ERROR"
+            }
+            PasswordResetRequestResponseContentFailure::LoginEmpty => {
+                "PASSWORD_RESET_REQUEST_LOGIN_EMPTY"
+            }
+            PasswordResetRequestResponseContentFailure::DatabaseError { reason: _ } => {
+                "PASSWORD_RESET_REQUEST_DATABASE_ERROR"
+            }
+            PasswordResetRequestResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "PASSWORD_RESET_REQUEST_TOKEN_GENERATING_ERROR"
+            }
+            PasswordResetRequestResponseContentFailure::Blocked { retry_after: _ } => {
+                "PASSWORD_RESET_REQUEST_BLOCKED"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            PasswordResetRequestResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            PasswordResetRequestResponseContentFailure::LoginEmpty => {
+                "author slug or email is empty in params".to_string()
+            }
+            PasswordResetRequestResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            PasswordResetRequestResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+            PasswordResetRequestResponseContentFailure::Blocked { retry_after } => {
+                format!(
+                    "too many password reset requests, retry in {} seconds",
+                    retry_after
+                )
+            }
+        })
+    }
+}
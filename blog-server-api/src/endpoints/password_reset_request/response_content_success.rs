@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetRequestResponseContentSuccess;
+
+impl ApiResponseContentBase for PasswordResetRequestResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for PasswordResetRequestResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "PASSWORD_RESET_REQUEST_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("password reset link sent if the author has an email".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
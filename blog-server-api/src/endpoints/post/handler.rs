@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use blog_generic::entities::PostContainer;
+use blog_server_services::traits::author_service::{Author, Capability};
 use blog_server_services::traits::entity_post_service::EntityPostService;
 use blog_server_services::traits::post_service::PostService;
 
@@ -31,9 +32,11 @@ pub async fn http_handler(
         })?
         .ok_or(NotFound)?;
 
+    let viewer = auth_author_future.await.ok();
+
     if !post.base.publish_type.is_published() {
-        let have_access = if let Some(author) = auth_author_future.await.ok() {
-            post.base.author_id == author.id || author.base.editor == 1
+        let have_access = if let Some(author) = &viewer {
+            post.base.author_id == author.id || author.can(Capability::ManageAnyPost)
         } else {
             false
         };
@@ -43,7 +46,7 @@ pub async fn http_handler(
     }
 
     let post_entity = entity_post_service
-        .posts_entities(vec![post])
+        .posts_entities(vec![post], viewer.as_ref())
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
@@ -55,6 +58,7 @@ pub async fn http_handler(
 
 pub async fn direct_handler(
     id: String,
+    auth_author: Option<Author>,
     post_service: Arc<dyn PostService>,
     entity_post_service: Arc<dyn EntityPostService>,
 ) -> Option<PostContainer> {
@@ -62,7 +66,9 @@ pub async fn direct_handler(
         id,
         post_service,
         entity_post_service,
-        auth_author_future: Box::pin(std::future::ready(Err(auth::Error::TokenMissing))),
+        auth_author_future: Box::pin(std::future::ready(
+            auth_author.ok_or(auth::Error::TokenMissing),
+        )),
     },))
     .await
     .ok()
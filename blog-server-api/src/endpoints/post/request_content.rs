@@ -1,5 +1,6 @@
 use crate::{extensions::Resolve, utils::auth};
 use blog_server_services::traits::{
+    access_token_service::AccessTokenService,
     author_service::{Author, AuthorService},
     entity_post_service::EntityPostService,
     post_service::PostService,
@@ -19,7 +20,8 @@ impl<Extensions> ApiRequestContent<Extensions> for PostRequestContent
 where
     Extensions: Resolve<Arc<dyn PostService>>
         + Resolve<Arc<dyn EntityPostService>>
-        + Resolve<Arc<dyn AuthorService>>,
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>,
 {
     type Data = ();
 
@@ -35,6 +37,7 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -26,7 +26,7 @@ pub async fn http_handler(
         .ok_or(NotFound)?;
 
     let post_entity = entity_post_service
-        .posts_entities(vec![post])
+        .posts_entities(vec![post], None)
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
@@ -1,3 +1,8 @@
+use blog_server_services::traits::audit_service::{
+    AuditAction, AuditTarget, BaseAuditRecord, ModerationDecision,
+};
+use blog_server_services::traits::author_service::Capability;
+
 use super::request_content::PostUpdateRecommendedRequestContent;
 use super::response_content_failure::PostUpdateRecommendedResponseContentFailure;
 use super::response_content_failure::PostUpdateRecommendedResponseContentFailure::*;
@@ -21,6 +26,7 @@ async fn http_handler(
     PostUpdateRecommendedRequestContent {
         id,
         post_service,
+        moderation_decision_service,
         auth_author_future,
     }: PostUpdateRecommendedRequestContent,
     recommended: u8,
@@ -34,16 +40,54 @@ async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.editor != 1 {
+    if !author.can(Capability::RecommendPosts) {
         return Err(Forbidden);
     }
 
+    let post = post_service
+        .post_by_id(&id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(NotFound)?;
+
+    let action = if recommended == 1 {
+        AuditAction::PostRecommended
+    } else {
+        AuditAction::PostUnrecommended
+    };
+
     post_service
-        .set_post_recommended_by_id(&id, &recommended)
+        .set_post_recommended_by_id(
+            &id,
+            &recommended,
+            &BaseAuditRecord::new(
+                author.id,
+                action,
+                AuditTarget::Post,
+                id,
+                Some(&serde_json::json!({ "recommended": post.recommended })),
+                Some(&serde_json::json!({ "recommended": recommended })),
+            ),
+        )
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
         })?;
 
+    let moderation_decision = ModerationDecision {
+        moderator_id: author.id,
+        author_id: post.base.author_id,
+        action,
+        target_type: AuditTarget::Post,
+        target_id: id,
+    };
+    tokio::spawn(async move {
+        moderation_decision_service
+            .publish(moderation_decision)
+            .await
+    });
+
     Ok(PostUpdateRecommendedResponseContentSuccess)
 }
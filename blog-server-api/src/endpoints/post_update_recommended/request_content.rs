@@ -1,6 +1,9 @@
 use crate::extensions::Resolve;
 use crate::utils::auth;
 use blog_server_services::traits::{
+    Publish,
+    access_token_service::AccessTokenService,
+    audit_service::ModerationDecision,
     author_service::{Author, AuthorService},
     post_service::PostService,
 };
@@ -11,12 +14,16 @@ use std::sync::Arc;
 pub struct PostUpdateRecommendedRequestContent {
     pub(super) id: String,
     pub(super) post_service: Arc<dyn PostService>,
+    pub(super) moderation_decision_service: Arc<dyn Publish<ModerationDecision>>,
     pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for PostUpdateRecommendedRequestContent
 where
-    Extensions: Resolve<Arc<dyn PostService>> + Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn PostService>>
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
+        + Resolve<Arc<dyn Publish<ModerationDecision>>>,
 {
     type Data = ();
 
@@ -28,9 +35,11 @@ where
                 .map(|n| n.to_owned())
                 .unwrap_or_default(),
             post_service: origin_content.extensions.resolve(),
+            moderation_decision_service: origin_content.extensions.resolve(),
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
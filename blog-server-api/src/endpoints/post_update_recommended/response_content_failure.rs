@@ -6,6 +6,7 @@ pub enum PostUpdateRecommendedResponseContentFailure {
     Forbidden,
     DatabaseError { reason: String },
     IncorrectIdFormat { reason: String },
+    NotFound,
 }
 
 impl ApiResponseContentBase for PostUpdateRecommendedResponseContentFailure {
@@ -21,6 +22,7 @@ impl ApiResponseContentBase for PostUpdateRecommendedResponseContentFailure {
             PostUpdateRecommendedResponseContentFailure::IncorrectIdFormat { reason: _ } => {
                 &StatusCode::BAD_REQUEST
             }
+            PostUpdateRecommendedResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
         }
     }
 }
@@ -40,6 +42,9 @@ impl ApiResponseContentFailure for PostUpdateRecommendedResponseContentFailure {
             PostUpdateRecommendedResponseContentFailure::IncorrectIdFormat { reason: _ } => {
                 "POST_UPDATE_RECOMMENDED_INCORRECT_ID_FORMAT"
             }
+            PostUpdateRecommendedResponseContentFailure::NotFound => {
+                "POST_UPDATE_RECOMMENDED_NOT_FOUND"
+            }
         }
     }
 
@@ -65,6 +70,9 @@ impl ApiResponseContentFailure for PostUpdateRecommendedResponseContentFailure {
             PostUpdateRecommendedResponseContentFailure::IncorrectIdFormat { reason } => {
                 format!("incorrect value provided for post ID: {}", reason)
             }
+            PostUpdateRecommendedResponseContentFailure::NotFound => {
+                "post record not found in database".to_string()
+            }
         })
     }
 }
@@ -3,14 +3,20 @@ use std::sync::Arc;
 use crate::utils::auth;
 use blog_generic::entities::{PostsContainer, PublishType, TotalOffsetLimitContainer};
 use blog_server_services::traits::author_service::Author;
+use blog_server_services::traits::author_service::Capability;
 use blog_server_services::traits::entity_post_service::EntityPostService;
-use blog_server_services::traits::post_service::{PostService, PostsQuery, PostsQueryAnswer};
+use blog_server_services::traits::post_service::{
+    PostService, PostsQuery, PostsQueryAnswer, PostsSort,
+};
+use blog_server_services::utils::cursor::Cursor;
 use screw_components::dyn_fn::DFuture;
 
 use super::request_content::{PostsRequestContentFilter as Filter, *};
 use super::response_content_failure::PostsResponseContentFailure;
 use super::response_content_failure::PostsResponseContentFailure::*;
-use super::response_content_success::PostsResponseContentSuccess;
+use super::response_content_success::{
+    PostsCursorContainer, PostsResponseContainer, PostsResponseContentSuccess,
+};
 
 pub async fn http_handler(
     (posts_request_content,): (PostsRequestContent,),
@@ -50,6 +56,20 @@ pub async fn http_handler_hidden(
     .await
 }
 
+/// Published posts of authors and tags followed by the current author.
+pub async fn http_handler_feed(
+    (UnpublishedPostsRequestContent {
+        base: posts_request_content,
+        auth_author_future,
+    },): (UnpublishedPostsRequestContent,),
+) -> Result<PostsResponseContentSuccess, PostsResponseContentFailure> {
+    handler(
+        posts_request_content,
+        HandlerType::Feed { auth_author_future },
+    )
+    .await
+}
+
 enum HandlerTypeAuthRequired {
     Unpublished,
     Hidden,
@@ -57,6 +77,9 @@ enum HandlerTypeAuthRequired {
 
 enum HandlerType {
     Published,
+    Feed {
+        auth_author_future: DFuture<Result<Author, auth::Error>>,
+    },
     AuthRequired {
         inner_type: HandlerTypeAuthRequired,
         auth_author_future: DFuture<Result<Author, auth::Error>>,
@@ -67,6 +90,8 @@ async fn handler(
     PostsRequestContent {
         filter,
         offset,
+        cursor,
+        sort,
         limit,
         post_service,
         entity_post_service,
@@ -75,9 +100,27 @@ async fn handler(
 ) -> Result<PostsResponseContentSuccess, PostsResponseContentFailure> {
     let offset = offset.unwrap_or(0).max(0);
     let limit = limit.unwrap_or(50).max(0).min(50);
+    let cursor = match cursor {
+        None => None,
+        Some(value) if value.is_empty() => Some(None),
+        Some(value) => Some(Some(Cursor::decode(&value).ok_or(IncorrectCursor)?)),
+    };
+    let sort = match sort {
+        None => None,
+        Some(value) => Some(PostsSort::parse(&value).ok_or(IncorrectSort)?),
+    };
+    if let Some(reason) = filter.incorrect_ids {
+        return Err(IncorrectIds { reason });
+    }
 
-    let publish_type = match handler_type {
-        HandlerType::Published => PublishType::Published,
+    let (publish_type, followed_by, viewer) = match handler_type {
+        HandlerType::Published => (PublishType::Published, None, None),
+        HandlerType::Feed { auth_author_future } => {
+            let author = auth_author_future.await.map_err(|e| Unauthorized {
+                reason: e.to_string(),
+            })?;
+            (PublishType::Published, Some(author.id), Some(author))
+        }
         HandlerType::AuthRequired {
             inner_type,
             auth_author_future,
@@ -85,41 +128,63 @@ async fn handler(
             let author = auth_author_future.await.map_err(|e| Unauthorized {
                 reason: e.to_string(),
             })?;
-            if !(filter.author_id == Some(author.id) || author.base.editor == 1) {
+            let own_posts_only = !filter.author_ids.is_empty()
+                && filter.author_ids.iter().all(|id| *id == author.id);
+            if !(own_posts_only || author.can(Capability::ManageAnyPost)) {
                 return Err(Forbidden);
             }
-            match inner_type {
+            let publish_type = match inner_type {
                 HandlerTypeAuthRequired::Unpublished => PublishType::Unpublished,
                 HandlerTypeAuthRequired::Hidden => PublishType::Hidden,
-            }
+            };
+            (publish_type, None, Some(author))
         }
     };
 
-    let posts_query = PostsQuery::offset_and_limit(&offset, &limit)
-        .publish_type(Some(&publish_type))
-        .search_query(Option::from(&filter.search_query))
-        .author_id(Option::from(&filter.author_id))
-        .tag_id(Option::from(&filter.tag_id));
-
-    let PostsQueryAnswer { total_count, posts } =
-        post_service
-            .posts(posts_query)
-            .await
-            .map_err(|e| DatabaseError {
-                reason: e.to_string(),
-            })?;
+    let posts_query = match &cursor {
+        Some(cursor) => PostsQuery::cursor_and_limit(cursor.clone(), &limit),
+        None => PostsQuery::offset_and_limit(&offset, &limit),
+    }
+    .publish_type(Some(&publish_type))
+    .search_query(Option::from(&filter.search_query))
+    .author_ids(&filter.author_ids)
+    .tag_ids(&filter.tag_ids, filter.tags_match)
+    .exclude_tag_ids(&filter.exclude_tag_ids)
+    .created_between(filter.created_from, filter.created_to)
+    .followed_by(followed_by)
+    .sort(sort);
+
+    let PostsQueryAnswer {
+        total_count,
+        posts,
+        next_cursor,
+    } = post_service
+        .posts(posts_query)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
 
     let posts_entities = entity_post_service
-        .posts_entities(posts)
+        .posts_entities(posts, viewer.as_ref())
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
         })?;
 
+    if cursor.is_some() {
+        return Ok(PostsCursorContainer {
+            posts: posts_entities,
+            next_cursor: next_cursor.map(|c| c.encode()),
+            limit,
+        }
+        .into());
+    }
+
     Ok(PostsContainer {
         posts: posts_entities,
         base: TotalOffsetLimitContainer {
-            total: total_count,
+            total: total_count.unwrap_or(0),
             offset,
             limit,
         },
@@ -134,19 +199,20 @@ pub async fn direct_handler(
     entity_post_service: Arc<dyn EntityPostService>,
 ) -> Option<PostsContainer> {
     http_handler((PostsRequestContent {
-        filter: PostsRequestContentFilter {
-            search_query: None,
-            author_id: None,
-            tag_id: None,
-        },
+        filter: PostsRequestContentFilter::default(),
         offset: Some(offset),
+        cursor: None,
+        sort: None,
         limit: Some(limit),
         post_service,
         entity_post_service,
     },))
     .await
     .ok()
-    .map(|s| s.container)
+    .and_then(|s| match s.container {
+        PostsResponseContainer::Offset(container) => Some(container),
+        PostsResponseContainer::Cursor(_) => None,
+    })
 }
 
 #[cfg(test)]
@@ -155,8 +221,10 @@ mod tests {
     use async_trait::async_trait;
     use blog_generic::entities::Post as EPost;
     use blog_server_services::traits::{
+        audit_service::BaseAuditRecord,
         author_service::{
-            Author as SAuthor, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+            Author as SAuthor, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor,
+            BaseSecondaryAuthor,
         },
         entity_post_service::EntityPostService,
         post_service::{Post, PostService},
@@ -180,8 +248,9 @@ mod tests {
         ) -> DResult<PostsQueryAnswer> {
             match self.behavior {
                 PostBehavior::Success(total) => Ok(PostsQueryAnswer {
-                    total_count: total,
+                    total_count: Some(total),
                     posts: vec![],
+                    next_cursor: None,
                 }),
                 PostBehavior::Error => Err("db error".into()),
             }
@@ -203,6 +272,7 @@ mod tests {
             _id: &u64,
             _post: &blog_server_services::traits::post_service::BasePost,
             _update_created_at: &bool,
+            _audit_record: Option<&BaseAuditRecord>,
         ) -> DResult<()> {
             unimplemented!()
         }
@@ -221,7 +291,12 @@ mod tests {
         async fn random_recommended_post(&self, _post_id: &u64) -> DResult<Option<Post>> {
             unimplemented!()
         }
-        async fn set_post_recommended_by_id(&self, _id: &u64, _recommended: &u8) -> DResult<()> {
+        async fn set_post_recommended_by_id(
+            &self,
+            _id: &u64,
+            _recommended: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
             unimplemented!()
         }
 
@@ -252,7 +327,11 @@ mod tests {
 
     #[async_trait]
     impl EntityPostService for MockEntityPostService {
-        async fn posts_entities(&self, _posts: Vec<Post>) -> DResult<Vec<EPost>> {
+        async fn posts_entities(
+            &self,
+            _posts: Vec<Post>,
+            _viewer: Option<&SAuthor>,
+        ) -> DResult<Vec<EPost>> {
             match &self.behavior {
                 EntityBehavior::Success(posts) => Ok(posts.clone()),
                 EntityBehavior::Error => Err("db error".into()),
@@ -265,19 +344,17 @@ mod tests {
         entity_post_service: Arc<dyn EntityPostService>,
     ) -> PostsRequestContent {
         PostsRequestContent {
-            filter: Filter {
-                search_query: None,
-                author_id: None,
-                tag_id: None,
-            },
+            filter: Filter::default(),
             offset: None,
+            cursor: None,
+            sort: None,
             limit: None,
             post_service,
             entity_post_service,
         }
     }
 
-    fn sample_author(editor: u8, id: u64) -> SAuthor {
+    fn sample_author(role: AuthorRole, id: u64) -> SAuthor {
         SAuthor {
             id,
             base: BaseAuthor {
@@ -291,13 +368,21 @@ mod tests {
                 registered_at: 0,
                 status: None,
                 image_url: None,
-                editor,
+                role,
                 blocked: 0,
                 yandex_id: None,
                 telegram_id: None,
                 notification_subscribed: None,
                 override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
             },
+            token_scopes: None,
         }
     }
 
@@ -317,6 +402,38 @@ mod tests {
         assert!(matches!(result, Err(Unauthorized { .. })));
     }
 
+    #[tokio::test]
+    async fn feed_requires_authorization() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let request = UnpublishedPostsRequestContent {
+            base: empty_request(post_service, entity_post_service),
+            auth_author_future: Box::pin(async { Err(auth::Error::TokenMissing) }),
+        };
+        let result = http_handler_feed((request,)).await;
+        assert!(matches!(result, Err(Unauthorized { .. })));
+    }
+
+    #[tokio::test]
+    async fn feed_allowed_for_reader() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let request = UnpublishedPostsRequestContent {
+            base: empty_request(post_service, entity_post_service),
+            auth_author_future: Box::pin(async { Ok(sample_author(AuthorRole::Reader, 1)) }),
+        };
+        let result = http_handler_feed((request,)).await;
+        assert!(matches!(result, Ok(_)));
+    }
+
     #[tokio::test]
     async fn forbidden_when_author_mismatch() {
         let post_service = Arc::new(MockPostService {
@@ -328,16 +445,53 @@ mod tests {
         let request = UnpublishedPostsRequestContent {
             base: PostsRequestContent {
                 filter: Filter {
-                    search_query: None,
-                    author_id: Some(2),
-                    tag_id: None,
+                    author_ids: vec![2],
+                    ..Filter::default()
                 },
                 offset: None,
+                cursor: None,
+                sort: None,
                 limit: None,
                 post_service,
                 entity_post_service,
             },
-            auth_author_future: Box::pin(async { Ok(sample_author(0, 1)) }),
+            auth_author_future: Box::pin(async { Ok(sample_author(AuthorRole::Contributor, 1)) }),
+        };
+        let result = http_handler_unpublished((request,)).await;
+        assert!(matches!(result, Err(Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn editor_allowed_for_other_author() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let mut base = empty_request(post_service, entity_post_service);
+        base.filter.author_ids = vec![2];
+        let request = UnpublishedPostsRequestContent {
+            base,
+            auth_author_future: Box::pin(async { Ok(sample_author(AuthorRole::Editor, 1)) }),
+        };
+        let result = http_handler_unpublished((request,)).await;
+        assert!(matches!(result, Ok(_)));
+    }
+
+    #[tokio::test]
+    async fn forbidden_when_other_author_included() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let mut base = empty_request(post_service, entity_post_service);
+        base.filter.author_ids = vec![1, 2];
+        let request = UnpublishedPostsRequestContent {
+            base,
+            auth_author_future: Box::pin(async { Ok(sample_author(AuthorRole::Contributor, 1)) }),
         };
         let result = http_handler_unpublished((request,)).await;
         assert!(matches!(result, Err(Forbidden)));
@@ -378,4 +532,99 @@ mod tests {
         let result = http_handler((empty_request(post_service, entity_post_service),)).await;
         assert!(matches!(result, Ok(_)));
     }
+
+    #[tokio::test]
+    async fn incorrect_cursor_rejected() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let mut request = empty_request(post_service, entity_post_service);
+        request.cursor = Some("not a cursor".to_string());
+        let result = http_handler((request,)).await;
+        assert!(matches!(result, Err(IncorrectCursor)));
+    }
+
+    #[tokio::test]
+    async fn unknown_sort_rejected() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let mut request = empty_request(post_service, entity_post_service);
+        request.sort = Some("id; DROP TABLE post".to_string());
+        let result = http_handler((request,)).await;
+        assert!(matches!(result, Err(IncorrectSort)));
+    }
+
+    #[tokio::test]
+    async fn empty_cursor_returns_cursor_page() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let mut request = empty_request(post_service, entity_post_service);
+        request.cursor = Some(String::new());
+        let result = http_handler((request,)).await;
+        assert!(matches!(
+            result,
+            Ok(PostsResponseContentSuccess {
+                container: PostsResponseContainer::Cursor(_)
+            })
+        ));
+    }
+
+    #[test]
+    fn ids_are_merged_and_deduplicated() {
+        let (single, list) = ("3".to_string(), "1, 2,3,".to_string());
+        assert_eq!(
+            ids("author_ids", [Some(&single), Some(&list), None]),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(ids("author_ids", [None]), Ok(vec![]));
+    }
+
+    #[test]
+    fn malformed_ids_are_rejected() {
+        for value in ["1,abc", "-1", "1;2", "18446744073709551616"] {
+            let value = value.to_string();
+            assert!(ids("tag_ids", [Some(&value)]).is_err());
+        }
+    }
+
+    #[test]
+    fn id_lists_are_capped() {
+        let ids_list = |count: u64| {
+            (1..=count)
+                .map(|id| id.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        };
+        assert_eq!(
+            ids("tag_ids", [Some(&ids_list(MAX_IDS as u64))]).map(|ids| ids.len()),
+            Ok(MAX_IDS)
+        );
+        assert!(ids("tag_ids", [Some(&ids_list(MAX_IDS as u64 + 1))]).is_err());
+        assert!(ids("tag_ids", [Some(&ids_list(70_000))]).is_err());
+    }
+
+    #[tokio::test]
+    async fn incorrect_ids_rejected() {
+        let post_service = Arc::new(MockPostService {
+            behavior: PostBehavior::Success(0),
+        });
+        let entity_post_service = Arc::new(MockEntityPostService {
+            behavior: EntityBehavior::Success(vec![]),
+        });
+        let mut request = empty_request(post_service, entity_post_service);
+        request.filter.incorrect_ids = Some("'abc' is not an id in author_ids".to_string());
+        let result = http_handler((request,)).await;
+        assert!(matches!(result, Err(IncorrectIds { .. })));
+    }
 }
@@ -1,22 +1,61 @@
 use crate::{extensions::Resolve, utils::auth};
 use blog_server_services::traits::{
+    access_token_service::AccessTokenService,
     author_service::{Author, AuthorService},
     entity_post_service::EntityPostService,
-    post_service::PostService,
+    post_service::{PostService, TagsMatch},
 };
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use screw_components::dyn_fn::DFuture;
 use std::sync::Arc;
 
+#[derive(Default)]
 pub struct PostsRequestContentFilter {
     pub search_query: Option<String>,
-    pub author_id: Option<u64>,
-    pub tag_id: Option<u64>,
+    pub author_ids: Vec<u64>,
+    pub tag_ids: Vec<u64>,
+    pub tags_match: TagsMatch,
+    pub exclude_tag_ids: Vec<u64>,
+    pub created_from: Option<u64>,
+    pub created_to: Option<u64>,
+    /// Set when an id list is malformed or too long, the handler answers 400.
+    pub incorrect_ids: Option<String>,
+}
+
+/// Each id list becomes bind parameters of the posts query, so it's kept short.
+pub const MAX_IDS: usize = 50;
+
+/// Collects ids from comma separated query values of the `name` parameter.
+pub(super) fn ids<'v>(
+    name: &str,
+    values: impl IntoIterator<Item = Option<&'v String>>,
+) -> Result<Vec<u64>, String> {
+    let mut ids = Vec::new();
+    for value in values.into_iter().flatten().flat_map(|v| v.split(',')) {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        ids.push(
+            value
+                .parse()
+                .map_err(|_| format!("'{value}' is not an id in {name}"))?,
+        );
+    }
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.len() > MAX_IDS {
+        return Err(format!("at most {MAX_IDS} ids are accepted in {name}"));
+    }
+    Ok(ids)
 }
 
 pub struct PostsRequestContent {
     pub(super) filter: PostsRequestContentFilter,
     pub(super) offset: Option<u64>,
+    /// Present (even empty) switches the listing to cursor pagination.
+    pub(super) cursor: Option<String>,
+    pub(super) sort: Option<String>,
     pub(super) limit: Option<u64>,
     pub(super) post_service: Arc<dyn PostService>,
     pub(super) entity_post_service: Arc<dyn EntityPostService>,
@@ -29,28 +68,60 @@ where
     type Data = ();
 
     fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        let author_ids = ids(
+            "author_ids",
+            [
+                origin_content.query.get("author_id"),
+                origin_content.query.get("author_ids"),
+            ],
+        );
+        let tag_ids = ids(
+            "tag_ids",
+            [
+                origin_content.query.get("tag_id"),
+                origin_content.query.get("tag_ids"),
+            ],
+        );
+        let exclude_tag_ids = ids(
+            "exclude_tag_ids",
+            [origin_content.query.get("exclude_tag_ids")],
+        );
+        let incorrect_ids = [&author_ids, &tag_ids, &exclude_tag_ids]
+            .into_iter()
+            .find_map(|ids| ids.as_ref().err().cloned());
+
         Self {
             filter: PostsRequestContentFilter {
                 search_query: origin_content
                     .query
                     .get("search_query")
                     .map(|n| n.to_owned()),
-                author_id: origin_content
+                author_ids: author_ids.unwrap_or_default(),
+                tag_ids: tag_ids.unwrap_or_default(),
+                tags_match: match origin_content.query.get("tags_match").map(|v| v.as_str()) {
+                    Some("all") => TagsMatch::All,
+                    _ => TagsMatch::Any,
+                },
+                exclude_tag_ids: exclude_tag_ids.unwrap_or_default(),
+                created_from: origin_content
                     .query
-                    .get("author_id")
-                    .map(|n| n.parse().ok())
+                    .get("created_from")
+                    .map(|v| v.parse().ok())
                     .flatten(),
-                tag_id: origin_content
+                created_to: origin_content
                     .query
-                    .get("tag_id")
-                    .map(|n| n.parse().ok())
+                    .get("created_to")
+                    .map(|v| v.parse().ok())
                     .flatten(),
+                incorrect_ids,
             },
             offset: origin_content
                 .query
                 .get("offset")
                 .map(|v| v.parse().ok())
                 .flatten(),
+            cursor: origin_content.query.get("cursor").map(|v| v.to_owned()),
+            sort: origin_content.query.get("sort").map(|v| v.to_owned()),
             limit: origin_content
                 .query
                 .get("limit")
@@ -62,6 +133,7 @@ where
     }
 }
 
+/// Listings for an authorized author: unpublished, hidden and the feed.
 pub struct UnpublishedPostsRequestContent {
     pub(super) base: PostsRequestContent,
     pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
@@ -71,7 +143,8 @@ impl<Extensions> ApiRequestContent<Extensions> for UnpublishedPostsRequestConten
 where
     Extensions: Resolve<Arc<dyn PostService>>
         + Resolve<Arc<dyn EntityPostService>>
-        + Resolve<Arc<dyn AuthorService>>,
+        + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>,
 {
     type Data = ();
 
@@ -79,6 +152,7 @@ where
         let auth_author_future = Box::pin(auth::author(
             &origin_content.http_parts,
             origin_content.extensions.resolve(),
+            origin_content.extensions.resolve(),
         ));
         Self {
             base: PostsRequestContent::create(origin_content),
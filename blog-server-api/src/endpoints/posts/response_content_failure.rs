@@ -5,6 +5,9 @@ pub enum PostsResponseContentFailure {
     DatabaseError { reason: String },
     Unauthorized { reason: String },
     Forbidden,
+    IncorrectCursor,
+    IncorrectSort,
+    IncorrectIds { reason: String },
 }
 
 impl ApiResponseContentBase for PostsResponseContentFailure {
@@ -15,6 +18,9 @@ impl ApiResponseContentBase for PostsResponseContentFailure {
             }
             PostsResponseContentFailure::Unauthorized { reason: _ } => &StatusCode::UNAUTHORIZED,
             PostsResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            PostsResponseContentFailure::IncorrectCursor => &StatusCode::BAD_REQUEST,
+            PostsResponseContentFailure::IncorrectSort => &StatusCode::BAD_REQUEST,
+            PostsResponseContentFailure::IncorrectIds { reason: _ } => &StatusCode::BAD_REQUEST,
         }
     }
 }
@@ -25,6 +31,9 @@ impl ApiResponseContentFailure for PostsResponseContentFailure {
             PostsResponseContentFailure::DatabaseError { reason: _ } => "POSTS_DATABASE_ERROR",
             PostsResponseContentFailure::Unauthorized { reason: _ } => "POSTS_UNAUTHORIZED",
             PostsResponseContentFailure::Forbidden => "POSTS_FORBIDDEN",
+            PostsResponseContentFailure::IncorrectCursor => "POSTS_INCORRECT_CURSOR",
+            PostsResponseContentFailure::IncorrectSort => "POSTS_INCORRECT_SORT",
+            PostsResponseContentFailure::IncorrectIds { reason: _ } => "POSTS_INCORRECT_IDS",
         }
     }
 
@@ -45,6 +54,15 @@ impl ApiResponseContentFailure for PostsResponseContentFailure {
                 }
             }
             PostsResponseContentFailure::Forbidden => String::from("insufficient rights"),
+            PostsResponseContentFailure::IncorrectCursor => {
+                String::from("incorrect value provided for cursor")
+            }
+            PostsResponseContentFailure::IncorrectSort => String::from(
+                "incorrect value provided for sort, expected one of: newest, oldest, recently_updated, most_commented, relevance",
+            ),
+            PostsResponseContentFailure::IncorrectIds { reason } => {
+                format!("incorrect ids: {}", reason)
+            }
         })
     }
 }
@@ -1,15 +1,41 @@
-use blog_generic::entities::PostsContainer;
+use blog_generic::entities::{Post, PostsContainer};
 use hyper::StatusCode;
 use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostsCursorContainer {
+    pub posts: Vec<Post>,
+    pub next_cursor: Option<String>,
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum PostsResponseContainer {
+    Offset(PostsContainer),
+    Cursor(PostsCursorContainer),
+}
 
 #[derive(Debug, Clone)]
 pub struct PostsResponseContentSuccess {
-    pub(super) container: PostsContainer,
+    pub(super) container: PostsResponseContainer,
 }
 
 impl Into<PostsResponseContentSuccess> for PostsContainer {
     fn into(self) -> PostsResponseContentSuccess {
-        PostsResponseContentSuccess { container: self }
+        PostsResponseContentSuccess {
+            container: PostsResponseContainer::Offset(self),
+        }
+    }
+}
+
+impl Into<PostsResponseContentSuccess> for PostsCursorContainer {
+    fn into(self) -> PostsResponseContentSuccess {
+        PostsResponseContentSuccess {
+            container: PostsResponseContainer::Cursor(self),
+        }
     }
 }
 
@@ -20,7 +46,7 @@ impl ApiResponseContentBase for PostsResponseContentSuccess {
 }
 
 impl ApiResponseContentSuccess for PostsResponseContentSuccess {
-    type Data = PostsContainer;
+    type Data = PostsResponseContainer;
 
     fn identifier(&self) -> &'static str {
         "POSTS_OK"
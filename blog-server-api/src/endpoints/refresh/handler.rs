@@ -0,0 +1,36 @@
+use super::request_content::RefreshRequestContent;
+use super::response_content_failure::RefreshResponseContentFailure;
+use super::response_content_failure::RefreshResponseContentFailure::*;
+use super::response_content_success::RefreshResponseContentSuccess;
+use crate::utils::auth;
+
+pub async fn http_handler(
+    (RefreshRequestContent {
+        refresh_data,
+        author_service,
+        session_service,
+    },): (RefreshRequestContent,),
+) -> Result<RefreshResponseContentSuccess, RefreshResponseContentFailure> {
+    let refresh_token = refresh_data
+        .map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?
+        .refresh_token;
+
+    let session_tokens = auth::refresh_session(&refresh_token, author_service, session_service)
+        .await
+        .map_err(|e| match e {
+            auth::SessionError::DatabaseError(e) => DatabaseError {
+                reason: e.to_string(),
+            },
+            auth::SessionError::Token(e) => TokenGeneratingError {
+                reason: e.to_string(),
+            },
+            auth::SessionError::RefreshTokenInvalid | auth::SessionError::AuthorNotFound => {
+                RefreshTokenInvalid
+            }
+            auth::SessionError::RefreshTokenReused => RefreshTokenReused,
+        })?;
+
+    Ok(session_tokens.into())
+}
@@ -0,0 +1,34 @@
+use crate::extensions::Resolve;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::session_service::SessionService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshRequestData {
+    pub refresh_token: String,
+}
+
+pub struct RefreshRequestContent {
+    pub(super) refresh_data: DResult<RefreshRequestData>,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for RefreshRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn SessionService>>,
+{
+    type Data = RefreshRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            refresh_data: origin_content.data_result,
+            author_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+        }
+    }
+}
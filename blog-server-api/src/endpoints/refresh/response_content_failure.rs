@@ -0,0 +1,72 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum RefreshResponseContentFailure {
+    DatabaseError { reason: String },
+    ParamsDecodeError { reason: String },
+    RefreshTokenInvalid,
+    RefreshTokenReused,
+    TokenGeneratingError { reason: String },
+}
+
+impl ApiResponseContentBase for RefreshResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            RefreshResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            RefreshResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            RefreshResponseContentFailure::RefreshTokenInvalid => &StatusCode::UNAUTHORIZED,
+            RefreshResponseContentFailure::RefreshTokenReused => &StatusCode::UNAUTHORIZED,
+            RefreshResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for RefreshResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            RefreshResponseContentFailure::DatabaseError { reason: _ } => "REFRESH_DATABASE_ERROR",
+            RefreshResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "REFRESH_PARAMS_ERROR"
+            }
+            RefreshResponseContentFailure::RefreshTokenInvalid => "REFRESH_TOKEN_INVALID",
+            RefreshResponseContentFailure::RefreshTokenReused => "REFRESH_TOKEN_REUSED",
+            RefreshResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "REFRESH_TOKEN_GENERATING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            RefreshResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            RefreshResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            RefreshResponseContentFailure::RefreshTokenInvalid => {
+                "refresh token is invalid, expired or revoked".to_string()
+            }
+            RefreshResponseContentFailure::RefreshTokenReused => {
+                "refresh token was already used, the session is revoked".to_string()
+            }
+            RefreshResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::utils::auth::SessionTokens;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct RefreshResponseContentSuccess {
+    session_tokens: SessionTokens,
+}
+
+impl Into<RefreshResponseContentSuccess> for SessionTokens {
+    fn into(self) -> RefreshResponseContentSuccess {
+        RefreshResponseContentSuccess {
+            session_tokens: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for RefreshResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for RefreshResponseContentSuccess {
+    type Data = SessionTokens;
+
+    fn identifier(&self) -> &'static str {
+        "REFRESH_SUCCESS"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("session refreshed and new tokens generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.session_tokens
+    }
+}
@@ -0,0 +1,451 @@
+use blog_server_services::traits::author_service::BaseMinimalAuthor;
+use blog_server_services::utils::author_slug;
+
+use super::request_content::{RegisterRequestContent, RegisterRequestData};
+use super::response_content_failure::RegisterResponseContentFailure;
+use super::response_content_failure::RegisterResponseContentFailure::*;
+use super::response_content_success::RegisterResponseContentSuccess;
+use crate::utils::{auth, password};
+
+const NAME_MAX_LENGTH: usize = 50;
+const EMAIL_MAX_LENGTH: usize = 50;
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_owned()).filter(|v| !v.is_empty())
+}
+
+pub async fn http_handler(
+    (RegisterRequestContent {
+        register_data,
+        registration_config,
+        author_service,
+        session_service,
+        user_agent,
+    },): (RegisterRequestContent,),
+) -> Result<RegisterResponseContentSuccess, RegisterResponseContentFailure> {
+    if !registration_config.enabled {
+        return Err(RegistrationDisabled);
+    }
+
+    let RegisterRequestData {
+        slug,
+        password,
+        email,
+        first_name,
+        last_name,
+    } = register_data.map_err(|e| ParamsDecodeError {
+        reason: e.to_string(),
+    })?;
+
+    let slug = slug.trim().to_lowercase();
+    if slug.is_empty() {
+        return Err(SlugEmpty);
+    }
+    if !author_slug::is_valid(&slug) {
+        return Err(IncorrectSlug);
+    }
+    if author_slug::is_reserved(&slug) {
+        return Err(SlugReserved);
+    }
+
+    if !password::is_acceptable(&password) {
+        return Err(IncorrectPassword);
+    }
+
+    let email = non_empty(email);
+    if let Some(email) = &email {
+        if email.len() > EMAIL_MAX_LENGTH || !validator::validate_email(email) {
+            return Err(IncorrectEmail);
+        }
+    }
+
+    let first_name = non_empty(first_name);
+    let last_name = non_empty(last_name);
+    if [&first_name, &last_name]
+        .into_iter()
+        .flatten()
+        .any(|n| n.chars().count() > NAME_MAX_LENGTH)
+    {
+        return Err(IncorrectName);
+    }
+
+    let slug_taken = author_service
+        .author_by_slug(&slug)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .is_some();
+    if slug_taken {
+        return Err(SlugTaken);
+    }
+
+    if let Some(email) = &email {
+        let email_taken = author_service
+            .author_by_email(email)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?
+            .is_some();
+        if email_taken {
+            return Err(EmailTaken);
+        }
+    }
+
+    let password_hash = password::hash(&password).map_err(|e| PasswordHashingError {
+        reason: e.to_string(),
+    })?;
+
+    let id = author_service
+        .insert_password_author(
+            &BaseMinimalAuthor {
+                slug,
+                first_name,
+                last_name,
+                image_url: None,
+            },
+            email.as_ref(),
+            &password_hash,
+        )
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let author = author_service
+        .author_by_id(&id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(DatabaseError {
+            reason: "registered author not found".to_string(),
+        })?;
+
+    let session_tokens = auth::start_session(author, user_agent, session_service)
+        .await
+        .map_err(|e| TokenGeneratingError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(session_tokens.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extensions::RegistrationConfig;
+    use async_trait::async_trait;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
+    use blog_server_services::traits::author_service::{
+        Author, AuthorRole, AuthorService, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor,
+        DeletedAuthorPosts,
+    };
+    use blog_server_services::traits::session_service::{BaseSession, Session, SessionService};
+    use screw_components::dyn_result::DResult;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MockAuthorService {
+        existing_slugs: Vec<String>,
+        inserted: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AuthorService for MockAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, _id: &u64) -> DResult<Option<Author>> {
+            Ok(Some(sample_author(None)))
+        }
+
+        async fn author_by_slug(&self, slug: &String) -> DResult<Option<Author>> {
+            Ok(self
+                .existing_slugs
+                .contains(slug)
+                .then(|| sample_author(None)))
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            Ok(None)
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            self.inserted.fetch_add(1, Ordering::SeqCst);
+            Ok(1)
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    struct MockSessionService;
+
+    #[async_trait]
+    impl SessionService for MockSessionService {
+        async fn create_session(&self, _session: &BaseSession) -> DResult<u64> {
+            Ok(1)
+        }
+
+        async fn active_sessions_by_author_id(&self, _author_id: &u64) -> DResult<Vec<Session>> {
+            unimplemented!()
+        }
+
+        async fn rotate_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+            _new_refresh_token_hash: &String,
+            _expires_at: &u64,
+        ) -> DResult<Option<Session>> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_rotated_refresh_token(
+            &self,
+            _id: &u64,
+            _refresh_token_hash: &String,
+        ) -> DResult<bool> {
+            unimplemented!()
+        }
+
+        async fn revoke_session_by_id(&self, _id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn revoke_sessions_by_author_id(&self, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_author(password_hash: Option<String>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    async fn register(
+        slug: &str,
+        enabled: bool,
+        author_service: Arc<MockAuthorService>,
+    ) -> Result<RegisterResponseContentSuccess, RegisterResponseContentFailure> {
+        http_handler((RegisterRequestContent {
+            register_data: Ok(RegisterRequestData {
+                slug: slug.to_string(),
+                password: "long secret".into(),
+                email: None,
+                first_name: None,
+                last_name: None,
+            }),
+            registration_config: RegistrationConfig { enabled },
+            author_service,
+            session_service: Arc::new(MockSessionService),
+            user_agent: None,
+        },))
+        .await
+    }
+
+    fn author_service(existing_slugs: &[&str]) -> Arc<MockAuthorService> {
+        Arc::new(MockAuthorService {
+            existing_slugs: existing_slugs.iter().map(|s| s.to_string()).collect(),
+            inserted: AtomicUsize::new(0),
+        })
+    }
+
+    #[tokio::test]
+    async fn disabled_registration_is_rejected() {
+        let author_service = author_service(&[]);
+        let result = register("john", false, author_service.clone()).await;
+        assert!(matches!(result, Err(RegistrationDisabled)));
+        assert_eq!(author_service.inserted.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn reserved_slugs_are_rejected() {
+        let author_service = author_service(&[]);
+        for slug in ["admin", "me", "deleted-12"] {
+            let result = register(slug, true, author_service.clone()).await;
+            assert!(matches!(result, Err(SlugReserved)));
+        }
+        assert_eq!(author_service.inserted.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn taken_slug_is_rejected() {
+        let author_service = author_service(&["john"]);
+        let result = register(" John ", true, author_service.clone()).await;
+        assert!(matches!(result, Err(SlugTaken)));
+        assert_eq!(author_service.inserted.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn free_slug_is_registered() {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let author_service = author_service(&["john"]);
+        let result = register("jane", true, author_service.clone()).await;
+        assert!(result.is_ok());
+        assert_eq!(author_service.inserted.load(Ordering::SeqCst), 1);
+    }
+}
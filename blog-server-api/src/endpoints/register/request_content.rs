@@ -0,0 +1,45 @@
+use crate::extensions::{RegistrationConfig, Resolve};
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::session_service::SessionService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterRequestData {
+    pub slug: String,
+    pub password: String,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+pub struct RegisterRequestContent {
+    pub(super) register_data: DResult<RegisterRequestData>,
+    pub(super) registration_config: RegistrationConfig,
+    pub(super) author_service: Arc<dyn AuthorService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+    pub(super) user_agent: Option<String>,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for RegisterRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn SessionService>>
+        + Resolve<RegistrationConfig>,
+{
+    type Data = RegisterRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            register_data: origin_content.data_result,
+            registration_config: origin_content.extensions.resolve(),
+            author_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+            user_agent: auth::user_agent(&origin_content.http_parts),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum RegisterResponseContentFailure {
+    RegistrationDisabled,
+    ParamsDecodeError { reason: String },
+    SlugEmpty,
+    IncorrectSlug,
+    IncorrectPassword,
+    IncorrectEmail,
+    IncorrectName,
+    SlugTaken,
+    SlugReserved,
+    EmailTaken,
+    DatabaseError { reason: String },
+    PasswordHashingError { reason: String },
+    TokenGeneratingError { reason: String },
+}
+
+impl ApiResponseContentBase for RegisterResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            RegisterResponseContentFailure::RegistrationDisabled => &StatusCode::FORBIDDEN,
+            RegisterResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            RegisterResponseContentFailure::SlugEmpty => &StatusCode::BAD_REQUEST,
+            RegisterResponseContentFailure::IncorrectSlug => &StatusCode::BAD_REQUEST,
+            RegisterResponseContentFailure::IncorrectPassword => &StatusCode::BAD_REQUEST,
+            RegisterResponseContentFailure::IncorrectEmail => &StatusCode::BAD_REQUEST,
+            RegisterResponseContentFailure::IncorrectName => &StatusCode::BAD_REQUEST,
+            RegisterResponseContentFailure::SlugTaken => &StatusCode::CONFLICT,
+            RegisterResponseContentFailure::SlugReserved => &StatusCode::CONFLICT,
+            RegisterResponseContentFailure::EmailTaken => &StatusCode::CONFLICT,
+            RegisterResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            RegisterResponseContentFailure::PasswordHashingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+            RegisterResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for RegisterResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            RegisterResponseContentFailure::RegistrationDisabled => "REGISTER_DISABLED",
+            RegisterResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "REGISTER_PARAMS_ERROR"
+            }
+            RegisterResponseContentFailure::SlugEmpty => "REGISTER_SLUG_EMPTY",
+            RegisterResponseContentFailure::IncorrectSlug => "REGISTER_INCORRECT_SLUG",
+            RegisterResponseContentFailure::IncorrectPassword => "REGISTER_INCORRECT_PASSWORD",
+            RegisterResponseContentFailure::IncorrectEmail => "REGISTER_INCORRECT_EMAIL",
+            RegisterResponseContentFailure::IncorrectName => "REGISTER_INCORRECT_NAME",
+            RegisterResponseContentFailure::SlugTaken => "REGISTER_SLUG_TAKEN",
+            RegisterResponseContentFailure::SlugReserved => "REGISTER_SLUG_RESERVED",
+            RegisterResponseContentFailure::EmailTaken => "REGISTER_EMAIL_TAKEN",
+            RegisterResponseContentFailure::DatabaseError { reason: _ } => {
+                "REGISTER_DATABASE_ERROR"
+            }
+            RegisterResponseContentFailure::PasswordHashingError { reason: _ } => {
+                "REGISTER_PASSWORD_HASHING_ERROR"
+            }
+            RegisterResponseContentFailure::TokenGeneratingError { reason: _ } => {
+                "REGISTER_TOKEN_GENERATING_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            RegisterResponseContentFailure::RegistrationDisabled => {
+                "registration is disabled".to_string()
+            }
+            RegisterResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            RegisterResponseContentFailure::SlugEmpty => {
+                "author slug is empty in params".to_string()
+            }
+            RegisterResponseContentFailure::IncorrectSlug => {
+                "author slug may contain only lowercase latin letters, digits, '-' and '_' (up to 50 characters)".to_string()
+            }
+            RegisterResponseContentFailure::IncorrectPassword => {
+                "password must be from 8 to 128 characters long".to_string()
+            }
+            RegisterResponseContentFailure::IncorrectEmail => {
+                "incorrect email provided".to_string()
+            }
+            RegisterResponseContentFailure::IncorrectName => {
+                "first and last name must be up to 50 characters long".to_string()
+            }
+            RegisterResponseContentFailure::SlugTaken => "author slug is already taken".to_string(),
+            RegisterResponseContentFailure::SlugReserved => "author slug is reserved".to_string(),
+            RegisterResponseContentFailure::EmailTaken => {
+                "email is already used by another author".to_string()
+            }
+            RegisterResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+            RegisterResponseContentFailure::PasswordHashingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("password hashing error: {}", reason)
+                } else {
+                    "internal password hashing error".to_string()
+                }
+            }
+            RegisterResponseContentFailure::TokenGeneratingError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("token generating error: {}", reason)
+                } else {
+                    "internal token generating error".to_string()
+                }
+            }
+        })
+    }
+}
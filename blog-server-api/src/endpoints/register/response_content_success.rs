@@ -0,0 +1,38 @@
+use crate::utils::auth::SessionTokens;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct RegisterResponseContentSuccess {
+    session_tokens: SessionTokens,
+}
+
+impl Into<RegisterResponseContentSuccess> for SessionTokens {
+    fn into(self) -> RegisterResponseContentSuccess {
+        RegisterResponseContentSuccess {
+            session_tokens: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for RegisterResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for RegisterResponseContentSuccess {
+    type Data = SessionTokens;
+
+    fn identifier(&self) -> &'static str {
+        "REGISTER_SUCCESS"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("author registered and session tokens generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.session_tokens
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::extensions::Resolve;
+use crate::utils::auth;
+use blog_server_services::traits::author_service::*;
+use blog_server_services::utils::time_utils;
+
+use screw_core::request::*;
+use screw_core::response::*;
+use screw_core::routing::*;
+
+fn session_cookie_response(status: hyper::StatusCode, cookie: Option<String>) -> Response {
+    let mut builder = hyper::Response::builder().status(status);
+    if let Some(cookie) = cookie {
+        builder = builder.header(hyper::header::SET_COOKIE, cookie);
+    }
+    Response {
+        http: builder.body(screw_core::body::empty()).unwrap(),
+    }
+}
+
+/// Stores the access token passed in `Authorization: Bearer` as an HttpOnly
+/// cookie, so server side rendering can see who the page is rendered for.
+/// Clients call it again after every refresh, the cookie expires with the token.
+pub async fn set_session_cookie_handler<Extensions: Resolve<Arc<dyn AuthorService>>>(
+    request: router::RoutedRequest<Request<Extensions>>,
+) -> Response {
+    let method = request.origin.http.method();
+    let headers = request.origin.http.headers();
+
+    let Ok(token) = auth::token(method, headers) else {
+        return session_cookie_response(hyper::StatusCode::UNAUTHORIZED, None);
+    };
+
+    let Ok(session) =
+        auth::session_by_headers(method, headers, request.origin.extensions.resolve()).await
+    else {
+        return session_cookie_response(hyper::StatusCode::UNAUTHORIZED, None);
+    };
+
+    let max_age = session.expires_at.saturating_sub(time_utils::now_as_secs());
+
+    session_cookie_response(
+        hyper::StatusCode::NO_CONTENT,
+        Some(auth::session_cookie(&token, max_age)),
+    )
+}
+
+pub async fn remove_session_cookie_handler<Extensions>(
+    _: router::RoutedRequest<Request<Extensions>>,
+) -> Response {
+    session_cookie_response(
+        hyper::StatusCode::NO_CONTENT,
+        Some(auth::session_cookie("", 0)),
+    )
+}
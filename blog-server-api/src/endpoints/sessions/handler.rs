@@ -0,0 +1,32 @@
+use super::request_content::SessionsRequestContent;
+use super::response_content_failure::SessionsResponseContentFailure;
+use super::response_content_failure::SessionsResponseContentFailure::*;
+use super::response_content_success::{
+    SessionEntity, SessionsContainer, SessionsResponseContentSuccess,
+};
+
+pub async fn http_handler(
+    (SessionsRequestContent {
+        session_service,
+        auth_session_future,
+    },): (SessionsRequestContent,),
+) -> Result<SessionsResponseContentSuccess, SessionsResponseContentFailure> {
+    let auth_session = auth_session_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    let sessions = session_service
+        .active_sessions_by_author_id(&auth_session.author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(SessionsContainer {
+        sessions: sessions
+            .into_iter()
+            .map(|s| SessionEntity::new(s, auth_session.session_id))
+            .collect(),
+    }
+    .into())
+}
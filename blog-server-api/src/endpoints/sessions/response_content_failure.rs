@@ -0,0 +1,48 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum SessionsResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for SessionsResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            SessionsResponseContentFailure::Unauthorized { reason: _ } => &StatusCode::UNAUTHORIZED,
+            SessionsResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for SessionsResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            SessionsResponseContentFailure::Unauthorized { reason: _ } => "SESSIONS_UNAUTHORIZED",
+            SessionsResponseContentFailure::DatabaseError { reason: _ } => {
+                "SESSIONS_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            SessionsResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            SessionsResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
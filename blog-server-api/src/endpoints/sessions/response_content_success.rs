@@ -0,0 +1,68 @@
+use blog_server_services::traits::session_service::Session;
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionEntity {
+    pub id: u64,
+    pub user_agent: Option<String>,
+    pub created_at: u64,
+    pub last_used_at: u64,
+    pub expires_at: u64,
+    /// Whether this is the session the request was made with.
+    pub current: bool,
+}
+
+impl SessionEntity {
+    pub fn new(session: Session, current_session_id: u64) -> Self {
+        SessionEntity {
+            id: session.id,
+            user_agent: session.base.user_agent,
+            created_at: session.base.created_at,
+            last_used_at: session.base.last_used_at,
+            expires_at: session.base.expires_at,
+            current: session.id == current_session_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionsContainer {
+    pub sessions: Vec<SessionEntity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionsResponseContentSuccess {
+    container: SessionsContainer,
+}
+
+impl Into<SessionsResponseContentSuccess> for SessionsContainer {
+    fn into(self) -> SessionsResponseContentSuccess {
+        SessionsResponseContentSuccess { container: self }
+    }
+}
+
+impl ApiResponseContentBase for SessionsResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for SessionsResponseContentSuccess {
+    type Data = SessionsContainer;
+
+    fn identifier(&self) -> &'static str {
+        "SESSIONS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("active sessions returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.container
+    }
+}
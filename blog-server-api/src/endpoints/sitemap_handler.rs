@@ -20,14 +20,10 @@ pub async fn sitemap_handler<Extensions: Resolve<Arc<dyn PostService>>>(
     let post_service: Arc<dyn PostService> = request.origin.extensions.resolve();
 
     let posts = post_service
-        .posts(PostsQuery {
-            search_query: None,
-            author_id: None,
-            tag_id: None,
-            publish_type: Some(&PublishType::Published),
-            offset: &0,
-            limit: &(RECORDS_LIMIT as u64),
-        })
+        .posts(
+            PostsQuery::offset_and_limit(&0, &(RECORDS_LIMIT as u64))
+                .publish_type(Some(&PublishType::Published)),
+        )
         .await
         .map(|p| p.posts)
         .unwrap_or_else(|_| vec![]);
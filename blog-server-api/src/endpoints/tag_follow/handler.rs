@@ -0,0 +1,59 @@
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::TagFollowRequestContent;
+use super::response_content_failure::TagFollowResponseContentFailure;
+use super::response_content_failure::TagFollowResponseContentFailure::*;
+use super::response_content_success::TagFollowResponseContentSuccess;
+
+pub async fn http_handler_follow(
+    (request_content,): (TagFollowRequestContent,),
+) -> Result<TagFollowResponseContentSuccess, TagFollowResponseContentFailure> {
+    http_handler(request_content, true).await
+}
+
+pub async fn http_handler_unfollow(
+    (request_content,): (TagFollowRequestContent,),
+) -> Result<TagFollowResponseContentSuccess, TagFollowResponseContentFailure> {
+    http_handler(request_content, false).await
+}
+
+async fn http_handler(
+    TagFollowRequestContent {
+        id,
+        post_service,
+        follow_service,
+        auth_author_future,
+    }: TagFollowRequestContent,
+    follow: bool,
+) -> Result<TagFollowResponseContentSuccess, TagFollowResponseContentFailure> {
+    let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
+        reason: e.to_string(),
+    })?;
+
+    let follower = auth_author_future.await.map_err(|e| Unauthorized {
+        reason: e.to_string(),
+    })?;
+
+    if !follower.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    if follow {
+        post_service
+            .tag_by_id(&id)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?
+            .ok_or(NotFound)?;
+
+        follow_service.follow_tag(&follower.id, &id).await
+    } else {
+        follow_service.unfollow_tag(&follower.id, &id).await
+    }
+    .map_err(|e| DatabaseError {
+        reason: e.to_string(),
+    })?;
+
+    Ok(TagFollowResponseContentSuccess)
+}
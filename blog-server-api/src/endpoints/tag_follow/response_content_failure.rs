@@ -0,0 +1,70 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum TagFollowResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    IncorrectIdFormat { reason: String },
+    NotFound,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for TagFollowResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            TagFollowResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            TagFollowResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            TagFollowResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            TagFollowResponseContentFailure::NotFound => &StatusCode::NOT_FOUND,
+            TagFollowResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for TagFollowResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            TagFollowResponseContentFailure::Unauthorized { reason: _ } => {
+                "TAG_FOLLOW_UNAUTHORIZED"
+            }
+            TagFollowResponseContentFailure::Forbidden => "TAG_FOLLOW_FORBIDDEN",
+            TagFollowResponseContentFailure::IncorrectIdFormat { reason: _ } => {
+                "TAG_FOLLOW_INCORRECT_ID_FORMAT"
+            }
+            TagFollowResponseContentFailure::NotFound => "TAG_FOLLOW_NOT_FOUND",
+            TagFollowResponseContentFailure::DatabaseError { reason: _ } => {
+                "TAG_FOLLOW_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            TagFollowResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            TagFollowResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            TagFollowResponseContentFailure::IncorrectIdFormat { reason } => {
+                format!("incorrect value provided for tag ID: {}", reason)
+            }
+            TagFollowResponseContentFailure::NotFound => "tag not found".to_string(),
+            TagFollowResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct TagFollowResponseContentSuccess;
+
+impl ApiResponseContentBase for TagFollowResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for TagFollowResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "TAG_FOLLOW_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("tag follow state updated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
@@ -0,0 +1,77 @@
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::{TwoFactorDisableRequestContent, TwoFactorDisableRequestData};
+use super::response_content_failure::TwoFactorDisableResponseContentFailure;
+use super::response_content_failure::TwoFactorDisableResponseContentFailure::*;
+use super::response_content_success::TwoFactorDisableResponseContentSuccess;
+use crate::utils::{login_attempts, two_factor};
+
+pub async fn http_handler(
+    (TwoFactorDisableRequestContent {
+        two_factor_disable_data,
+        two_factor_service,
+        login_attempt_service,
+        auth_session_future,
+        client_ip,
+    },): (TwoFactorDisableRequestContent,),
+) -> Result<TwoFactorDisableResponseContentSuccess, TwoFactorDisableResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    let TwoFactorDisableRequestData { code } =
+        two_factor_disable_data.map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?;
+
+    let author_totp = two_factor::enabled_totp(&author.id, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(NotEnabled)?;
+
+    let retry_after =
+        login_attempts::begin_attempt(&author.id, &client_ip, login_attempt_service.clone())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    // A stolen access token alone must not be enough to drop the second factor.
+    let is_code_valid = two_factor::verify_code(&author_totp, &code, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if !is_code_valid {
+        return Err(WrongCode);
+    }
+
+    login_attempts::register_success(&author.id, &client_ip, login_attempt_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    two_factor_service
+        .disable_totp(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(TwoFactorDisableResponseContentSuccess)
+}
@@ -0,0 +1,51 @@
+use crate::extensions::{Resolve, TrustedProxies};
+use crate::utils::auth;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+use blog_server_services::traits::two_factor_service::TwoFactorService;
+use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
+use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorDisableRequestData {
+    /// Current TOTP code or one of the recovery codes.
+    pub code: String,
+}
+
+pub struct TwoFactorDisableRequestContent {
+    pub(super) two_factor_disable_data: DResult<TwoFactorDisableRequestData>,
+    pub(super) two_factor_service: Arc<dyn TwoFactorService>,
+    pub(super) login_attempt_service: Arc<dyn LoginAttemptService>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
+    pub(super) client_ip: String,
+}
+
+impl<Extensions> ApiRequestContent<Extensions> for TwoFactorDisableRequestContent
+where
+    Extensions: Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn TwoFactorService>>
+        + Resolve<Arc<dyn LoginAttemptService>>
+        + Resolve<Arc<TrustedProxies>>,
+{
+    type Data = TwoFactorDisableRequestData;
+
+    fn create(origin_content: ApiRequestOriginContent<Self::Data, Extensions>) -> Self {
+        Self {
+            two_factor_disable_data: origin_content.data_result,
+            two_factor_service: origin_content.extensions.resolve(),
+            login_attempt_service: origin_content.extensions.resolve(),
+            auth_session_future: Box::pin(auth::session(
+                &origin_content.http_parts,
+                origin_content.extensions.resolve(),
+            )),
+            client_ip: auth::client_ip(
+                &origin_content.http_parts,
+                &origin_content.remote_addr,
+                origin_content.extensions.resolve(),
+            ),
+        }
+    }
+}
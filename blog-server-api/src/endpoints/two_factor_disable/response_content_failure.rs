@@ -0,0 +1,88 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum TwoFactorDisableResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    ParamsDecodeError { reason: String },
+    NotEnabled,
+    WrongCode,
+    Blocked { retry_after: u64 },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for TwoFactorDisableResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            TwoFactorDisableResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            TwoFactorDisableResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            TwoFactorDisableResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            TwoFactorDisableResponseContentFailure::NotEnabled => &StatusCode::BAD_REQUEST,
+            TwoFactorDisableResponseContentFailure::WrongCode => &StatusCode::FORBIDDEN,
+            TwoFactorDisableResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
+            TwoFactorDisableResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for TwoFactorDisableResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            TwoFactorDisableResponseContentFailure::Unauthorized { reason: _ } => {
+                "TWO_FACTOR_DISABLE_UNAUTHORIZED"
+            }
+            TwoFactorDisableResponseContentFailure::Forbidden => "TWO_FACTOR_DISABLE_FORBIDDEN",
+            TwoFactorDisableResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "TWO_FACTOR_DISABLE_PARAMS_ERROR"
+            }
+            TwoFactorDisableResponseContentFailure::NotEnabled => "TWO_FACTOR_DISABLE_NOT_ENABLED",
+            TwoFactorDisableResponseContentFailure::WrongCode => "TWO_FACTOR_DISABLE_WRONG_CODE",
+            TwoFactorDisableResponseContentFailure::Blocked { retry_after: _ } => {
+                "TWO_FACTOR_DISABLE_BLOCKED"
+            }
+            TwoFactorDisableResponseContentFailure::DatabaseError { reason: _ } => {
+                "TWO_FACTOR_DISABLE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            TwoFactorDisableResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            TwoFactorDisableResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            TwoFactorDisableResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            TwoFactorDisableResponseContentFailure::NotEnabled => {
+                "two-factor authentication not enabled".to_string()
+            }
+            TwoFactorDisableResponseContentFailure::WrongCode => {
+                "wrong two-factor code passed to request".to_string()
+            }
+            TwoFactorDisableResponseContentFailure::Blocked { retry_after } => {
+                format!("too many wrong codes, retry in {} seconds", retry_after)
+            }
+            TwoFactorDisableResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
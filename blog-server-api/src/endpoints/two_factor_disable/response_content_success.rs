@@ -0,0 +1,27 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorDisableResponseContentSuccess;
+
+impl ApiResponseContentBase for TwoFactorDisableResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for TwoFactorDisableResponseContentSuccess {
+    type Data = ();
+
+    fn identifier(&self) -> &'static str {
+        "TWO_FACTOR_DISABLE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("two-factor authentication disabled and recovery codes removed".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &()
+    }
+}
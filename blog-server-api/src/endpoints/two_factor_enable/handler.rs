@@ -0,0 +1,87 @@
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::{TwoFactorEnableRequestContent, TwoFactorEnableRequestData};
+use super::response_content_failure::TwoFactorEnableResponseContentFailure;
+use super::response_content_failure::TwoFactorEnableResponseContentFailure::*;
+use super::response_content_success::{RecoveryCodes, TwoFactorEnableResponseContentSuccess};
+use crate::utils::{login_attempts, two_factor};
+
+pub async fn http_handler(
+    (TwoFactorEnableRequestContent {
+        two_factor_enable_data,
+        two_factor_service,
+        login_attempt_service,
+        auth_session_future,
+        client_ip,
+    },): (TwoFactorEnableRequestContent,),
+) -> Result<TwoFactorEnableResponseContentSuccess, TwoFactorEnableResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    let TwoFactorEnableRequestData { code } =
+        two_factor_enable_data.map_err(|e| ParamsDecodeError {
+            reason: e.to_string(),
+        })?;
+
+    let author_totp = two_factor_service
+        .totp_by_author_id(&author.id)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?
+        .ok_or(NotSetUp)?;
+
+    if author_totp.enabled != 0 {
+        return Err(AlreadyEnabled);
+    }
+
+    let retry_after =
+        login_attempts::begin_attempt(&author.id, &client_ip, login_attempt_service.clone())
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?;
+
+    if let Some(retry_after) = retry_after {
+        return Err(Blocked { retry_after });
+    }
+
+    let is_code_valid = two_factor::verify_code(&author_totp, &code, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if !is_code_valid {
+        return Err(WrongCode);
+    }
+
+    login_attempts::register_success(&author.id, &client_ip, login_attempt_service)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let recovery_codes = two_factor::generate_recovery_codes();
+    let recovery_code_hashes = recovery_codes
+        .iter()
+        .map(|c| two_factor::recovery_code_hash(c))
+        .collect();
+
+    two_factor_service
+        .enable_totp(&author.id, &recovery_code_hashes)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(RecoveryCodes { recovery_codes }.into())
+}
@@ -0,0 +1,96 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum TwoFactorEnableResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    ParamsDecodeError { reason: String },
+    NotSetUp,
+    AlreadyEnabled,
+    WrongCode,
+    Blocked { retry_after: u64 },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for TwoFactorEnableResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            TwoFactorEnableResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            TwoFactorEnableResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            TwoFactorEnableResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                &StatusCode::BAD_REQUEST
+            }
+            TwoFactorEnableResponseContentFailure::NotSetUp => &StatusCode::BAD_REQUEST,
+            TwoFactorEnableResponseContentFailure::AlreadyEnabled => &StatusCode::CONFLICT,
+            TwoFactorEnableResponseContentFailure::WrongCode => &StatusCode::FORBIDDEN,
+            TwoFactorEnableResponseContentFailure::Blocked { retry_after: _ } => {
+                &StatusCode::TOO_MANY_REQUESTS
+            }
+            TwoFactorEnableResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for TwoFactorEnableResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            TwoFactorEnableResponseContentFailure::Unauthorized { reason: _ } => {
+                "TWO_FACTOR_ENABLE_UNAUTHORIZED"
+            }
+            TwoFactorEnableResponseContentFailure::Forbidden => "TWO_FACTOR_ENABLE_FORBIDDEN",
+            TwoFactorEnableResponseContentFailure::ParamsDecodeError { reason: _ } => {
+                "TWO_FACTOR_ENABLE_PARAMS_ERROR"
+            }
+            TwoFactorEnableResponseContentFailure::NotSetUp => "TWO_FACTOR_ENABLE_NOT_SET_UP",
+            TwoFactorEnableResponseContentFailure::AlreadyEnabled => {
+                "TWO_FACTOR_ENABLE_ALREADY_ENABLED"
+            }
+            TwoFactorEnableResponseContentFailure::WrongCode => "TWO_FACTOR_ENABLE_WRONG_CODE",
+            TwoFactorEnableResponseContentFailure::Blocked { retry_after: _ } => {
+                "TWO_FACTOR_ENABLE_BLOCKED"
+            }
+            TwoFactorEnableResponseContentFailure::DatabaseError { reason: _ } => {
+                "TWO_FACTOR_ENABLE_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            TwoFactorEnableResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            TwoFactorEnableResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            TwoFactorEnableResponseContentFailure::ParamsDecodeError { reason } => {
+                format!("params error: {}", reason)
+            }
+            TwoFactorEnableResponseContentFailure::NotSetUp => {
+                "two-factor secret not generated yet".to_string()
+            }
+            TwoFactorEnableResponseContentFailure::AlreadyEnabled => {
+                "two-factor authentication already enabled".to_string()
+            }
+            TwoFactorEnableResponseContentFailure::WrongCode => {
+                "wrong two-factor code passed to request".to_string()
+            }
+            TwoFactorEnableResponseContentFailure::Blocked { retry_after } => {
+                format!("too many wrong codes, retry in {} seconds", retry_after)
+            }
+            TwoFactorEnableResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
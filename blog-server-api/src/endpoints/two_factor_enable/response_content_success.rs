@@ -0,0 +1,45 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryCodes {
+    /// Shown only once, the server keeps only their hashes.
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorEnableResponseContentSuccess {
+    recovery_codes: RecoveryCodes,
+}
+
+impl Into<TwoFactorEnableResponseContentSuccess> for RecoveryCodes {
+    fn into(self) -> TwoFactorEnableResponseContentSuccess {
+        TwoFactorEnableResponseContentSuccess {
+            recovery_codes: self,
+        }
+    }
+}
+
+impl ApiResponseContentBase for TwoFactorEnableResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for TwoFactorEnableResponseContentSuccess {
+    type Data = RecoveryCodes;
+
+    fn identifier(&self) -> &'static str {
+        "TWO_FACTOR_ENABLE_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("two-factor authentication enabled and recovery codes generated".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.recovery_codes
+    }
+}
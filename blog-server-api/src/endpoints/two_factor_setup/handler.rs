@@ -0,0 +1,55 @@
+use blog_server_services::traits::author_service::Capability;
+
+use super::request_content::TwoFactorSetupRequestContent;
+use super::response_content_failure::TwoFactorSetupResponseContentFailure;
+use super::response_content_failure::TwoFactorSetupResponseContentFailure::*;
+use super::response_content_success::{TwoFactorSetup, TwoFactorSetupResponseContentSuccess};
+use crate::utils::two_factor;
+
+pub async fn http_handler(
+    (TwoFactorSetupRequestContent {
+        two_factor_service,
+        auth_session_future,
+    },): (TwoFactorSetupRequestContent,),
+) -> Result<TwoFactorSetupResponseContentSuccess, TwoFactorSetupResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    if !author.can(Capability::EditOwnProfile) {
+        return Err(Forbidden);
+    }
+
+    // The second factor is only asked for in the password login flow.
+    if author.base.password_hash.is_none() {
+        return Err(PasswordMissing);
+    }
+
+    let author_totp = two_factor::enabled_totp(&author.id, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    if author_totp.is_some() {
+        return Err(AlreadyEnabled);
+    }
+
+    let secret = two_factor::generate_secret();
+
+    two_factor_service
+        .set_pending_totp(&author.id, &secret)
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    Ok(TwoFactorSetup {
+        otpauth_uri: two_factor::otpauth_uri(&author, &secret),
+        secret,
+    }
+    .into())
+}
@@ -0,0 +1,72 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum TwoFactorSetupResponseContentFailure {
+    Unauthorized { reason: String },
+    Forbidden,
+    PasswordMissing,
+    AlreadyEnabled,
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for TwoFactorSetupResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            TwoFactorSetupResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            TwoFactorSetupResponseContentFailure::Forbidden => &StatusCode::FORBIDDEN,
+            TwoFactorSetupResponseContentFailure::PasswordMissing => &StatusCode::BAD_REQUEST,
+            TwoFactorSetupResponseContentFailure::AlreadyEnabled => &StatusCode::CONFLICT,
+            TwoFactorSetupResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for TwoFactorSetupResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            TwoFactorSetupResponseContentFailure::Unauthorized { reason: _ } => {
+                "TWO_FACTOR_SETUP_UNAUTHORIZED"
+            }
+            TwoFactorSetupResponseContentFailure::Forbidden => "TWO_FACTOR_SETUP_FORBIDDEN",
+            TwoFactorSetupResponseContentFailure::PasswordMissing => {
+                "TWO_FACTOR_SETUP_PASSWORD_MISSING"
+            }
+            TwoFactorSetupResponseContentFailure::AlreadyEnabled => {
+                "TWO_FACTOR_SETUP_ALREADY_ENABLED"
+            }
+            TwoFactorSetupResponseContentFailure::DatabaseError { reason: _ } => {
+                "TWO_FACTOR_SETUP_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            TwoFactorSetupResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            TwoFactorSetupResponseContentFailure::Forbidden => "insufficient rights".to_string(),
+            TwoFactorSetupResponseContentFailure::PasswordMissing => {
+                "two-factor authentication requires a password".to_string()
+            }
+            TwoFactorSetupResponseContentFailure::AlreadyEnabled => {
+                "two-factor authentication already enabled".to_string()
+            }
+            TwoFactorSetupResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorSetup {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorSetupResponseContentSuccess {
+    setup: TwoFactorSetup,
+}
+
+impl Into<TwoFactorSetupResponseContentSuccess> for TwoFactorSetup {
+    fn into(self) -> TwoFactorSetupResponseContentSuccess {
+        TwoFactorSetupResponseContentSuccess { setup: self }
+    }
+}
+
+impl ApiResponseContentBase for TwoFactorSetupResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for TwoFactorSetupResponseContentSuccess {
+    type Data = TwoFactorSetup;
+
+    fn identifier(&self) -> &'static str {
+        "TWO_FACTOR_SETUP_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(
+            "secret generated, confirm it with a code to enable two-factor authentication"
+                .to_string(),
+        )
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.setup
+    }
+}
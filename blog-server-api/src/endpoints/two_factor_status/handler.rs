@@ -0,0 +1,42 @@
+use super::request_content::TwoFactorStatusRequestContent;
+use super::response_content_failure::TwoFactorStatusResponseContentFailure;
+use super::response_content_failure::TwoFactorStatusResponseContentFailure::*;
+use super::response_content_success::{TwoFactorStatus, TwoFactorStatusResponseContentSuccess};
+use crate::utils::two_factor;
+
+pub async fn http_handler(
+    (TwoFactorStatusRequestContent {
+        two_factor_service,
+        auth_session_future,
+    },): (TwoFactorStatusRequestContent,),
+) -> Result<TwoFactorStatusResponseContentSuccess, TwoFactorStatusResponseContentFailure> {
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
+
+    let author_totp = two_factor::enabled_totp(&author.id, two_factor_service.clone())
+        .await
+        .map_err(|e| DatabaseError {
+            reason: e.to_string(),
+        })?;
+
+    let recovery_codes_left = if author_totp.is_some() {
+        two_factor_service
+            .unused_recovery_codes_count(&author.id)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?
+    } else {
+        0
+    };
+
+    Ok(TwoFactorStatus {
+        enabled: author_totp.is_some(),
+        recovery_codes_left,
+    }
+    .into())
+}
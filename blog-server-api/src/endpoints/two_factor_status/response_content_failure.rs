@@ -0,0 +1,52 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentFailure};
+
+pub enum TwoFactorStatusResponseContentFailure {
+    Unauthorized { reason: String },
+    DatabaseError { reason: String },
+}
+
+impl ApiResponseContentBase for TwoFactorStatusResponseContentFailure {
+    fn status_code(&self) -> &'static StatusCode {
+        match self {
+            TwoFactorStatusResponseContentFailure::Unauthorized { reason: _ } => {
+                &StatusCode::UNAUTHORIZED
+            }
+            TwoFactorStatusResponseContentFailure::DatabaseError { reason: _ } => {
+                &StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl ApiResponseContentFailure for TwoFactorStatusResponseContentFailure {
+    fn identifier(&self) -> &'static str {
+        match self {
+            TwoFactorStatusResponseContentFailure::Unauthorized { reason: _ } => {
+                "TWO_FACTOR_STATUS_UNAUTHORIZED"
+            }
+            TwoFactorStatusResponseContentFailure::DatabaseError { reason: _ } => {
+                "TWO_FACTOR_STATUS_DATABASE_ERROR"
+            }
+        }
+    }
+
+    fn reason(&self) -> Option<String> {
+        Some(match self {
+            TwoFactorStatusResponseContentFailure::Unauthorized { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("unauthorized error: {}", reason)
+                } else {
+                    "unauthorized error".to_string()
+                }
+            }
+            TwoFactorStatusResponseContentFailure::DatabaseError { reason } => {
+                if cfg!(debug_assertions) {
+                    format!("database error: {}", reason)
+                } else {
+                    "internal database error".to_string()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use hyper::StatusCode;
+use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    pub recovery_codes_left: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorStatusResponseContentSuccess {
+    status: TwoFactorStatus,
+}
+
+impl Into<TwoFactorStatusResponseContentSuccess> for TwoFactorStatus {
+    fn into(self) -> TwoFactorStatusResponseContentSuccess {
+        TwoFactorStatusResponseContentSuccess { status: self }
+    }
+}
+
+impl ApiResponseContentBase for TwoFactorStatusResponseContentSuccess {
+    fn status_code(&self) -> &'static StatusCode {
+        &StatusCode::OK
+    }
+}
+
+impl ApiResponseContentSuccess for TwoFactorStatusResponseContentSuccess {
+    type Data = TwoFactorStatus;
+
+    fn identifier(&self) -> &'static str {
+        "TWO_FACTOR_STATUS_OK"
+    }
+
+    fn description(&self) -> Option<String> {
+        Some("two-factor authentication status returned".to_string())
+    }
+
+    fn data(&self) -> &Self::Data {
+        &self.status
+    }
+}
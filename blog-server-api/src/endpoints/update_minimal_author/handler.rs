@@ -1,3 +1,5 @@
+use blog_server_services::traits::author_service::{BaseMinimalAuthor, Capability};
+use blog_server_services::utils::author_slug;
 use validator::Validate;
 
 use super::request_content::UpdateMinimalAuthorRequestContent;
@@ -16,7 +18,7 @@ pub async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::EditOwnProfile) {
         return Err(EditingForbidden);
     }
 
@@ -30,8 +32,30 @@ pub async fn http_handler(
             reason: e.to_string(),
         })?;
 
+    let base_minimal_author = BaseMinimalAuthor::from(base_minimal_author);
+
+    // Slugs given before the rules existed are kept while the author doesn't change them.
+    if base_minimal_author.slug != author.base.slug {
+        if !author_slug::is_valid(&base_minimal_author.slug) {
+            return Err(IncorrectSlug);
+        }
+        if author_slug::is_reserved(&base_minimal_author.slug) {
+            return Err(SlugReserved);
+        }
+        let slug_taken = author_service
+            .author_by_slug(&base_minimal_author.slug)
+            .await
+            .map_err(|e| DatabaseError {
+                reason: e.to_string(),
+            })?
+            .is_some();
+        if slug_taken {
+            return Err(SlugTaken);
+        }
+    }
+
     author_service
-        .update_minimal_custom_author_by_id(&author.id, &From::from(base_minimal_author))
+        .update_minimal_custom_author_by_id(&author.id, &base_minimal_author)
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
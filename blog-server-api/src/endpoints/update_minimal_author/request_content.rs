@@ -1,4 +1,5 @@
 use blog_generic::entities::CommonMinimalAuthor;
+use blog_server_services::traits::access_token_service::AccessTokenService;
 use blog_server_services::traits::author_service::{Author, AuthorService};
 use screw_api::request::ApiRequestContent;
 use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
@@ -14,7 +15,7 @@ pub struct UpdateMinimalAuthorRequestContent {
 
 impl<Extensions> ApiRequestContent<Extensions> for UpdateMinimalAuthorRequestContent
 where
-    Extensions: Resolve<Arc<dyn AuthorService>>,
+    Extensions: Resolve<Arc<dyn AuthorService>> + Resolve<Arc<dyn AccessTokenService>>,
 {
     type Data = CommonMinimalAuthor;
 
@@ -27,6 +28,7 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
         }
     }
@@ -6,6 +6,9 @@ pub enum UpdateMinimalAuthorContentFailure {
     ValidationError { reason: String },
     Unauthorized { reason: String },
     EditingForbidden,
+    IncorrectSlug,
+    SlugTaken,
+    SlugReserved,
 }
 
 impl ApiResponseContentBase for UpdateMinimalAuthorContentFailure {
@@ -21,6 +24,9 @@ impl ApiResponseContentBase for UpdateMinimalAuthorContentFailure {
                 &StatusCode::UNAUTHORIZED
             }
             UpdateMinimalAuthorContentFailure::EditingForbidden => &StatusCode::FORBIDDEN,
+            UpdateMinimalAuthorContentFailure::IncorrectSlug => &StatusCode::BAD_REQUEST,
+            UpdateMinimalAuthorContentFailure::SlugTaken => &StatusCode::CONFLICT,
+            UpdateMinimalAuthorContentFailure::SlugReserved => &StatusCode::CONFLICT,
         }
     }
 }
@@ -40,6 +46,13 @@ impl ApiResponseContentFailure for UpdateMinimalAuthorContentFailure {
             UpdateMinimalAuthorContentFailure::EditingForbidden => {
                 "UPDATE_MINIMAL_AUTHOR_EDITING_FORBIDDEN"
             }
+            UpdateMinimalAuthorContentFailure::IncorrectSlug => {
+                "UPDATE_MINIMAL_AUTHOR_INCORRECT_SLUG"
+            }
+            UpdateMinimalAuthorContentFailure::SlugTaken => "UPDATE_MINIMAL_AUTHOR_SLUG_TAKEN",
+            UpdateMinimalAuthorContentFailure::SlugReserved => {
+                "UPDATE_MINIMAL_AUTHOR_SLUG_RESERVED"
+            }
         }
     }
 
@@ -65,6 +78,15 @@ impl ApiResponseContentFailure for UpdateMinimalAuthorContentFailure {
             UpdateMinimalAuthorContentFailure::EditingForbidden => {
                 String::from("insufficient rights to edit author")
             }
+            UpdateMinimalAuthorContentFailure::IncorrectSlug => String::from(
+                "author slug may contain only lowercase latin letters, digits, '-' and '_' (up to 50 characters)",
+            ),
+            UpdateMinimalAuthorContentFailure::SlugTaken => {
+                String::from("author slug is already taken")
+            }
+            UpdateMinimalAuthorContentFailure::SlugReserved => {
+                String::from("author slug is reserved")
+            }
         })
     }
 }
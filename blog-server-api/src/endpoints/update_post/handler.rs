@@ -1,5 +1,10 @@
 use blog_generic::entities::PublishType;
 use blog_generic::events::NewPostPublished;
+use blog_server_services::traits::audit_service::{
+    AuditAction, AuditTarget, BaseAuditRecord, ModerationDecision,
+};
+use blog_server_services::traits::author_service::Capability;
+use blog_server_services::traits::post_service::{BasePost, Post};
 use validator::Validate;
 
 use super::request_content::UpdatePostRequestContent;
@@ -15,6 +20,7 @@ pub async fn http_handler(
         entity_post_service,
         auth_author_future,
         new_post_service,
+        moderation_decision_service,
     },): (UpdatePostRequestContent,),
 ) -> Result<UpdatePostContentSuccess, UpdatePostContentFailure> {
     let id = id.parse::<u64>().map_err(|e| IncorrectIdFormat {
@@ -25,7 +31,7 @@ pub async fn http_handler(
         reason: e.to_string(),
     })?;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::WritePosts) {
         return Err(EditingForbidden);
     }
 
@@ -37,7 +43,7 @@ pub async fn http_handler(
         })?
         .ok_or(PostNotFound)?;
 
-    if !(existing_post.base.author_id == author.id || author.base.editor == 1) {
+    if !(existing_post.base.author_id == author.id || author.can(Capability::ManageAnyPost)) {
         return Err(if existing_post.base.publish_type.is_published() {
             EditingForbidden
         } else {
@@ -45,7 +51,7 @@ pub async fn http_handler(
         });
     }
 
-    if existing_post.base.publish_type.is_published() && author.base.editor == 0 {
+    if existing_post.base.publish_type.is_published() && !author.can(Capability::PublishPosts) {
         return Err(EditingForbidden);
     }
 
@@ -59,7 +65,7 @@ pub async fn http_handler(
         });
     }
 
-    if author.base.editor == 0 && base_post.publish_type.is_published() {
+    if !author.can(Capability::PublishPosts) && base_post.publish_type.is_published() {
         return Err(ValidationError {
             reason: "publishing not allowed for you".to_owned(),
         });
@@ -67,20 +73,56 @@ pub async fn http_handler(
 
     let tag_titles: Vec<String> = base_post.tags.to_owned();
     let is_published_changed = base_post.publish_type != existing_post.base.publish_type;
+    let post_data: BasePost = From::from((author.id, base_post));
 
-    post_service
-        .update_post_by_id(
-            &id,
-            &From::from((author.id, base_post)),
-            &is_published_changed,
-        )
+    let post_tags = post_service
+        .create_tags(tag_titles)
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
         })?;
 
-    let post_tags = post_service
-        .create_tags(tag_titles)
+    let is_moderated = existing_post.base.author_id != author.id;
+    let action = if !existing_post.base.publish_type.is_published()
+        && post_data.publish_type.is_published()
+    {
+        AuditAction::PostPublished
+    } else {
+        AuditAction::PostUpdated
+    };
+
+    let audit_record = is_moderated.then(|| {
+        // The post as the update leaves it, written along with the update itself.
+        let post_after = Post {
+            tags: post_tags.clone(),
+            base: BasePost {
+                author_id: existing_post.base.author_id,
+                created_at: if is_published_changed {
+                    post_data.created_at
+                } else {
+                    existing_post.base.created_at
+                },
+                ..post_data.clone()
+            },
+            ..existing_post.clone()
+        };
+        BaseAuditRecord::new(
+            author.id,
+            action,
+            AuditTarget::Post,
+            id,
+            Some(&existing_post),
+            Some(&post_after),
+        )
+    });
+
+    post_service
+        .update_post_by_id(
+            &id,
+            &post_data,
+            &is_published_changed,
+            audit_record.as_ref(),
+        )
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
@@ -103,8 +145,23 @@ pub async fn http_handler(
 
     let is_visible_published = updated_post.base.publish_type == PublishType::Published;
 
+    if is_moderated {
+        let moderation_decision = ModerationDecision {
+            moderator_id: author.id,
+            author_id: existing_post.base.author_id,
+            action,
+            target_type: AuditTarget::Post,
+            target_id: id,
+        };
+        tokio::spawn(async move {
+            moderation_decision_service
+                .publish(moderation_decision)
+                .await
+        });
+    }
+
     let updated_post_entity = entity_post_service
-        .posts_entities(vec![updated_post])
+        .posts_entities(vec![updated_post], Some(&author))
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
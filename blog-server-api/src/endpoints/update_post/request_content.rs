@@ -1,6 +1,8 @@
 use blog_generic::{entities::CommonPost, events::NewPostPublished};
 use blog_server_services::traits::{
     Publish,
+    access_token_service::AccessTokenService,
+    audit_service::ModerationDecision,
     author_service::{Author, AuthorService},
     entity_post_service::EntityPostService,
     post_service::PostService,
@@ -18,14 +20,17 @@ pub struct UpdatePostRequestContent {
     pub(super) entity_post_service: Arc<dyn EntityPostService>,
     pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
     pub(super) new_post_service: Arc<dyn Publish<NewPostPublished>>,
+    pub(super) moderation_decision_service: Arc<dyn Publish<ModerationDecision>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for UpdatePostRequestContent
 where
     Extensions: Resolve<Arc<dyn PostService>>
         + Resolve<Arc<dyn AuthorService>>
+        + Resolve<Arc<dyn AccessTokenService>>
         + Resolve<Arc<dyn EntityPostService>>
-        + Resolve<Arc<dyn Publish<NewPostPublished>>>,
+        + Resolve<Arc<dyn Publish<NewPostPublished>>>
+        + Resolve<Arc<dyn Publish<ModerationDecision>>>,
 {
     type Data = CommonPost;
 
@@ -44,8 +49,10 @@ where
             auth_author_future: Box::pin(auth::author(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
+                origin_content.extensions.resolve(),
             )),
             new_post_service: origin_content.extensions.resolve(),
+            moderation_decision_service: origin_content.extensions.resolve(),
         }
     }
 }
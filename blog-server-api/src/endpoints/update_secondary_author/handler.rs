@@ -1,37 +1,135 @@
+use blog_server_services::traits::author_service::{BaseSecondaryAuthor, Capability};
+use blog_server_services::utils::html;
 use validator::Validate;
 
-use super::request_content::UpdateSecondaryAuthorRequestContent;
+use super::request_content::{
+    UpdateSecondaryAuthorRequestContent, UpdateSecondaryAuthorRequestData,
+};
 use super::response_content_failure::UpdateSecondaryAuthorContentFailure;
 use super::response_content_failure::UpdateSecondaryAuthorContentFailure::*;
 use super::response_content_success::UpdateSecondaryAuthorContentSuccess;
 
+const BIO_MAX_LENGTH: usize = 5000;
+const LINKS_MAX_COUNT: usize = 10;
+const URL_MAX_LENGTH: usize = 255;
+const LOCATION_MAX_LENGTH: usize = 100;
+
+fn non_empty(value: String) -> Option<String> {
+    Some(value.trim().to_owned()).filter(|v| !v.is_empty())
+}
+
+fn is_valid_url(url: &str) -> bool {
+    url.len() <= URL_MAX_LENGTH
+        && (url.starts_with("https://") || url.starts_with("http://"))
+        && !url.contains(char::is_whitespace)
+        && validator::validate_url(url)
+}
+
+fn validation_error(reason: &str) -> UpdateSecondaryAuthorContentFailure {
+    ValidationError {
+        reason: reason.to_string(),
+    }
+}
+
 pub async fn http_handler(
     (UpdateSecondaryAuthorRequestContent {
         updated_secondary_author_data,
         author_service,
-        auth_author_future,
+        auth_session_future,
     },): (UpdateSecondaryAuthorRequestContent,),
 ) -> Result<UpdateSecondaryAuthorContentSuccess, UpdateSecondaryAuthorContentFailure> {
-    let author = auth_author_future.await.map_err(|e| Unauthorized {
-        reason: e.to_string(),
-    })?;
+    // Contacts are where password resets go, so they can't be changed with an access token.
+    let author = auth_session_future
+        .await
+        .map_err(|e| Unauthorized {
+            reason: e.to_string(),
+        })?
+        .author;
 
-    if author.base.blocked == 1 {
+    if !author.can(Capability::EditOwnProfile) {
         return Err(EditingForbidden);
     }
 
-    let base_secondary_author = updated_secondary_author_data.map_err(|e| ValidationError {
+    let UpdateSecondaryAuthorRequestData {
+        common,
+        bio,
+        links,
+        cover_url,
+        location,
+        email_public,
+        mobile_public,
+    } = updated_secondary_author_data.map_err(|e| ValidationError {
         reason: e.to_string(),
     })?;
 
-    base_secondary_author
-        .validate()
-        .map_err(|e| ValidationError {
-            reason: e.to_string(),
-        })?;
+    common.validate().map_err(|e| ValidationError {
+        reason: e.to_string(),
+    })?;
+
+    let bio = match bio {
+        Some(bio) => non_empty(html::clean(&bio)),
+        None => author.base.bio.clone(),
+    };
+    if bio.as_ref().is_some_and(|b| b.len() > BIO_MAX_LENGTH) {
+        return Err(validation_error("bio is too long"));
+    }
+
+    let links = match links {
+        Some(links) => {
+            let mut unique_links: Vec<String> = vec![];
+            for link in links.into_iter().filter_map(non_empty) {
+                if !is_valid_url(&link) {
+                    return Err(validation_error("links should be http or https URLs"));
+                }
+                if !unique_links.contains(&link) {
+                    unique_links.push(link);
+                }
+            }
+            if unique_links.len() > LINKS_MAX_COUNT {
+                return Err(validation_error("too many links"));
+            }
+            Some(unique_links.join(" ")).filter(|l| !l.is_empty())
+        }
+        None => author.base.links.clone(),
+    };
+
+    let cover_url = match cover_url {
+        Some(cover_url) => non_empty(cover_url),
+        None => author.base.cover_url.clone(),
+    };
+    if cover_url.as_deref().is_some_and(|u| !is_valid_url(u)) {
+        return Err(validation_error("cover should be an http or https URL"));
+    }
+
+    let location = match location {
+        Some(location) => non_empty(location),
+        None => author.base.location.clone(),
+    };
+    if location
+        .as_ref()
+        .is_some_and(|l| l.chars().count() > LOCATION_MAX_LENGTH)
+    {
+        return Err(validation_error("location is too long"));
+    }
+
+    let base_secondary_author = BaseSecondaryAuthor {
+        email: common.email,
+        mobile: common.mobile,
+        status: common.status,
+        bio,
+        links,
+        cover_url,
+        location,
+        email_public: email_public
+            .map(|p| p as u8)
+            .unwrap_or(author.base.email_public),
+        mobile_public: mobile_public
+            .map(|p| p as u8)
+            .unwrap_or(author.base.mobile_public),
+    };
 
     author_service
-        .update_secondary_author_by_id(&author.id, &From::from(base_secondary_author))
+        .update_secondary_author_by_id(&author.id, &base_secondary_author)
         .await
         .map_err(|e| DatabaseError {
             reason: e.to_string(),
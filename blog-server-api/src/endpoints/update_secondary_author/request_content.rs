@@ -1,22 +1,38 @@
 use blog_generic::entities::CommonSecondaryAuthor;
-use blog_server_services::traits::author_service::{Author, AuthorService};
+use blog_server_services::traits::author_service::AuthorService;
 use screw_api::request::ApiRequestContent;
 use screw_components::{dyn_fn::DFuture, dyn_result::DResult};
+use serde::Deserialize;
 use std::sync::Arc;
 
 use crate::{extensions::Resolve, utils::auth};
 
+/// Profile fields left out keep their current values, so older clients
+/// sending only the common ones don't wipe them.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSecondaryAuthorRequestData {
+    #[serde(flatten)]
+    pub common: CommonSecondaryAuthor,
+    pub bio: Option<String>,
+    pub links: Option<Vec<String>>,
+    pub cover_url: Option<String>,
+    pub location: Option<String>,
+    pub email_public: Option<bool>,
+    pub mobile_public: Option<bool>,
+}
+
 pub struct UpdateSecondaryAuthorRequestContent {
-    pub(super) updated_secondary_author_data: DResult<CommonSecondaryAuthor>,
+    pub(super) updated_secondary_author_data: DResult<UpdateSecondaryAuthorRequestData>,
     pub(super) author_service: Arc<dyn AuthorService>,
-    pub(super) auth_author_future: DFuture<Result<Author, auth::Error>>,
+    pub(super) auth_session_future: DFuture<Result<auth::AuthSession, auth::Error>>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for UpdateSecondaryAuthorRequestContent
 where
     Extensions: Resolve<Arc<dyn AuthorService>>,
 {
-    type Data = CommonSecondaryAuthor;
+    type Data = UpdateSecondaryAuthorRequestData;
 
     fn create(
         origin_content: screw_api::request::ApiRequestOriginContent<Self::Data, Extensions>,
@@ -24,7 +40,7 @@ where
         Self {
             updated_secondary_author_data: origin_content.data_result,
             author_service: origin_content.extensions.resolve(),
-            auth_author_future: Box::pin(auth::author(
+            auth_session_future: Box::pin(auth::session(
                 &origin_content.http_parts,
                 origin_content.extensions.resolve(),
             )),
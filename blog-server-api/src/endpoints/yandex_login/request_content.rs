@@ -1,5 +1,7 @@
 use crate::extensions::Resolve;
+use crate::utils::auth;
 use blog_generic::entities::LoginYandexQuestion;
+use blog_server_services::traits::session_service::SessionService;
 use blog_server_services::traits::social_service::SocialService;
 use screw_api::request::{ApiRequestContent, ApiRequestOriginContent};
 use screw_components::dyn_result::DResult;
@@ -8,11 +10,13 @@ use std::sync::Arc;
 pub struct LoginYandexRequestContent {
     pub(super) login_yandex_question: DResult<LoginYandexQuestion>,
     pub(super) social_service: Arc<dyn SocialService>,
+    pub(super) session_service: Arc<dyn SessionService>,
+    pub(super) user_agent: Option<String>,
 }
 
 impl<Extensions> ApiRequestContent<Extensions> for LoginYandexRequestContent
 where
-    Extensions: Resolve<Arc<dyn SocialService>>,
+    Extensions: Resolve<Arc<dyn SocialService>> + Resolve<Arc<dyn SessionService>>,
 {
     type Data = LoginYandexQuestion;
 
@@ -20,6 +24,8 @@ where
         Self {
             login_yandex_question: origin_content.data_result,
             social_service: origin_content.extensions.resolve(),
+            session_service: origin_content.extensions.resolve(),
+            user_agent: auth::user_agent(&origin_content.http_parts),
         }
     }
 }
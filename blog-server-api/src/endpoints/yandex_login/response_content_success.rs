@@ -1,16 +1,16 @@
-use blog_generic::entities::LoginAnswer;
+use crate::utils::auth::SessionTokens;
 use hyper::StatusCode;
 use screw_api::response::{ApiResponseContentBase, ApiResponseContentSuccess};
 
 #[derive(Debug, Clone)]
 pub struct LoginYandexResponseContentSuccess {
-    login_answer: LoginAnswer,
+    session_tokens: SessionTokens,
 }
 
-impl Into<LoginYandexResponseContentSuccess> for String {
+impl Into<LoginYandexResponseContentSuccess> for SessionTokens {
     fn into(self) -> LoginYandexResponseContentSuccess {
         LoginYandexResponseContentSuccess {
-            login_answer: LoginAnswer { token: self },
+            session_tokens: self,
         }
     }
 }
@@ -22,17 +22,17 @@ impl ApiResponseContentBase for LoginYandexResponseContentSuccess {
 }
 
 impl ApiResponseContentSuccess for LoginYandexResponseContentSuccess {
-    type Data = LoginAnswer;
+    type Data = SessionTokens;
 
     fn identifier(&self) -> &'static str {
         "LOGIN_YANDEX_SUCCESS"
     }
 
     fn description(&self) -> Option<String> {
-        Some("login yandex success and token generated".to_string())
+        Some("login yandex success and session tokens generated".to_string())
     }
 
     fn data(&self) -> &Self::Data {
-        &self.login_answer
+        &self.session_tokens
     }
 }
@@ -1,47 +1,156 @@
 use blog_generic::events::{NewPostPublished, SubscriptionStateChanged};
 use blog_server_services::impls::{
     create_discord_new_post_published_service, create_entity_comment_service,
-    create_entity_post_service, create_rbatis_author_service, create_rbatis_comment_service,
-    create_rbatis_post_service, create_social_service, create_telegram_new_post_published_service,
-    create_telegram_user_updates_service,
+    create_entity_post_service, create_file_mail_service, create_in_app_notification_service,
+    create_local_image_storage_service, create_oidc_service, create_rbatis_access_token_service,
+    create_rbatis_audit_service, create_rbatis_author_service, create_rbatis_author_stats_service,
+    create_rbatis_comment_service, create_rbatis_email_subscription_service,
+    create_rbatis_follow_service, create_rbatis_identity_service,
+    create_rbatis_login_attempt_service, create_rbatis_notification_preference_service,
+    create_rbatis_notification_service, create_rbatis_post_service,
+    create_rbatis_request_throttle_service, create_rbatis_session_service,
+    create_rbatis_two_factor_service, create_smtp_mail_service, create_social_service,
+    create_telegram_new_post_published_service, create_telegram_user_updates_service,
 };
+use blog_server_services::traits::access_token_service::AccessTokenService;
+use blog_server_services::traits::audit_service::{AuditService, ModerationDecision};
 use blog_server_services::traits::author_service::AuthorService;
-use blog_server_services::traits::comment_service::CommentService;
+use blog_server_services::traits::author_stats_service::AuthorStatsService;
+use blog_server_services::traits::comment_service::{CommentPublished, CommentService};
+use blog_server_services::traits::email_subscription_service::EmailSubscriptionService;
 use blog_server_services::traits::entity_comment_service::EntityCommentService;
 use blog_server_services::traits::entity_post_service::EntityPostService;
+use blog_server_services::traits::follow_service::FollowService;
+use blog_server_services::traits::identity_service::IdentityService;
+use blog_server_services::traits::image_storage_service::{
+    ImageStorageService, LocalImageStorageConfig,
+};
+use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+use blog_server_services::traits::mail_service::{FileMailConfig, Mail, MailService, SmtpConfig};
+use blog_server_services::traits::notification_preference_service::NotificationPreferenceService;
+use blog_server_services::traits::notification_service::NotificationService;
+use blog_server_services::traits::oidc_service::{OidcProviderConfig, OidcService};
 use blog_server_services::traits::post_service::PostService;
+use blog_server_services::traits::request_throttle_service::RequestThrottleService;
+use blog_server_services::traits::session_service::SessionService;
 use blog_server_services::traits::social_service::SocialService;
+use blog_server_services::traits::two_factor_service::TwoFactorService;
 use blog_server_services::traits::{Publish, PublishCollection};
 use config::Config;
 use rbatis::rbatis::RBatis;
+use screw_components::dyn_result::DResult;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 pub trait Resolve<T>: Send + Sync {
     fn resolve(&self) -> T;
 }
 
+#[derive(Clone, Copy, Default, Deserialize)]
+pub struct RegistrationConfig {
+    pub enabled: bool,
+}
+
+/// Reverse proxies in front of the server, only their `X-Forwarded-For` is
+/// believed, see `auth::client_ip`.
+#[derive(Clone, Default, Deserialize)]
+pub struct TrustedProxies(Vec<IpAddr>);
+
+impl TrustedProxies {
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        self.0.contains(ip)
+    }
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+pub struct AccountDeletionConfig {
+    /// Author receiving posts of deleted accounts, the posts are deleted when not set.
+    pub reassign_posts_to: Option<u64>,
+}
+
+impl AccountDeletionConfig {
+    /// Checked at startup, so a typo doesn't surface only when someone deletes the account.
+    pub async fn validate(&self, author_service: Arc<dyn AuthorService>) -> DResult<()> {
+        let Some(target_id) = self.reassign_posts_to else {
+            return Ok(());
+        };
+        match author_service.author_by_id(&target_id).await? {
+            Some(author) if author.base.blocked == 0 => Ok(()),
+            Some(_) => Err(format!(
+                "author {target_id} receiving posts of deleted accounts is blocked"
+            )
+            .into()),
+            None => Err(format!(
+                "author {target_id} receiving posts of deleted accounts not found"
+            )
+            .into()),
+        }
+    }
+}
+
 pub trait ExtensionsProviderType:
     Resolve<Arc<dyn AuthorService>>
+    + Resolve<Arc<dyn AuthorStatsService>>
     + Resolve<Arc<dyn PostService>>
     + Resolve<Arc<dyn CommentService>>
     + Resolve<Arc<dyn EntityCommentService>>
     + Resolve<Arc<dyn EntityPostService>>
     + Resolve<Arc<dyn Publish<NewPostPublished>>>
     + Resolve<Arc<dyn Publish<SubscriptionStateChanged>>>
+    + Resolve<Arc<dyn Publish<CommentPublished>>>
+    + Resolve<Arc<dyn Publish<ModerationDecision>>>
     + Resolve<Arc<dyn SocialService>>
+    + Resolve<Arc<dyn IdentityService>>
+    + Resolve<Arc<dyn AuditService>>
+    + Resolve<Arc<dyn SessionService>>
+    + Resolve<Arc<dyn AccessTokenService>>
+    + Resolve<Arc<dyn FollowService>>
+    + Resolve<Arc<dyn NotificationPreferenceService>>
+    + Resolve<Arc<dyn NotificationService>>
+    + Resolve<Arc<dyn EmailSubscriptionService>>
+    + Resolve<Arc<dyn TwoFactorService>>
+    + Resolve<Arc<dyn LoginAttemptService>>
+    + Resolve<Arc<dyn RequestThrottleService>>
+    + Resolve<Arc<dyn MailService>>
+    + Resolve<Arc<dyn OidcService>>
+    + Resolve<Arc<dyn ImageStorageService>>
+    + Resolve<RegistrationConfig>
+    + Resolve<AccountDeletionConfig>
+    + Resolve<Arc<TrustedProxies>>
 {
 }
 
 struct ExtensionsProvider {
     author_service: Arc<dyn AuthorService>,
+    author_stats_service: Arc<dyn AuthorStatsService>,
     post_service: Arc<dyn PostService>,
     comment_service: Arc<dyn CommentService>,
     entity_comment_service: Arc<dyn EntityCommentService>,
     entity_post_service: Arc<dyn EntityPostService>,
     new_post_published_service: Arc<dyn Publish<NewPostPublished>>,
     subscription_state_changed_service: Arc<dyn Publish<SubscriptionStateChanged>>,
+    comment_published_service: Arc<dyn Publish<CommentPublished>>,
+    moderation_decision_service: Arc<dyn Publish<ModerationDecision>>,
     social_service: Arc<dyn SocialService>,
+    identity_service: Arc<dyn IdentityService>,
+    audit_service: Arc<dyn AuditService>,
+    session_service: Arc<dyn SessionService>,
+    access_token_service: Arc<dyn AccessTokenService>,
+    follow_service: Arc<dyn FollowService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    notification_service: Arc<dyn NotificationService>,
+    email_subscription_service: Arc<dyn EmailSubscriptionService>,
+    two_factor_service: Arc<dyn TwoFactorService>,
+    login_attempt_service: Arc<dyn LoginAttemptService>,
+    request_throttle_service: Arc<dyn RequestThrottleService>,
+    mail_service: Arc<dyn MailService>,
+    oidc_service: Arc<dyn OidcService>,
+    image_storage_service: Arc<dyn ImageStorageService>,
+    registration_config: RegistrationConfig,
+    account_deletion_config: AccountDeletionConfig,
+    trusted_proxies: Arc<TrustedProxies>,
 }
 
 impl ExtensionsProviderType for ExtensionsProvider {}
@@ -52,6 +161,12 @@ impl Resolve<Arc<dyn AuthorService>> for ExtensionsProvider {
     }
 }
 
+impl Resolve<Arc<dyn AuthorStatsService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn AuthorStatsService> {
+        self.author_stats_service.clone()
+    }
+}
+
 impl Resolve<Arc<dyn PostService>> for ExtensionsProvider {
     fn resolve(&self) -> Arc<dyn PostService> {
         self.post_service.clone()
@@ -82,6 +197,108 @@ impl Resolve<Arc<dyn SocialService>> for ExtensionsProvider {
     }
 }
 
+impl Resolve<Arc<dyn IdentityService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn IdentityService> {
+        self.identity_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn AuditService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn AuditService> {
+        self.audit_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn SessionService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn SessionService> {
+        self.session_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn AccessTokenService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn AccessTokenService> {
+        self.access_token_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn FollowService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn FollowService> {
+        self.follow_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn NotificationPreferenceService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn NotificationPreferenceService> {
+        self.notification_preference_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn NotificationService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn NotificationService> {
+        self.notification_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn EmailSubscriptionService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn EmailSubscriptionService> {
+        self.email_subscription_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn TwoFactorService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn TwoFactorService> {
+        self.two_factor_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn LoginAttemptService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn LoginAttemptService> {
+        self.login_attempt_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn RequestThrottleService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn RequestThrottleService> {
+        self.request_throttle_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn MailService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn MailService> {
+        self.mail_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn OidcService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn OidcService> {
+        self.oidc_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn ImageStorageService>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn ImageStorageService> {
+        self.image_storage_service.clone()
+    }
+}
+
+impl Resolve<RegistrationConfig> for ExtensionsProvider {
+    fn resolve(&self) -> RegistrationConfig {
+        self.registration_config
+    }
+}
+
+impl Resolve<AccountDeletionConfig> for ExtensionsProvider {
+    fn resolve(&self) -> AccountDeletionConfig {
+        self.account_deletion_config
+    }
+}
+
+impl Resolve<Arc<TrustedProxies>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<TrustedProxies> {
+        self.trusted_proxies.clone()
+    }
+}
+
 impl Resolve<Arc<dyn Publish<NewPostPublished>>> for ExtensionsProvider {
     fn resolve(&self) -> Arc<dyn Publish<NewPostPublished>> {
         self.new_post_published_service.clone()
@@ -94,6 +311,18 @@ impl Resolve<Arc<dyn Publish<SubscriptionStateChanged>>> for ExtensionsProvider
     }
 }
 
+impl Resolve<Arc<dyn Publish<CommentPublished>>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn Publish<CommentPublished>> {
+        self.comment_published_service.clone()
+    }
+}
+
+impl Resolve<Arc<dyn Publish<ModerationDecision>>> for ExtensionsProvider {
+    fn resolve(&self) -> Arc<dyn Publish<ModerationDecision>> {
+        self.moderation_decision_service.clone()
+    }
+}
+
 pub fn make_extensions<U>(
     config: Config,
     rbatis: RBatis,
@@ -103,6 +332,19 @@ where
     U: Publish<NewPostPublished> + Publish<SubscriptionStateChanged> + 'static,
 {
     let author_service = create_rbatis_author_service(rbatis.clone());
+    let follow_service = create_rbatis_follow_service(rbatis.clone());
+    let post_service = create_rbatis_post_service(rbatis.clone());
+    let comment_service = create_rbatis_comment_service(rbatis.clone());
+    let notification_preference_service =
+        create_rbatis_notification_preference_service(rbatis.clone());
+    let notification_service = create_rbatis_notification_service(rbatis.clone());
+    let in_app_notification_service = create_in_app_notification_service(
+        notification_service.clone(),
+        notification_preference_service.clone(),
+        follow_service.clone(),
+        post_service.clone(),
+        comment_service.clone(),
+    );
 
     let new_post_published_service: Arc<dyn Publish<NewPostPublished>>;
     let subscription_state_changed_service: Arc<dyn Publish<SubscriptionStateChanged>>;
@@ -115,6 +357,8 @@ where
             crate::TELEGRAM_BOT_TOKEN.to_string(),
             crate::SITE_URL.to_string(),
             author_service.clone(),
+            follow_service.clone(),
+            notification_preference_service.clone(),
         ) {
             Ok(telegram_user_updates_service) => {
                 println!("Alternative Telegram updates service used");
@@ -175,25 +419,101 @@ where
             .collect();
 
         Arc::new(PublishCollection::new(
-            std::iter::once(new_post_published_service)
-                .chain(telegram_services.into_iter())
-                .chain(discord_services.into_iter())
-                .collect(),
+            [
+                new_post_published_service,
+                in_app_notification_service.clone() as Arc<dyn Publish<NewPostPublished>>,
+            ]
+            .into_iter()
+            .chain(telegram_services.into_iter())
+            .chain(discord_services.into_iter())
+            .collect(),
         ))
     };
 
+    let comment_published_service: Arc<dyn Publish<CommentPublished>> =
+        Arc::new(PublishCollection::new(vec![
+            in_app_notification_service.clone() as Arc<dyn Publish<CommentPublished>>,
+        ]));
+    let moderation_decision_service: Arc<dyn Publish<ModerationDecision>> =
+        Arc::new(PublishCollection::new(vec![
+            in_app_notification_service.clone() as Arc<dyn Publish<ModerationDecision>>,
+        ]));
+
+    let mail_service: Arc<dyn MailService> = match config.get::<SmtpConfig>("smtp") {
+        Ok(smtp_config) => {
+            create_smtp_mail_service(smtp_config).expect("Failed to create SMTP mail service")
+        }
+        Err(err) => match config.get::<FileMailConfig>("mail_file") {
+            Ok(file_mail_config) => create_file_mail_service(file_mail_config),
+            Err(_) => {
+                println!("SMTP mail service is not configured, emails won't be sent: {err}");
+                Arc::new(EmptyMailService)
+            }
+        },
+    };
+
+    let registration_config: RegistrationConfig = config.get("registration").unwrap_or_default();
+    let account_deletion_config: AccountDeletionConfig =
+        config.get("account_deletion").unwrap_or_default();
+    let trusted_proxies: TrustedProxies = config.get("trusted_proxies").unwrap_or_default();
+
+    let image_storage_config: LocalImageStorageConfig =
+        config.get("image_storage").unwrap_or_default();
+    let image_storage_service = create_local_image_storage_service(
+        image_storage_config
+            .path
+            .unwrap_or_else(|| "uploads".to_string()),
+        image_storage_config
+            .public_url
+            .unwrap_or_else(|| format!("{}/uploads", &*crate::SITE_URL)),
+    );
+
+    let mut oidc_providers: HashMap<String, OidcProviderConfig> =
+        config.get("oidc").unwrap_or_default();
+    // Names of the built-in providers, their identities share the same table.
+    oidc_providers.retain(|name, _| {
+        let reserved = name == "yandex" || name == "telegram";
+        if reserved {
+            println!("OIDC provider name '{name}' is reserved, provider skipped");
+        }
+        !reserved
+    });
+
+    let identity_service = create_rbatis_identity_service(rbatis.clone());
+
     ExtensionsProvider {
         author_service: author_service.clone(),
-        post_service: create_rbatis_post_service(rbatis.clone()),
-        comment_service: create_rbatis_comment_service(rbatis.clone()),
+        author_stats_service: create_rbatis_author_stats_service(rbatis.clone()),
+        post_service,
+        comment_service,
         entity_comment_service: create_entity_comment_service(author_service.clone()),
         entity_post_service: create_entity_post_service(author_service.clone()),
         new_post_published_service: new_post_published_service.clone(),
         subscription_state_changed_service: subscription_state_changed_service.clone(),
+        comment_published_service,
+        moderation_decision_service,
         social_service: create_social_service(
             author_service.clone(),
+            identity_service.clone(),
             subscription_state_changed_service.clone(),
         ),
+        identity_service,
+        audit_service: create_rbatis_audit_service(rbatis.clone()),
+        session_service: create_rbatis_session_service(rbatis.clone()),
+        access_token_service: create_rbatis_access_token_service(rbatis.clone()),
+        follow_service,
+        notification_preference_service,
+        notification_service,
+        email_subscription_service: create_rbatis_email_subscription_service(rbatis.clone()),
+        two_factor_service: create_rbatis_two_factor_service(rbatis.clone()),
+        login_attempt_service: create_rbatis_login_attempt_service(rbatis.clone()),
+        request_throttle_service: create_rbatis_request_throttle_service(rbatis.clone()),
+        mail_service,
+        oidc_service: create_oidc_service(oidc_providers),
+        image_storage_service,
+        registration_config,
+        account_deletion_config,
+        trusted_proxies: Arc::new(trusted_proxies),
     }
 }
 
@@ -208,3 +528,12 @@ impl Publish<SubscriptionStateChanged> for EmptyUpdatesService {
 impl Publish<NewPostPublished> for EmptyUpdatesService {
     async fn publish(&self, _: NewPostPublished) {}
 }
+
+struct EmptyMailService;
+
+#[async_trait]
+impl MailService for EmptyMailService {
+    async fn send(&self, _: Mail) -> DResult<()> {
+        Err("mail transport is not configured".into())
+    }
+}
@@ -10,6 +10,7 @@ mod utils;
 #[macro_use]
 extern crate async_trait;
 
+use extensions::Resolve;
 use once_cell::sync::Lazy;
 
 pub(crate) static JWT_SECRET: Lazy<String> =
@@ -51,13 +52,27 @@ async fn main() -> screw_components::dyn_result::DResult<()> {
             }
         };
 
+    let extensions = extensions::make_extensions(config, rbatis, rabbit_event_bus_service);
+
+    let account_deletion_config: extensions::AccountDeletionConfig = extensions.resolve();
+    account_deletion_config
+        .validate(extensions.resolve())
+        .await
+        .expect("Account deletion config check failed");
+
+    utils::email_digest_job::spawn(
+        extensions.resolve(),
+        extensions.resolve(),
+        extensions.resolve(),
+        extensions.resolve(),
+        extensions.resolve(),
+        extensions.resolve(),
+    );
+    utils::stale_records_job::spawn(extensions.resolve());
+
     let server_service = screw_core::server::ServerService::with_responder_factory(
         screw_core::responder_factory::ResponderFactory::with_router(router::make_router())
-            .and_extensions(extensions::make_extensions(
-                config,
-                rbatis,
-                rabbit_event_bus_service,
-            )),
+            .and_extensions(extensions),
     );
 
     let addr: std::net::SocketAddr = SERVER_ADDRESS.parse()?;
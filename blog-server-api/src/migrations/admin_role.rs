@@ -0,0 +1,43 @@
+use blog_server_services::utils::time_utils;
+
+const KEY: &'static str = "admin_role";
+
+/// Databases that got the `role` column before editors were mapped to admins have
+/// nobody able to manage roles, editors of those get the admin role back.
+pub async fn exec(rb: &rbatis::RBatis) -> Result<(), Box<dyn std::error::Error>> {
+    let already_done: bool = rb
+        .query_decode::<u64>(
+            "select count(1) as count from migration where key=?",
+            vec![rbs::value!(KEY)],
+        )
+        .await?
+        > 0;
+    if already_done {
+        return Ok(());
+    }
+
+    let has_admin: bool = rb
+        .query_decode::<u64>(
+            "select count(1) as count from author where role='admin' and blocked=0",
+            vec![],
+        )
+        .await?
+        > 0;
+
+    if !has_admin {
+        rb.query(
+            "update author set role='admin' where editor=1 and blocked=0",
+            vec![],
+        )
+        .await?;
+        println!("no admin found, editors got the admin role");
+    }
+
+    rb.query(
+        "insert into migration (key, created_at) values (?, to_timestamp(?))",
+        vec![rbs::value!(KEY), rbs::value!(time_utils::now_as_secs())],
+    )
+    .await?;
+
+    Ok(())
+}
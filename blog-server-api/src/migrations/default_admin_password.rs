@@ -0,0 +1,51 @@
+use crate::utils::password;
+use blog_server_services::utils::time_utils;
+
+const KEY: &'static str = "default_admin_password";
+
+const ADMIN_SLUG: &'static str = "admin";
+const DEFAULT_ADMIN_PASSWORD: &'static str = "admin";
+
+/// Databases seeded before the initial password was generated still have
+/// `admin`/`admin`, such an account has to change its password first.
+pub async fn exec(rb: &rbatis::RBatis) -> Result<(), Box<dyn std::error::Error>> {
+    let already_done: bool = rb
+        .query_decode::<u64>(
+            "select count(1) as count from migration where key=?",
+            vec![rbs::value!(KEY)],
+        )
+        .await?
+        > 0;
+    if already_done {
+        return Ok(());
+    }
+
+    let password_hashes: Vec<Option<String>> = rb
+        .query_decode(
+            "select password_hash from author where slug=? limit 1",
+            vec![rbs::value!(ADMIN_SLUG)],
+        )
+        .await?;
+
+    let is_default_password = password_hashes
+        .into_iter()
+        .flatten()
+        .any(|h| password::verify(&DEFAULT_ADMIN_PASSWORD.to_string(), &h).is_ok());
+
+    if is_default_password {
+        rb.query(
+            "update author set must_change_password=1 where slug=?",
+            vec![rbs::value!(ADMIN_SLUG)],
+        )
+        .await?;
+        println!("'{ADMIN_SLUG}' still uses the default password, it has to be changed on login");
+    }
+
+    rb.query(
+        "insert into migration (key, created_at) values (?, to_timestamp(?))",
+        vec![rbs::value!(KEY), rbs::value!(time_utils::now_as_secs())],
+    )
+    .await?;
+
+    Ok(())
+}
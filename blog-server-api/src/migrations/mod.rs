@@ -1,10 +1,14 @@
+mod admin_role;
 mod base;
 mod content_formatting;
+mod default_admin_password;
 mod seed_placeholder_ru;
 
 pub async fn exec(rb: &rbatis::RBatis) -> Result<(), Box<dyn std::error::Error>> {
     base::exec(rb).await?;
     content_formatting::exec(rb).await?;
     seed_placeholder_ru::exec(rb).await?;
+    default_admin_password::exec(rb).await?;
+    admin_role::exec(rb).await?;
     Ok(())
 }
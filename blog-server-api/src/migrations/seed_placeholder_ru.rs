@@ -4,7 +4,8 @@ use blog_server_services::utils::time_utils;
 const KEY: &'static str = "seed_placeholder_ru";
 
 const ADMIN_SLUG: &'static str = "admin";
-const ADMIN_PASSWORD: &'static str = "admin";
+const ADMIN_PASSWORD_ENV: &'static str = "ADMIN_INITIAL_PASSWORD";
+const ADMIN_GENERATED_PASSWORD_LENGTH: usize = 20;
 const ADMIN_FIRST_NAME: &'static str = "Александр";
 const ADMIN_LAST_NAME: &'static str = "Иванов";
 const ADMIN_EMAIL: &'static str = "admin@example.com";
@@ -87,12 +88,26 @@ pub async fn exec(rb: &rbatis::RBatis) -> Result<(), Box<dyn std::error::Error>>
 
     if author_count == 0 {
         let now = time_utils::now_as_secs();
-        let password_hash = password::hash(&ADMIN_PASSWORD.to_string())
-            .map_err(|e| format!("password hash error: {e}"))?;
+        let admin_password = match std::env::var(ADMIN_PASSWORD_ENV) {
+            Ok(admin_password) if !admin_password.is_empty() => {
+                println!("Initial password of '{ADMIN_SLUG}' taken from {ADMIN_PASSWORD_ENV}");
+                admin_password
+            }
+            _ => {
+                let admin_password = password::generate(ADMIN_GENERATED_PASSWORD_LENGTH);
+                println!(
+                    "Initial password of '{ADMIN_SLUG}': {admin_password} \
+                    (shown only once, has to be changed after the first login)"
+                );
+                admin_password
+            }
+        };
+        let password_hash =
+            password::hash(&admin_password).map_err(|e| format!("password hash error: {e}"))?;
 
         rb.query(
-            "insert into author (slug, first_name, last_name, email, password_hash, registered_at, status, image_url, editor) \
-             values (?, ?, ?, ?, ?, to_timestamp(?), ?, ?, 1)",
+            "insert into author (slug, first_name, last_name, email, password_hash, registered_at, status, image_url, editor, role, must_change_password) \
+             values (?, ?, ?, ?, ?, to_timestamp(?), ?, ?, 1, 'admin', 1)",
             vec![
                 rbs::value!(ADMIN_SLUG),
                 rbs::value!(ADMIN_FIRST_NAME),
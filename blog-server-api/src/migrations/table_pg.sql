@@ -258,4 +258,417 @@ DO $$ BEGIN
   END IF;
 END $$
 
-;
\ No newline at end of file
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_extension where extname = 'pg_trgm') THEN
+    CREATE EXTENSION pg_trgm;
+  END IF;
+
+  IF NOT EXISTS(select * from pg_indexes where tablename = 'author' and indexname = 'idx_author_slug_trgm') THEN
+    CREATE INDEX idx_author_slug_trgm ON author USING GIN (slug gin_trgm_ops);
+    CREATE INDEX idx_author_first_name_trgm ON author USING GIN (first_name gin_trgm_ops);
+    CREATE INDEX idx_author_middle_name_trgm ON author USING GIN (middle_name gin_trgm_ops);
+    CREATE INDEX idx_author_last_name_trgm ON author USING GIN (last_name gin_trgm_ops);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from information_schema.columns where table_name = 'post' and column_name = 'updated_at') THEN
+    ALTER TABLE post ADD COLUMN updated_at TIMESTAMP(0) NULL DEFAULT NULL;
+  END IF;
+
+  IF NOT EXISTS(select * from pg_indexes where tablename = 'post' and indexname = 'idx_post_created_at') THEN
+    CREATE INDEX idx_post_created_at ON post (created_at DESC, id DESC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'audit_log') THEN
+    CREATE SEQUENCE audit_log_seq;
+
+    CREATE TABLE audit_log (
+      id BIGINT NOT NULL DEFAULT NEXTVAL ('audit_log_seq'),
+      actor_id BIGINT NOT NULL,
+      action VARCHAR(50) NOT NULL,
+      target_type VARCHAR(50) NOT NULL,
+      target_id BIGINT NOT NULL,
+      before TEXT NULL DEFAULT NULL,
+      after TEXT NULL DEFAULT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (id),
+      CONSTRAINT fk_audit_log_actor
+        FOREIGN KEY (actor_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_audit_log_actor ON audit_log (actor_id ASC);
+    CREATE INDEX idx_audit_log_target ON audit_log (target_type ASC, target_id ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from information_schema.columns where table_name = 'author' and column_name = 'role') THEN
+    ALTER TABLE author ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'contributor';
+    UPDATE author SET role = 'admin' WHERE editor = 1;
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_session') THEN
+    CREATE SEQUENCE author_session_seq;
+
+    CREATE TABLE author_session (
+      id BIGINT NOT NULL DEFAULT NEXTVAL ('author_session_seq'),
+      author_id BIGINT NOT NULL,
+      refresh_token_hash VARCHAR(64) NOT NULL,
+      user_agent VARCHAR(255) NULL DEFAULT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      last_used_at TIMESTAMP(0) NOT NULL,
+      expires_at TIMESTAMP(0) NOT NULL,
+      revoked SMALLINT NOT NULL DEFAULT 0,
+      PRIMARY KEY (id),
+      CONSTRAINT fk_author_session_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_author_session_author ON author_session (author_id ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from information_schema.columns where table_name = 'author' and column_name = 'must_change_password') THEN
+    ALTER TABLE author ADD COLUMN must_change_password SMALLINT NOT NULL DEFAULT 0;
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_totp') THEN
+    CREATE TABLE author_totp (
+      author_id BIGINT NOT NULL,
+      secret VARCHAR(64) NOT NULL,
+      enabled SMALLINT NOT NULL DEFAULT 0,
+      last_used_step BIGINT NULL DEFAULT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (author_id),
+      CONSTRAINT fk_author_totp_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_recovery_code') THEN
+    CREATE TABLE author_recovery_code (
+      author_id BIGINT NOT NULL,
+      code_hash VARCHAR(64) NOT NULL,
+      used_at TIMESTAMP(0) NULL DEFAULT NULL,
+      PRIMARY KEY (author_id, code_hash),
+      CONSTRAINT fk_author_recovery_code_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'login_attempt') THEN
+    CREATE TABLE login_attempt (
+      author_id BIGINT NOT NULL,
+      ip VARCHAR(45) NOT NULL,
+      failed_count BIGINT NOT NULL DEFAULT 0,
+      last_failed_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (author_id, ip),
+      CONSTRAINT fk_login_attempt_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_login_attempt_last_failed_at ON login_attempt (last_failed_at ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_identity') THEN
+    CREATE SEQUENCE author_identity_seq;
+
+    CREATE TABLE author_identity (
+      id BIGINT NOT NULL DEFAULT NEXTVAL ('author_identity_seq'),
+      author_id BIGINT NOT NULL,
+      provider VARCHAR(64) NOT NULL,
+      subject VARCHAR(255) NOT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (id),
+      CONSTRAINT uq_author_identity_provider_subject UNIQUE (provider, subject),
+      CONSTRAINT fk_author_identity_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_author_identity_author_id ON author_identity (author_id ASC);
+
+    INSERT INTO author_identity (author_id, provider, subject, created_at)
+    SELECT id, 'yandex', yandex_id::TEXT, now() FROM author WHERE yandex_id IS NOT NULL
+    ON CONFLICT DO NOTHING;
+
+    INSERT INTO author_identity (author_id, provider, subject, created_at)
+    SELECT id, 'telegram', telegram_id::TEXT, now() FROM author WHERE telegram_id IS NOT NULL
+    ON CONFLICT DO NOTHING;
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_access_token') THEN
+    CREATE SEQUENCE author_access_token_seq;
+
+    CREATE TABLE author_access_token (
+      id BIGINT NOT NULL DEFAULT NEXTVAL ('author_access_token_seq'),
+      author_id BIGINT NOT NULL,
+      name VARCHAR(64) NOT NULL,
+      token_hash VARCHAR(64) NOT NULL,
+      scopes VARCHAR(255) NOT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      last_used_at TIMESTAMP(0) NULL DEFAULT NULL,
+      expires_at TIMESTAMP(0) NOT NULL,
+      revoked SMALLINT NOT NULL DEFAULT 0,
+      PRIMARY KEY (id),
+      CONSTRAINT fk_author_access_token_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_author_access_token_author ON author_access_token (author_id ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_follow') THEN
+    CREATE TABLE author_follow (
+      follower_id BIGINT NOT NULL,
+      author_id BIGINT NOT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (follower_id, author_id),
+      CONSTRAINT fk_author_follow_follower
+        FOREIGN KEY (follower_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION,
+      CONSTRAINT fk_author_follow_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_author_follow_author ON author_follow (author_id ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'tag_follow') THEN
+    CREATE TABLE tag_follow (
+      follower_id BIGINT NOT NULL,
+      tag_id BIGINT NOT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (follower_id, tag_id),
+      CONSTRAINT fk_tag_follow_follower
+        FOREIGN KEY (follower_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION,
+      CONSTRAINT fk_tag_follow_tag
+        FOREIGN KEY (tag_id)
+        REFERENCES tag (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_tag_follow_tag ON tag_follow (tag_id ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_notification_preference') THEN
+    CREATE TABLE author_notification_preference (
+      author_id BIGINT NOT NULL,
+      telegram_events VARCHAR(128) NOT NULL,
+      email_events VARCHAR(128) NOT NULL,
+      in_app_events VARCHAR(128) NOT NULL,
+      digest VARCHAR(16) NOT NULL,
+      updated_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (author_id),
+      CONSTRAINT fk_author_notification_preference_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_notification') THEN
+    CREATE SEQUENCE author_notification_seq;
+
+    CREATE TABLE author_notification (
+      id BIGINT NOT NULL DEFAULT NEXTVAL ('author_notification_seq'),
+      author_id BIGINT NOT NULL,
+      event VARCHAR(50) NOT NULL,
+      actor_id BIGINT NOT NULL,
+      action VARCHAR(50) NULL DEFAULT NULL,
+      link VARCHAR(512) NULL DEFAULT NULL,
+      created_at TIMESTAMP(0) NOT NULL,
+      read SMALLINT NOT NULL DEFAULT 0,
+      PRIMARY KEY (id),
+      CONSTRAINT fk_author_notification_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION,
+      CONSTRAINT fk_author_notification_actor
+        FOREIGN KEY (actor_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_author_notification_author ON author_notification (author_id ASC, id DESC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'email_subscription') THEN
+    CREATE SEQUENCE email_subscription_seq;
+
+    CREATE TABLE email_subscription (
+      id BIGINT NOT NULL DEFAULT NEXTVAL ('email_subscription_seq'),
+      email VARCHAR(50) NOT NULL,
+      frequency VARCHAR(16) NOT NULL,
+      confirmed SMALLINT NOT NULL DEFAULT 0,
+      created_at TIMESTAMP(0) NOT NULL,
+      confirmed_at TIMESTAMP(0) NULL DEFAULT NULL,
+      last_sent_at TIMESTAMP(0) NULL DEFAULT NULL,
+      PRIMARY KEY (id),
+      CONSTRAINT email_subscription_email_unique UNIQUE (email));
+
+    CREATE INDEX idx_email_subscription_due ON email_subscription (frequency ASC, last_sent_at ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from information_schema.columns where table_name = 'author' and column_name = 'bio') THEN
+    ALTER TABLE author ADD COLUMN bio TEXT;
+    ALTER TABLE author ADD COLUMN links TEXT;
+    ALTER TABLE author ADD COLUMN cover_url VARCHAR(255);
+    ALTER TABLE author ADD COLUMN location VARCHAR(100);
+    ALTER TABLE author ADD COLUMN email_public SMALLINT NOT NULL DEFAULT 0;
+    ALTER TABLE author ADD COLUMN mobile_public SMALLINT NOT NULL DEFAULT 0;
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'author_session_rotated_token') THEN
+    CREATE TABLE author_session_rotated_token (
+      refresh_token_hash VARCHAR(64) NOT NULL,
+      session_id BIGINT NOT NULL,
+      author_id BIGINT NOT NULL,
+      rotated_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (refresh_token_hash),
+      CONSTRAINT fk_author_session_rotated_token_session
+        FOREIGN KEY (session_id)
+        REFERENCES author_session (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION,
+      CONSTRAINT fk_author_session_rotated_token_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_author_session_rotated_token_session ON author_session_rotated_token (session_id ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from information_schema.columns where table_name = 'author_notification_preference' and column_name = 'digest_sent_at') THEN
+    ALTER TABLE author_notification_preference ADD COLUMN IF NOT EXISTS email_events VARCHAR(128) NOT NULL DEFAULT '';
+    ALTER TABLE author_notification_preference ADD COLUMN IF NOT EXISTS digest VARCHAR(16) NOT NULL DEFAULT 'immediate';
+    ALTER TABLE author_notification_preference ADD COLUMN digest_sent_at TIMESTAMP(0) NULL DEFAULT NULL;
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'request_throttle') THEN
+    CREATE TABLE request_throttle (
+      key VARCHAR(128) NOT NULL,
+      request_count BIGINT NOT NULL,
+      started_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (key));
+
+    CREATE INDEX idx_request_throttle_started_at ON request_throttle (started_at ASC);
+  END IF;
+END $$
+
+;
+
+DO $$ BEGIN
+  IF NOT EXISTS(select * from pg_tables where schemaname = 'public' and tablename = 'login_known_address') THEN
+    CREATE TABLE login_known_address (
+      author_id BIGINT NOT NULL,
+      ip VARCHAR(45) NOT NULL,
+      last_succeeded_at TIMESTAMP(0) NOT NULL,
+      PRIMARY KEY (author_id, ip),
+      CONSTRAINT fk_login_known_address_author
+        FOREIGN KEY (author_id)
+        REFERENCES author (id)
+        ON DELETE NO ACTION
+        ON UPDATE NO ACTION);
+
+    CREATE INDEX idx_login_known_address_last_succeeded_at ON login_known_address (last_succeeded_at ASC);
+  END IF;
+END $$
+
+;
@@ -54,6 +54,16 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
     #[cfg(feature = "ssr")]
     let sitemap_handler = sitemap_handler;
 
+    #[cfg(not(feature = "ssr"))]
+    let set_session_cookie_handler = not_found_fallback_handler;
+    #[cfg(feature = "ssr")]
+    let set_session_cookie_handler = set_session_cookie_handler;
+
+    #[cfg(not(feature = "ssr"))]
+    let remove_session_cookie_handler = not_found_fallback_handler;
+    #[cfg(feature = "ssr")]
+    let remove_session_cookie_handler = remove_session_cookie_handler;
+
     #[cfg(not(feature = "yandex"))]
     let yandex_handler = api_not_found_fallback_handler;
     #[cfg(feature = "yandex")]
@@ -64,6 +74,16 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
     #[cfg(feature = "telegram")]
     let telegram_handler = telegram_login::http_handler;
 
+    #[cfg(not(feature = "yandex"))]
+    let yandex_link_handler = api_not_found_fallback_handler;
+    #[cfg(feature = "yandex")]
+    let yandex_link_handler = author_identity_link::http_handler_yandex;
+
+    #[cfg(not(feature = "telegram"))]
+    let telegram_link_handler = api_not_found_fallback_handler;
+    #[cfg(feature = "telegram")]
+    let telegram_link_handler = author_identity_link::http_handler_telegram;
+
     #[cfg(not(feature = "chatgpt"))]
     let chatgpt_handler = api_not_found_fallback_handler;
     #[cfg(feature = "chatgpt")]
@@ -82,6 +102,101 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                             .and_path("/me")
                             .and_handler(author_me::http_handler),
                     )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/password")
+                            .and_handler(change_password::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/2fa")
+                            .and_handler(two_factor_status::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/2fa")
+                            .and_handler(two_factor_setup::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/2fa/enable")
+                            .and_handler(two_factor_enable::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/2fa/disable")
+                            .and_handler(two_factor_disable::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/identities")
+                            .and_handler(author_identities::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/identities/oidc/{provider:[^/]*}")
+                            .and_handler(author_identity_link::http_handler_oidc),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/identities/yandex")
+                            .and_handler(yandex_link_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/identities/telegram")
+                            .and_handler(telegram_link_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::DELETE)
+                            .and_path("/me/identities/{id:[^/]*}")
+                            .and_handler(author_identity_unlink::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/tokens")
+                            .and_handler(access_tokens::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/me/tokens")
+                            .and_handler(access_token_create::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::DELETE)
+                            .and_path("/me/tokens/{id:[^/]*}")
+                            .and_handler(access_token_revoke::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/follows")
+                            .and_handler(author_follows::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/notifications")
+                            .and_handler(notification_preferences::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::PATCH)
+                            .and_path("/me/notifications")
+                            .and_handler(notification_preferences_update::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/stats")
+                            .and_handler(author_stats::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/me/export")
+                            .and_handler(author_export::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::DELETE)
+                            .and_path("/me")
+                            .and_handler(author_delete::http_handler),
+                    )
                     .route(
                         route::first::Route::with_method(&hyper::Method::GET)
                             .and_path("/slug/{slug:[^/]*}")
@@ -97,6 +212,16 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                             .and_path("/id/{id:[^/]*}/unblock")
                             .and_handler(author_block::http_handler_unblock),
                     )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/id/{id:[^/]*}/merge")
+                            .and_handler(author_merge::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::PATCH)
+                            .and_path("/id/{id:[^/]*}/role")
+                            .and_handler(author_role::http_handler),
+                    )
                     .route(
                         route::first::Route::with_method(&hyper::Method::PATCH)
                             .and_path("/id/{id:[^/]*}/subscribe")
@@ -107,6 +232,16 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                             .and_path("/id/{id:[^/]*}/unsubscribe")
                             .and_handler(author_subscribe::http_handler_unsubscribe),
                     )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::PATCH)
+                            .and_path("/id/{id:[^/]*}/follow")
+                            .and_handler(author_follow::http_handler_follow),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::PATCH)
+                            .and_path("/id/{id:[^/]*}/unfollow")
+                            .and_handler(author_follow::http_handler_unfollow),
+                    )
                     .route(
                         route::first::Route::with_method(&hyper::Method::PATCH)
                             .and_path("/reset_override_social_data")
@@ -187,6 +322,13 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                                 .and_handler(posts::http_handler_hidden),
                         )
                     })
+                    .scoped("/feed", |r| {
+                        r.route(
+                            route::first::Route::with_method(&hyper::Method::GET)
+                                .and_path("")
+                                .and_handler(posts::http_handler_feed),
+                        )
+                    })
                     .route(
                         route::first::Route::with_method(&hyper::Method::GET)
                             .and_path("")
@@ -198,6 +340,16 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                         .and_path("/tag/{id:[^/]*}")
                         .and_handler(tag::http_handler),
                 )
+                .route(
+                    route::first::Route::with_method(&hyper::Method::PATCH)
+                        .and_path("/tag/{id:[^/]*}/follow")
+                        .and_handler(tag_follow::http_handler_follow),
+                )
+                .route(
+                    route::first::Route::with_method(&hyper::Method::PATCH)
+                        .and_path("/tag/{id:[^/]*}/unfollow")
+                        .and_handler(tag_follow::http_handler_unfollow),
+                )
                 .route(
                     route::first::Route::with_method(&hyper::Method::GET)
                         .and_path("/comments/{post_id:[^/]*}")
@@ -215,16 +367,118 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                             .and_handler(create_comment::http_handler),
                     )
                 })
+                .scoped("/subscription/email", |r| {
+                    r.route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("")
+                            .and_handler(email_subscribe::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/confirm")
+                            .and_handler(email_subscription_confirm::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/unsubscribe")
+                            .and_handler(email_unsubscribe::http_handler),
+                    )
+                })
+                .scoped("/notifications", |r| {
+                    r.route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/unread")
+                            .and_handler(notifications_unread::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::PATCH)
+                            .and_path("/read")
+                            .and_handler(notifications_read::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("")
+                            .and_handler(notifications::http_handler),
+                    )
+                })
+                .route(
+                    route::first::Route::with_method(&hyper::Method::GET)
+                        .and_path("/audit")
+                        .and_handler(audit::http_handler),
+                )
                 .route(
                     route::first::Route::with_method(&hyper::Method::POST)
                         .and_path("/chatgpt")
                         .and_handler(chatgpt_handler),
                 )
+                .scoped("/login", |r| {
+                    r.route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("")
+                            .and_handler(login::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/2fa")
+                            .and_handler(login_two_factor::http_handler),
+                    )
+                })
                 .route(
                     route::first::Route::with_method(&hyper::Method::POST)
-                        .and_path("/login")
-                        .and_handler(login::http_handler),
+                        .and_path("/register")
+                        .and_handler(register::http_handler),
                 )
+                .scoped("/password/reset", |r| {
+                    r.route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/request")
+                            .and_handler(password_reset_request::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("")
+                            .and_handler(password_reset::http_handler),
+                    )
+                })
+                .route(
+                    route::first::Route::with_method(&hyper::Method::POST)
+                        .and_path("/refresh")
+                        .and_handler(refresh::http_handler),
+                )
+                .scoped("/logout", |r| {
+                    r.route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("")
+                            .and_handler(logout::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/all")
+                            .and_handler(logout_all::http_handler),
+                    )
+                })
+                .route(
+                    route::first::Route::with_method(&hyper::Method::GET)
+                        .and_path("/sessions")
+                        .and_handler(sessions::http_handler),
+                )
+                .scoped("/oidc", |r| {
+                    r.route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/providers")
+                            .and_handler(oidc_providers::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::GET)
+                            .and_path("/{provider:[^/]*}/authorize")
+                            .and_handler(oidc_authorize::http_handler),
+                    )
+                    .route(
+                        route::first::Route::with_method(&hyper::Method::POST)
+                            .and_path("/{provider:[^/]*}/login")
+                            .and_handler(oidc_login::http_handler),
+                    )
+                })
                 .route(
                     route::first::Route::with_method(&hyper::Method::POST)
                         .and_path("/ylogin")
@@ -247,5 +501,35 @@ pub fn make_router<Extensions: ExtensionsProviderType>()
                 .and_path("/sitemap.xml")
                 .and_handler(sitemap_handler),
         )
+        .route(
+            route::first::Route::with_method(&hyper::Method::PUT)
+                .and_path("/session/cookie")
+                .and_handler(set_session_cookie_handler),
+        )
+        .route(
+            route::first::Route::with_method(&hyper::Method::DELETE)
+                .and_path("/session/cookie")
+                .and_handler(remove_session_cookie_handler),
+        )
+        .route(
+            route::first::Route::with_method(&hyper::Method::GET)
+                .and_path("/subscription/email/confirm")
+                .and_handler(email_subscription_confirm_page_handler),
+        )
+        .route(
+            route::first::Route::with_method(&hyper::Method::GET)
+                .and_path("/subscription/email/unsubscribe")
+                .and_handler(email_unsubscribe_page_handler),
+        )
+        .route(
+            route::first::Route::with_method(&hyper::Method::POST)
+                .and_path("/upload/avatar")
+                .and_handler(avatar_upload_handler),
+        )
+        .route(
+            route::first::Route::with_method(&hyper::Method::GET)
+                .and_path("/uploads/{name:[^/]*}")
+                .and_handler(uploaded_image_handler),
+        )
     })
 }
@@ -1,18 +1,41 @@
+use crate::extensions::TrustedProxies;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use blog_server_services::traits::access_token_service::{
+    AccessToken, AccessTokenService, BaseAccessToken, TokenScope,
+};
 use blog_server_services::traits::author_service::{Author, AuthorService};
-use hyper::header::HeaderValue;
-use hyper::header::ToStrError;
+use blog_server_services::traits::session_service::{BaseSession, SessionService};
+use blog_server_services::utils::time_utils;
+use hyper::Method;
+use hyper::header::{AUTHORIZATION, COOKIE, HeaderMap, ToStrError};
 use hyper::http::request::Parts;
 use jsonwebtoken::errors::{Error as JwtError, Result as JwtResult};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::error::Error as StdError;
 use std::fmt::Display;
 use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
+/// Access tokens are short-lived, so a revoked session stops working
+/// at most this many seconds after revocation.
+const ACCESS_TOKEN_LIFETIME: u64 = 60 * 15;
+const REFRESH_TOKEN_LIFETIME: u64 = 60 * 60 * 24 * 31;
+/// Sessions signed in at most this many seconds ago count as a fresh login.
+const RECENT_LOGIN_LIFETIME: u64 = 60 * 10;
+
+/// HttpOnly cookie carrying the access token for server side rendered pages.
+pub const SESSION_COOKIE_NAME: &str = "blog_session";
+
 #[derive(Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Data {
     author_id: u64,
+    session_id: u64,
+    /// When the session was signed in, kept through refreshes.
+    #[serde(default)]
+    auth_time: u64,
     exp: u64,
 }
 
@@ -26,20 +49,268 @@ fn additional_secret_for_author(author: &Author) -> String {
         .unwrap_or(author.id.to_string())
 }
 
-pub fn token(author: Author) -> JwtResult<String> {
+fn token(author: &Author, session_id: u64, auth_time: u64) -> JwtResult<String> {
     super::jwt::encode(
         &Data {
             author_id: author.id,
-            exp: jsonwebtoken::get_current_timestamp() + (60 * 60 * 24 * 31),
+            session_id,
+            auth_time,
+            exp: jsonwebtoken::get_current_timestamp() + ACCESS_TOKEN_LIFETIME,
+        },
+        &additional_secret_for_author(author),
+    )
+}
+
+const PASSWORD_RESET_TOKEN_LIFETIME: u64 = 60 * 60;
+const PASSWORD_RESET_PURPOSE: &str = "password_reset";
+pub const TWO_FACTOR_CHALLENGE_TOKEN_LIFETIME: u64 = 60 * 5;
+const TWO_FACTOR_CHALLENGE_PURPOSE: &str = "two_factor_challenge";
+
+/// Single purpose tokens, never accepted in place of an access token.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PurposeData {
+    author_id: u64,
+    purpose: String,
+    exp: u64,
+}
+
+fn purpose_token(author: &Author, purpose: &str, lifetime: u64) -> JwtResult<String> {
+    super::jwt::encode(
+        &PurposeData {
+            author_id: author.id,
+            purpose: purpose.to_string(),
+            exp: jsonwebtoken::get_current_timestamp() + lifetime,
         },
-        &additional_secret_for_author(&author),
+        &additional_secret_for_author(author),
+    )
+}
+
+async fn author_by_purpose_token(
+    token: &str,
+    purpose: &str,
+    author_service: Arc<dyn AuthorService>,
+) -> Result<Author, Error> {
+    let insecure_purpose_data =
+        super::jwt::insecure_decode::<PurposeData>(token).map_err(|e| Error::Token(e))?;
+
+    let author = author_service
+        .author_by_id(&insecure_purpose_data.author_id)
+        .await
+        .map_err(|e| Error::DatabaseError(e))?
+        .ok_or(Error::AuthorNotFound)?;
+
+    let purpose_data =
+        super::jwt::decode::<PurposeData>(token, &additional_secret_for_author(&author))
+            .map_err(|e| Error::Token(e))?;
+
+    if purpose_data.purpose != purpose {
+        return Err(Error::Token(
+            jsonwebtoken::errors::ErrorKind::InvalidToken.into(),
+        ));
+    }
+
+    Ok(author)
+}
+
+/// Signed with the current password hash mixed into the secret,
+/// so the token stops working as soon as the password is changed.
+pub fn password_reset_token(author: &Author) -> JwtResult<String> {
+    purpose_token(
+        author,
+        PASSWORD_RESET_PURPOSE,
+        PASSWORD_RESET_TOKEN_LIFETIME,
     )
 }
 
+pub async fn author_by_password_reset_token(
+    token: &str,
+    author_service: Arc<dyn AuthorService>,
+) -> Result<Author, Error> {
+    author_by_purpose_token(token, PASSWORD_RESET_PURPOSE, author_service).await
+}
+
+/// Proof that the password step of a two-factor login has been passed.
+pub fn two_factor_challenge_token(author: &Author) -> JwtResult<String> {
+    purpose_token(
+        author,
+        TWO_FACTOR_CHALLENGE_PURPOSE,
+        TWO_FACTOR_CHALLENGE_TOKEN_LIFETIME,
+    )
+}
+
+pub async fn author_by_two_factor_challenge_token(
+    token: &str,
+    author_service: Arc<dyn AuthorService>,
+) -> Result<Author, Error> {
+    author_by_purpose_token(token, TWO_FACTOR_CHALLENGE_PURPOSE, author_service).await
+}
+
+fn random_secret() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+fn secret_hash(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTokens {
+    pub token: String,
+    /// `{session_id}.{secret}`, only the hash of the secret is kept server-side.
+    pub refresh_token: String,
+    pub expires_in: u64,
+    /// The account only has access to its own profile until the password is changed.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub must_change_password: bool,
+}
+
+pub enum SessionError {
+    DatabaseError(Box<dyn StdError + Send>),
+    Token(JwtError),
+    RefreshTokenInvalid,
+    RefreshTokenReused,
+    AuthorNotFound,
+}
+
+impl Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionError::DatabaseError(e) => write!(f, "{}", e.to_string()),
+            SessionError::Token(e) => write!(f, "{}", e.to_string()),
+            SessionError::RefreshTokenInvalid => write!(f, "refresh token invalid"),
+            SessionError::RefreshTokenReused => write!(f, "refresh token reused"),
+            SessionError::AuthorNotFound => write!(f, "author not found"),
+        }
+    }
+}
+
+pub async fn start_session(
+    author: Author,
+    user_agent: Option<String>,
+    session_service: Arc<dyn SessionService>,
+) -> Result<SessionTokens, SessionError> {
+    let now = time_utils::now_as_secs();
+    let secret = random_secret();
+
+    let session_id = session_service
+        .create_session(&BaseSession {
+            author_id: author.id,
+            refresh_token_hash: secret_hash(&secret),
+            user_agent,
+            created_at: now,
+            last_used_at: now,
+            expires_at: now + REFRESH_TOKEN_LIFETIME,
+        })
+        .await
+        .map_err(|e| SessionError::DatabaseError(e))?;
+
+    Ok(SessionTokens {
+        token: token(&author, session_id, now).map_err(|e| SessionError::Token(e))?,
+        refresh_token: format!("{session_id}.{secret}"),
+        expires_in: ACCESS_TOKEN_LIFETIME,
+        must_change_password: author.base.must_change_password != 0,
+    })
+}
+
+pub async fn refresh_session(
+    refresh_token: &str,
+    author_service: Arc<dyn AuthorService>,
+    session_service: Arc<dyn SessionService>,
+) -> Result<SessionTokens, SessionError> {
+    let (session_id, secret) = refresh_token
+        .split_once('.')
+        .and_then(|(id, secret)| Some((id.parse::<u64>().ok()?, secret)))
+        .ok_or(SessionError::RefreshTokenInvalid)?;
+
+    let new_secret = random_secret();
+
+    let rotated = session_service
+        .rotate_refresh_token(
+            &session_id,
+            &secret_hash(secret),
+            &secret_hash(&new_secret),
+            &(time_utils::now_as_secs() + REFRESH_TOKEN_LIFETIME),
+        )
+        .await
+        .map_err(|e| SessionError::DatabaseError(e))?;
+
+    let Some(session) = rotated else {
+        // Either the client or whoever stole the token already got the next one,
+        // there's no telling which, so the session ends for both.
+        let is_reused = session_service
+            .revoke_session_by_rotated_refresh_token(&session_id, &secret_hash(secret))
+            .await
+            .map_err(|e| SessionError::DatabaseError(e))?;
+        return Err(if is_reused {
+            SessionError::RefreshTokenReused
+        } else {
+            SessionError::RefreshTokenInvalid
+        });
+    };
+
+    let author = author_service
+        .author_by_id(&session.base.author_id)
+        .await
+        .map_err(|e| SessionError::DatabaseError(e))?
+        .ok_or(SessionError::AuthorNotFound)?;
+
+    Ok(SessionTokens {
+        token: token(&author, session.id, session.base.created_at)
+            .map_err(|e| SessionError::Token(e))?,
+        refresh_token: format!("{}.{new_secret}", session.id),
+        expires_in: ACCESS_TOKEN_LIFETIME,
+        must_change_password: author.base.must_change_password != 0,
+    })
+}
+
+/// Personal access tokens are `pat_{id}_{secret}`, the prefix tells them apart
+/// from access JWTs and makes leaked ones easy to scan for.
+const ACCESS_TOKEN_PREFIX: &str = "pat_";
+
+/// Issues a personal access token, the returned token string is the only
+/// place its secret is ever shown.
+pub async fn create_access_token(
+    author: &Author,
+    name: String,
+    scopes: &[TokenScope],
+    lifetime: u64,
+    access_token_service: Arc<dyn AccessTokenService>,
+) -> Result<(AccessToken, String), Box<dyn StdError + Send>> {
+    let now = time_utils::now_as_secs();
+    let secret = random_secret();
+
+    let base = BaseAccessToken {
+        author_id: author.id,
+        name,
+        token_hash: secret_hash(&secret),
+        scopes: TokenScope::join(scopes),
+        created_at: now,
+        last_used_at: None,
+        expires_at: now + lifetime,
+    };
+    let id = access_token_service.create_access_token(&base).await?;
+
+    Ok((
+        AccessToken {
+            id,
+            revoked: 0,
+            base,
+        },
+        format!("{ACCESS_TOKEN_PREFIX}{id}_{secret}"),
+    ))
+}
+
 pub enum Error {
     TokenMissing,
     TokenHeaderCorrupted(ToStrError),
+    AuthorizationSchemeUnsupported,
     Token(JwtError),
+    AccessTokenInvalid,
+    AccessTokenNotAccepted,
     DatabaseError(Box<dyn StdError + Send>),
     AuthorNotFound,
 }
@@ -49,25 +320,89 @@ impl Display for Error {
         match self {
             Error::TokenMissing => write!(f, "token missing"),
             Error::TokenHeaderCorrupted(e) => write!(f, "{}", e.to_string()),
+            Error::AuthorizationSchemeUnsupported => write!(f, "unsupported authorization scheme"),
             Error::Token(e) => write!(f, "{}", e.to_string()),
+            Error::AccessTokenInvalid => write!(f, "access token invalid"),
+            Error::AccessTokenNotAccepted => {
+                write!(f, "personal access tokens are not accepted here")
+            }
             Error::DatabaseError(e) => write!(f, "{}", e.to_string()),
             Error::AuthorNotFound => write!(f, "author not found"),
         }
     }
 }
 
-async fn author_by_token_header_value(
-    token_header_value: Option<HeaderValue>,
+pub struct AuthSession {
+    pub author: Author,
+    pub session_id: u64,
+    pub authenticated_at: u64,
+    pub expires_at: u64,
+}
+
+impl AuthSession {
+    /// Whether the author has just signed in, not merely refreshed an older session.
+    pub fn is_recent_login(&self) -> bool {
+        self.authenticated_at + RECENT_LOGIN_LIFETIME >= time_utils::now_as_secs()
+    }
+}
+
+fn cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|c| c.trim().split_once('='))
+        .find(|(n, _)| *n == name)
+        .map(|(_, v)| v.to_owned())
+}
+
+/// Access token of the request: `Authorization: Bearer`, then the legacy `Token`
+/// header, then the session cookie. Browsers attach cookies on their own, so the
+/// cookie is only accepted for safe methods to keep writes out of reach of CSRF.
+pub fn token(method: &Method, headers: &HeaderMap) -> Result<String, Error> {
+    if let Some(authorization) = headers.get(AUTHORIZATION) {
+        let authorization = authorization
+            .to_str()
+            .map_err(|e| Error::TokenHeaderCorrupted(e))?;
+        return match authorization.split_once(' ') {
+            Some((scheme, token)) if scheme.eq_ignore_ascii_case("Bearer") => {
+                Ok(token.trim().to_owned())
+            }
+            _ => Err(Error::AuthorizationSchemeUnsupported),
+        };
+    }
+
+    if let Some(token) = headers.get("Token") {
+        return token
+            .to_str()
+            .map(|t| t.to_owned())
+            .map_err(|e| Error::TokenHeaderCorrupted(e));
+    }
+
+    if method == Method::GET || method == Method::HEAD {
+        if let Some(token) = cookie(headers, SESSION_COOKIE_NAME) {
+            return Ok(token);
+        }
+    }
+
+    Err(Error::TokenMissing)
+}
+
+async fn session_by_token(
+    token: Result<String, Error>,
     author_service: Arc<dyn AuthorService>,
-) -> Result<Author, Error> {
-    let token_header_value = token_header_value.ok_or(Error::TokenMissing)?;
+) -> Result<AuthSession, Error> {
+    let token = token?;
 
-    let token = token_header_value
-        .to_str()
-        .map_err(|e| Error::TokenHeaderCorrupted(e))?;
+    // Sessions are managed with interactive logins only, so a leaked
+    // access token can't be used to take over the account.
+    if token.starts_with(ACCESS_TOKEN_PREFIX) {
+        return Err(Error::AccessTokenNotAccepted);
+    }
 
     let insecure_login_data =
-        super::jwt::insecure_decode::<Data>(token).map_err(|e| Error::Token(e))?;
+        super::jwt::insecure_decode::<Data>(&token).map_err(|e| Error::Token(e))?;
 
     let author = author_service
         .author_by_id(&insecure_login_data.author_id)
@@ -75,16 +410,599 @@ async fn author_by_token_header_value(
         .map_err(|e| Error::DatabaseError(e))?
         .ok_or(Error::AuthorNotFound)?;
 
-    super::jwt::decode::<Data>(token, &additional_secret_for_author(&author))
+    let login_data = super::jwt::decode::<Data>(&token, &additional_secret_for_author(&author))
         .map_err(|e| Error::Token(e))?;
 
+    Ok(AuthSession {
+        author,
+        session_id: login_data.session_id,
+        authenticated_at: login_data.auth_time,
+        expires_at: login_data.exp,
+    })
+}
+
+async fn author_by_access_token(
+    token: &str,
+    author_service: Arc<dyn AuthorService>,
+    access_token_service: Arc<dyn AccessTokenService>,
+) -> Result<Author, Error> {
+    let (id, secret) = token
+        .strip_prefix(ACCESS_TOKEN_PREFIX)
+        .and_then(|t| t.split_once('_'))
+        .and_then(|(id, secret)| Some((id.parse::<u64>().ok()?, secret)))
+        .ok_or(Error::AccessTokenInvalid)?;
+
+    let access_token = access_token_service
+        .use_access_token(&id, &secret_hash(secret))
+        .await
+        .map_err(|e| Error::DatabaseError(e))?
+        .ok_or(Error::AccessTokenInvalid)?;
+
+    let mut author = author_service
+        .author_by_id(&access_token.base.author_id)
+        .await
+        .map_err(|e| Error::DatabaseError(e))?
+        .ok_or(Error::AuthorNotFound)?;
+
+    author.token_scopes = Some(TokenScope::split(&access_token.base.scopes));
+
     Ok(author)
 }
 
+pub fn session_by_headers(
+    method: &Method,
+    headers: &HeaderMap,
+    author_service: Arc<dyn AuthorService>,
+) -> impl Future<Output = Result<AuthSession, Error>> + Send + 'static {
+    session_by_token(token(method, headers), author_service)
+}
+
+pub fn session(
+    http_parts: &Parts,
+    author_service: Arc<dyn AuthorService>,
+) -> impl Future<Output = Result<AuthSession, Error>> + Send + 'static {
+    session_by_headers(&http_parts.method, &http_parts.headers, author_service)
+}
+
+/// Author of a session or of a personal access token, the latter limited to its scopes.
+pub fn author_by_headers(
+    method: &Method,
+    headers: &HeaderMap,
+    author_service: Arc<dyn AuthorService>,
+    access_token_service: Arc<dyn AccessTokenService>,
+) -> impl Future<Output = Result<Author, Error>> + Send + 'static {
+    let token = token(method, headers);
+    async move {
+        let token = token?;
+        if token.starts_with(ACCESS_TOKEN_PREFIX) {
+            author_by_access_token(&token, author_service, access_token_service).await
+        } else {
+            session_by_token(Ok(token), author_service)
+                .await
+                .map(|s| s.author)
+        }
+    }
+}
+
 pub fn author(
     http_parts: &Parts,
     author_service: Arc<dyn AuthorService>,
+    access_token_service: Arc<dyn AccessTokenService>,
 ) -> impl Future<Output = Result<Author, Error>> + Send + 'static {
-    let token_header_value = http_parts.headers.get("Token").cloned();
-    author_by_token_header_value(token_header_value, author_service)
+    author_by_headers(
+        &http_parts.method,
+        &http_parts.headers,
+        author_service,
+        access_token_service,
+    )
+}
+
+/// `Set-Cookie` value storing the access token for `max_age` seconds,
+/// an empty token with zero `max_age` removes the cookie.
+pub fn session_cookie(token: &str, max_age: u64) -> String {
+    let secure = if crate::SITE_URL.starts_with("https://") {
+        "; Secure"
+    } else {
+        ""
+    };
+    format!(
+        "{SESSION_COOKIE_NAME}={token}; Path=/; Max-Age={max_age}; HttpOnly; SameSite=Lax{secure}"
+    )
+}
+
+/// User agent to remember the session by, truncated to fit the column.
+pub fn user_agent(http_parts: &Parts) -> Option<String> {
+    http_parts
+        .headers
+        .get(hyper::header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.chars().take(255).collect())
+}
+
+/// Client address: the peer address, unless it's one of `trusted_proxies`. Then
+/// `X-Forwarded-For` is walked from the right, skipping the trusted proxies, since
+/// everything to the left of the entry the outermost one appended is client supplied.
+/// `X-Real-IP` is only used when a trusted proxy sends no `X-Forwarded-For`.
+pub fn client_ip(
+    http_parts: &Parts,
+    remote_addr: &SocketAddr,
+    trusted_proxies: Arc<TrustedProxies>,
+) -> String {
+    let headers = &http_parts.headers;
+    let mut ip = remote_addr.ip().to_canonical();
+    if !trusted_proxies.contains(&ip) {
+        return ip.to_string();
+    }
+
+    let forwarded: Vec<Option<IpAddr>> = headers
+        .get_all("X-Forwarded-For")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|v| v.trim().parse::<IpAddr>().ok())
+        .collect();
+    if forwarded.is_empty() {
+        return headers
+            .get("X-Real-IP")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<IpAddr>().ok())
+            .unwrap_or(ip)
+            .to_canonical()
+            .to_string();
+    }
+    for forwarded_ip in forwarded.into_iter().rev() {
+        let Some(forwarded_ip) = forwarded_ip else {
+            break;
+        };
+        ip = forwarded_ip.to_canonical();
+        if !trusted_proxies.contains(&ip) {
+            break;
+        }
+    }
+    ip.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use blog_server_services::traits::audit_service::BaseAuditRecord;
+    use blog_server_services::traits::author_service::{
+        AuthorRole, BaseAuthor, BaseMinimalAuthor, BaseSecondaryAuthor, DeletedAuthorPosts,
+    };
+    use blog_server_services::traits::session_service::Session;
+    use hyper::header::HeaderValue;
+    use screw_components::dyn_result::DResult;
+    use std::sync::Mutex;
+
+    /// Knows a single author, only looked up by id.
+    struct StubAuthorService {
+        author: Option<Author>,
+    }
+
+    #[async_trait]
+    impl AuthorService for StubAuthorService {
+        async fn authors_count_by_query(&self, _query: &String) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors_by_query(
+            &self,
+            _query: &String,
+            _offset: &u64,
+            _limit: &u64,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_count(&self) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn authors(&self, _offset: &u64, _limit: &u64) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn authors_by_ids(
+            &self,
+            _ids: &std::collections::HashSet<u64>,
+        ) -> DResult<Vec<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_id(&self, id: &u64) -> DResult<Option<Author>> {
+            Ok(self.author.clone().filter(|a| a.id == *id))
+        }
+
+        async fn author_by_slug(&self, _slug: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_yandex_id(&self, _yandex_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_telegram_id(&self, _telegram_id: &u64) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn author_by_email(&self, _email: &String) -> DResult<Option<Author>> {
+            unimplemented!()
+        }
+
+        async fn set_author_override_social_data_by_id(
+            &self,
+            _id: &u64,
+            _override_social_data: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_custom_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_minimal_social_author_by_id(
+            &self,
+            _id: &u64,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_minimal_social_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn insert_password_author(
+            &self,
+            _base_minimal_author: &BaseMinimalAuthor,
+            _email: Option<&String>,
+            _password_hash: &String,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn update_secondary_author_by_id(
+            &self,
+            _id: &u64,
+            _base_secondary_author: &BaseSecondaryAuthor,
+        ) -> DResult<u64> {
+            unimplemented!()
+        }
+
+        async fn set_author_password_hash_by_id(
+            &self,
+            _id: &u64,
+            _password_hash: &String,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_blocked_by_id(
+            &self,
+            _id: &u64,
+            _is_blocked: &u8,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_role_by_id(
+            &self,
+            _id: &u64,
+            _role: &AuthorRole,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_subscription_by_id(
+            &self,
+            _id: &u64,
+            _is_subscribed: &u8,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn set_author_social_ids_by_id(
+            &self,
+            _id: &u64,
+            _yandex_id: Option<&u64>,
+            _telegram_id: Option<&u64>,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn merge_authors(
+            &self,
+            _source_id: &u64,
+            _target_id: &u64,
+            _audit_record: &BaseAuditRecord,
+        ) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn delete_author(&self, _id: &u64, _posts: &DeletedAuthorPosts) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn token_is_read_from_bearer_authorization() {
+        let result = token(
+            &Method::POST,
+            &headers(&[("Authorization", "Bearer abc.def.ghi")]),
+        );
+        assert_eq!(result.ok().as_deref(), Some("abc.def.ghi"));
+
+        let result = token(
+            &Method::GET,
+            &headers(&[("Authorization", "bearer  abc.def.ghi ")]),
+        );
+        assert_eq!(result.ok().as_deref(), Some("abc.def.ghi"));
+    }
+
+    #[test]
+    fn token_rejects_other_authorization_schemes() {
+        for value in ["Basic am9objpzZWNyZXQ=", "abc.def.ghi"] {
+            let result = token(&Method::GET, &headers(&[("Authorization", value)]));
+            assert!(matches!(result, Err(Error::AuthorizationSchemeUnsupported)));
+        }
+    }
+
+    #[test]
+    fn token_falls_back_to_token_header() {
+        let result = token(&Method::POST, &headers(&[("Token", "abc.def.ghi")]));
+        assert_eq!(result.ok().as_deref(), Some("abc.def.ghi"));
+
+        // Authorization wins when both are sent.
+        let result = token(
+            &Method::POST,
+            &headers(&[("Authorization", "Bearer new"), ("Token", "old")]),
+        );
+        assert_eq!(result.ok().as_deref(), Some("new"));
+    }
+
+    #[test]
+    fn session_cookie_is_accepted_for_safe_methods_only() {
+        let cookie = headers(&[("Cookie", "theme=dark; blog_session=abc.def.ghi")]);
+        for method in [Method::GET, Method::HEAD] {
+            assert_eq!(token(&method, &cookie).ok().as_deref(), Some("abc.def.ghi"));
+        }
+        for method in [Method::POST, Method::PUT, Method::PATCH, Method::DELETE] {
+            assert!(matches!(token(&method, &cookie), Err(Error::TokenMissing)));
+        }
+    }
+
+    #[test]
+    fn token_missing_without_credentials() {
+        let result = token(&Method::GET, &headers(&[("Cookie", "theme=dark")]));
+        assert!(matches!(result, Err(Error::TokenMissing)));
+    }
+
+    #[tokio::test]
+    async fn sessions_turn_down_access_tokens() {
+        for (name, value) in [
+            ("Authorization", "Bearer pat_1_secret"),
+            ("Token", "pat_1_secret"),
+        ] {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, HeaderValue::from_static(value));
+            for method in [Method::POST, Method::DELETE] {
+                let result = session_by_headers(
+                    &method,
+                    &headers,
+                    Arc::new(StubAuthorService { author: None }),
+                )
+                .await;
+                assert!(matches!(result, Err(Error::AccessTokenNotAccepted)));
+            }
+        }
+    }
+
+    /// Rotates refresh tokens the way the database does: only the current hash of an
+    /// active session is accepted, and the replaced ones are remembered.
+    #[derive(Default)]
+    struct MockSessionService {
+        sessions: Mutex<Vec<Session>>,
+        rotated: Mutex<Vec<(u64, String)>>,
+    }
+
+    #[async_trait]
+    impl SessionService for MockSessionService {
+        async fn create_session(&self, session: &BaseSession) -> DResult<u64> {
+            let mut sessions = self.sessions.lock().unwrap();
+            let id = sessions.len() as u64 + 1;
+            sessions.push(Session {
+                id,
+                revoked: 0,
+                base: session.clone(),
+            });
+            Ok(id)
+        }
+
+        async fn active_sessions_by_author_id(&self, _author_id: &u64) -> DResult<Vec<Session>> {
+            unimplemented!()
+        }
+
+        async fn rotate_refresh_token(
+            &self,
+            id: &u64,
+            refresh_token_hash: &String,
+            new_refresh_token_hash: &String,
+            expires_at: &u64,
+        ) -> DResult<Option<Session>> {
+            let mut sessions = self.sessions.lock().unwrap();
+            let Some(session) = sessions.iter_mut().find(|s| {
+                s.id == *id && s.revoked == 0 && s.base.refresh_token_hash == *refresh_token_hash
+            }) else {
+                return Ok(None);
+            };
+            self.rotated
+                .lock()
+                .unwrap()
+                .push((*id, refresh_token_hash.clone()));
+            session.base.refresh_token_hash = new_refresh_token_hash.clone();
+            session.base.expires_at = *expires_at;
+            Ok(Some(session.clone()))
+        }
+
+        async fn revoke_session_by_rotated_refresh_token(
+            &self,
+            id: &u64,
+            refresh_token_hash: &String,
+        ) -> DResult<bool> {
+            let is_rotated = self
+                .rotated
+                .lock()
+                .unwrap()
+                .contains(&(*id, refresh_token_hash.clone()));
+            let mut sessions = self.sessions.lock().unwrap();
+            match sessions.iter_mut().find(|s| s.id == *id && s.revoked == 0) {
+                Some(session) if is_rotated => {
+                    session.revoked = 1;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn revoke_session_by_id(&self, _id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+
+        async fn revoke_sessions_by_author_id(&self, _author_id: &u64) -> DResult<()> {
+            unimplemented!()
+        }
+    }
+
+    fn sample_author() -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash: Some("hash".into()),
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role: AuthorRole::Contributor,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes: None,
+        }
+    }
+
+    async fn signed_in() -> (
+        SessionTokens,
+        Arc<StubAuthorService>,
+        Arc<MockSessionService>,
+    ) {
+        unsafe { std::env::set_var("JWT_SECRET", "secret") };
+        let author_service = Arc::new(StubAuthorService {
+            author: Some(sample_author()),
+        });
+        let session_service = Arc::new(MockSessionService::default());
+        let Ok(tokens) = start_session(sample_author(), None, session_service.clone()).await else {
+            panic!("session not started");
+        };
+        (tokens, author_service, session_service)
+    }
+
+    async fn refresh(
+        refresh_token: &str,
+        author_service: &Arc<StubAuthorService>,
+        session_service: &Arc<MockSessionService>,
+    ) -> Result<SessionTokens, SessionError> {
+        refresh_session(
+            refresh_token,
+            author_service.clone(),
+            session_service.clone(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn refresh_rotates_the_refresh_token() {
+        let (tokens, author_service, session_service) = signed_in().await;
+
+        let Ok(refreshed) = refresh(&tokens.refresh_token, &author_service, &session_service).await
+        else {
+            panic!("refresh failed");
+        };
+        assert_ne!(refreshed.refresh_token, tokens.refresh_token);
+        assert!(refreshed.refresh_token.starts_with("1."));
+
+        let Ok(refreshed_again) =
+            refresh(&refreshed.refresh_token, &author_service, &session_service).await
+        else {
+            panic!("second refresh failed");
+        };
+        assert_ne!(refreshed_again.refresh_token, refreshed.refresh_token);
+        assert_eq!(session_service.sessions.lock().unwrap()[0].revoked, 0);
+    }
+
+    #[tokio::test]
+    async fn reused_refresh_token_revokes_the_session() {
+        let (tokens, author_service, session_service) = signed_in().await;
+
+        let Ok(refreshed) = refresh(&tokens.refresh_token, &author_service, &session_service).await
+        else {
+            panic!("refresh failed");
+        };
+
+        let reused = refresh(&tokens.refresh_token, &author_service, &session_service).await;
+        assert!(matches!(reused, Err(SessionError::RefreshTokenReused)));
+        assert_eq!(session_service.sessions.lock().unwrap()[0].revoked, 1);
+
+        // The copy that was rotated legitimately ends with the session too.
+        let latest = refresh(&refreshed.refresh_token, &author_service, &session_service).await;
+        assert!(matches!(latest, Err(SessionError::RefreshTokenInvalid)));
+    }
+
+    #[tokio::test]
+    async fn garbage_refresh_token_is_invalid() {
+        let (_, author_service, session_service) = signed_in().await;
+
+        for refresh_token in ["", "abc", "abc.def", "1.wrong", "2.wrong"] {
+            let result = refresh(refresh_token, &author_service, &session_service).await;
+            assert!(matches!(result, Err(SessionError::RefreshTokenInvalid)));
+        }
+        assert_eq!(session_service.sessions.lock().unwrap()[0].revoked, 0);
+    }
 }
@@ -0,0 +1,248 @@
+use blog_generic::entities::PublishType;
+use blog_server_services::traits::author_service::AuthorService;
+use blog_server_services::traits::email_subscription_service::{
+    EmailSubscription, EmailSubscriptionService,
+};
+use blog_server_services::traits::follow_service::FollowService;
+use blog_server_services::traits::mail_service::{Mail, MailService};
+use blog_server_services::traits::notification_preference_service::{
+    DigestFrequency, DueEmailDigest, NotificationEvent, NotificationPreferenceService,
+};
+use blog_server_services::traits::post_service::{PostService, PostsQuery, PostsSort};
+use blog_server_services::utils::{email_digest, time_utils};
+use screw_components::dyn_result::DResult;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often subscriptions and author preferences are checked for a due digest, and so
+/// how long "immediate" emails are batched.
+const CHECK_INTERVAL: u64 = 60 * 60;
+const DAY: u64 = 60 * 60 * 24;
+const MAX_POSTS: u64 = 50;
+
+pub fn spawn(
+    post_service: Arc<dyn PostService>,
+    email_subscription_service: Arc<dyn EmailSubscriptionService>,
+    mail_service: Arc<dyn MailService>,
+    author_service: Arc<dyn AuthorService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    follow_service: Arc<dyn FollowService>,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(CHECK_INTERVAL));
+        loop {
+            interval.tick().await;
+            send_due_digests(
+                post_service.clone(),
+                email_subscription_service.clone(),
+                mail_service.clone(),
+            )
+            .await;
+            send_due_author_digests(
+                post_service.clone(),
+                author_service.clone(),
+                notification_preference_service.clone(),
+                follow_service.clone(),
+                mail_service.clone(),
+            )
+            .await;
+        }
+    });
+}
+
+async fn send_due_digests(
+    post_service: Arc<dyn PostService>,
+    email_subscription_service: Arc<dyn EmailSubscriptionService>,
+    mail_service: Arc<dyn MailService>,
+) {
+    for (frequency, period) in [
+        (DigestFrequency::Daily, DAY),
+        (DigestFrequency::Weekly, DAY * 7),
+    ] {
+        let now = time_utils::now_as_secs();
+        let subscriptions = match email_subscription_service
+            .claim_due_email_subscriptions(&frequency, &(now - period), &now)
+            .await
+        {
+            Ok(subscriptions) => subscriptions,
+            Err(err) => {
+                println!("Failed to claim due email subscriptions: {err}");
+                continue;
+            }
+        };
+        for subscription in subscriptions {
+            if let Err(err) = send_digest(
+                &subscription,
+                frequency,
+                now,
+                post_service.clone(),
+                mail_service.clone(),
+            )
+            .await
+            {
+                println!("Failed to send email digest: {err}");
+            }
+        }
+    }
+}
+
+/// The subscription is already claimed as sent at `now`, so a failed digest is skipped
+/// rather than sent twice. Nothing is sent when no posts were published since the
+/// previous digest.
+async fn send_digest(
+    subscription: &EmailSubscription,
+    frequency: DigestFrequency,
+    now: u64,
+    post_service: Arc<dyn PostService>,
+    mail_service: Arc<dyn MailService>,
+) -> DResult<()> {
+    let since = subscription
+        .base
+        .last_sent_at
+        .unwrap_or(subscription.base.created_at);
+
+    let posts = post_service
+        .posts(
+            PostsQuery::offset_and_limit(&0, &MAX_POSTS)
+                .publish_type(Some(&PublishType::Published))
+                .created_between(Some(since), Some(now))
+                .sort(Some(PostsSort::Newest)),
+        )
+        .await?
+        .posts;
+
+    if !posts.is_empty() {
+        let digest = email_digest::render(
+            &posts,
+            frequency,
+            &crate::SITE_URL,
+            &super::email_subscription::unsubscribe_url(subscription)?,
+        );
+        mail_service
+            .send(Mail {
+                to: subscription.base.email.clone(),
+                subject: digest.subject,
+                body: digest.text,
+                html: Some(digest.html),
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+async fn send_due_author_digests(
+    post_service: Arc<dyn PostService>,
+    author_service: Arc<dyn AuthorService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    follow_service: Arc<dyn FollowService>,
+    mail_service: Arc<dyn MailService>,
+) {
+    for (frequency, period) in [
+        (DigestFrequency::Immediate, CHECK_INTERVAL),
+        (DigestFrequency::Daily, DAY),
+        (DigestFrequency::Weekly, DAY * 7),
+    ] {
+        let now = time_utils::now_as_secs();
+        // Immediate digests go out on every check, whatever the tick drift.
+        let sent_before = match frequency {
+            DigestFrequency::Immediate => now,
+            _ => now - period,
+        };
+        let due_digests = match notification_preference_service
+            .claim_due_email_digests(&frequency, &sent_before, &now)
+            .await
+        {
+            Ok(due_digests) => due_digests,
+            Err(err) => {
+                println!("Failed to claim due author email digests: {err}");
+                continue;
+            }
+        };
+        for due_digest in due_digests {
+            if let Err(err) = send_author_digest(
+                &due_digest,
+                frequency,
+                now - period,
+                now,
+                post_service.clone(),
+                author_service.clone(),
+                follow_service.clone(),
+                mail_service.clone(),
+            )
+            .await
+            {
+                println!("Failed to send author email digest: {err}");
+            }
+        }
+    }
+}
+
+/// Mails the posts the author chose in their email preferences, every new post or only
+/// those of followed authors. Claimed the same way as subscriptions, so a failed digest
+/// is skipped. `first_since` is used when no digest was sent to the author yet.
+async fn send_author_digest(
+    due_digest: &DueEmailDigest,
+    frequency: DigestFrequency,
+    first_since: u64,
+    now: u64,
+    post_service: Arc<dyn PostService>,
+    author_service: Arc<dyn AuthorService>,
+    follow_service: Arc<dyn FollowService>,
+    mail_service: Arc<dyn MailService>,
+) -> DResult<()> {
+    let events = due_digest.events();
+    let author_ids = if events.contains(&NotificationEvent::NewPost) {
+        vec![]
+    } else if events.contains(&NotificationEvent::FollowedAuthorPost) {
+        let author_ids = follow_service
+            .followed_author_ids(&due_digest.author_id)
+            .await?;
+        if author_ids.is_empty() {
+            return Ok(());
+        }
+        author_ids
+    } else {
+        return Ok(());
+    };
+
+    let Some(author) = author_service.author_by_id(&due_digest.author_id).await? else {
+        return Ok(());
+    };
+    let Some(email) = author.base.email.clone() else {
+        return Ok(());
+    };
+    if author.base.blocked == 1 {
+        return Ok(());
+    }
+
+    let since = due_digest.digest_sent_at.unwrap_or(first_since);
+
+    let posts = post_service
+        .posts(
+            PostsQuery::offset_and_limit(&0, &MAX_POSTS)
+                .publish_type(Some(&PublishType::Published))
+                .created_between(Some(since), Some(now))
+                .author_ids(&author_ids)
+                .sort(Some(PostsSort::Newest)),
+        )
+        .await?
+        .posts;
+
+    if !posts.is_empty() {
+        let digest = email_digest::render(
+            &posts,
+            frequency,
+            &crate::SITE_URL,
+            &format!("{}/author/me/notifications", &*crate::SITE_URL),
+        );
+        mail_service
+            .send(Mail {
+                to: email,
+                subject: digest.subject,
+                body: digest.text,
+                html: Some(digest.html),
+            })
+            .await?;
+    }
+    Ok(())
+}
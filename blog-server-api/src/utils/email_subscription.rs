@@ -0,0 +1,110 @@
+use blog_server_services::traits::email_subscription_service::{
+    EmailSubscription, EmailSubscriptionService,
+};
+use jsonwebtoken::errors::{Error as JwtError, Result as JwtResult};
+use serde::{Deserialize, Serialize};
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::sync::Arc;
+
+const CONFIRM_TOKEN_LIFETIME: u64 = 60 * 60 * 24 * 2;
+const CONFIRM_PURPOSE: &str = "email_subscription_confirm";
+/// Unsubscribe links have to keep working in old digests.
+const UNSUBSCRIBE_TOKEN_LIFETIME: u64 = 60 * 60 * 24 * 365 * 10;
+const UNSUBSCRIBE_PURPOSE: &str = "email_unsubscribe";
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionData {
+    subscription_id: u64,
+    purpose: String,
+    exp: u64,
+}
+
+/// Signed with the subscription email mixed into the secret.
+fn token(subscription: &EmailSubscription, purpose: &str, lifetime: u64) -> JwtResult<String> {
+    super::jwt::encode(
+        &SubscriptionData {
+            subscription_id: subscription.id,
+            purpose: purpose.to_string(),
+            exp: jsonwebtoken::get_current_timestamp() + lifetime,
+        },
+        &subscription.base.email,
+    )
+}
+
+async fn subscription_by_token(
+    token: &str,
+    purpose: &str,
+    email_subscription_service: Arc<dyn EmailSubscriptionService>,
+) -> Result<EmailSubscription, Error> {
+    let insecure_data =
+        super::jwt::insecure_decode::<SubscriptionData>(token).map_err(|e| Error::Token(e))?;
+
+    let subscription = email_subscription_service
+        .email_subscription_by_id(&insecure_data.subscription_id)
+        .await
+        .map_err(|e| Error::DatabaseError(e))?
+        .ok_or(Error::SubscriptionNotFound)?;
+
+    let data = super::jwt::decode::<SubscriptionData>(token, &subscription.base.email)
+        .map_err(|e| Error::Token(e))?;
+
+    if data.purpose != purpose {
+        return Err(Error::Token(
+            jsonwebtoken::errors::ErrorKind::InvalidToken.into(),
+        ));
+    }
+
+    Ok(subscription)
+}
+
+pub fn confirm_url(subscription: &EmailSubscription) -> JwtResult<String> {
+    Ok(format!(
+        "{site_url}/subscription/email/confirm?token={token}",
+        site_url = &*crate::SITE_URL,
+        token = token(subscription, CONFIRM_PURPOSE, CONFIRM_TOKEN_LIFETIME)?,
+    ))
+}
+
+pub async fn subscription_by_confirm_token(
+    token: &str,
+    email_subscription_service: Arc<dyn EmailSubscriptionService>,
+) -> Result<EmailSubscription, Error> {
+    subscription_by_token(token, CONFIRM_PURPOSE, email_subscription_service).await
+}
+
+pub fn unsubscribe_url(subscription: &EmailSubscription) -> JwtResult<String> {
+    Ok(format!(
+        "{site_url}/subscription/email/unsubscribe?token={token}",
+        site_url = &*crate::SITE_URL,
+        token = token(
+            subscription,
+            UNSUBSCRIBE_PURPOSE,
+            UNSUBSCRIBE_TOKEN_LIFETIME
+        )?,
+    ))
+}
+
+pub async fn subscription_by_unsubscribe_token(
+    token: &str,
+    email_subscription_service: Arc<dyn EmailSubscriptionService>,
+) -> Result<EmailSubscription, Error> {
+    subscription_by_token(token, UNSUBSCRIBE_PURPOSE, email_subscription_service).await
+}
+
+pub enum Error {
+    Token(JwtError),
+    DatabaseError(Box<dyn StdError + Send>),
+    SubscriptionNotFound,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Token(e) => write!(f, "{}", e.to_string()),
+            Error::DatabaseError(e) => write!(f, "{}", e.to_string()),
+            Error::SubscriptionNotFound => write!(f, "subscription not found"),
+        }
+    }
+}
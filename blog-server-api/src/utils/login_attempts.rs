@@ -0,0 +1,144 @@
+use blog_server_services::traits::login_attempt_service::{LoginAttemptService, LoginFailures};
+use blog_server_services::utils::time_utils;
+use screw_components::dyn_result::DResult;
+use std::sync::Arc;
+
+/// Failures in a row that are not slowed down at all.
+const FREE_ATTEMPTS: u64 = 5;
+/// Same for failures of an author from every address together, which caps
+/// guessing spread over many addresses.
+const AUTHOR_FREE_ATTEMPTS: u64 = 20;
+/// Address the author-wide failures are kept under.
+const ANY_ADDRESS: &str = "*";
+const BASE_DELAY: u64 = 1;
+const MAX_DELAY: u64 = 60 * 60;
+/// Failures older than this are forgotten.
+const FAILURES_LIFETIME: u64 = 60 * 60 * 24;
+/// Addresses not signed in from for this long aren't trusted any more.
+const KNOWN_ADDRESS_LIFETIME: u64 = 60 * 60 * 24 * 90;
+/// Times an attempt is counted again after concurrent ones got ahead of it.
+const COUNTING_RETRIES: usize = 5;
+
+/// Seconds to wait after the latest of `failed_count` failures in a row:
+/// nothing for the first `free_attempts`, then doubling up to an hour.
+pub fn delay_after(failed_count: u64, free_attempts: u64) -> u64 {
+    if failed_count < free_attempts {
+        return 0;
+    }
+    let exponent = (failed_count - free_attempts).min(32) as u32;
+    (BASE_DELAY << exponent).min(MAX_DELAY)
+}
+
+async fn retry_after(
+    author_id: &u64,
+    ip: &String,
+    free_attempts: u64,
+    login_attempt_service: &Arc<dyn LoginAttemptService>,
+) -> DResult<(Option<u64>, LoginFailures)> {
+    let now = time_utils::now_as_secs();
+    let failures = login_attempt_service
+        .failures(author_id, ip, &now.saturating_sub(FAILURES_LIFETIME))
+        .await?
+        .unwrap_or_default();
+    let allowed_at = failures.last_failed_at + delay_after(failures.failed_count, free_attempts);
+    Ok((allowed_at.checked_sub(now).filter(|s| *s > 0), failures))
+}
+
+/// Counts one more failure unless it has to wait, checking again whenever
+/// a concurrent attempt got counted first.
+async fn count_failure(
+    author_id: &u64,
+    ip: &String,
+    free_attempts: u64,
+    login_attempt_service: &Arc<dyn LoginAttemptService>,
+) -> DResult<Option<u64>> {
+    for _ in 0..COUNTING_RETRIES {
+        let (retry_after, failures) =
+            retry_after(author_id, ip, free_attempts, login_attempt_service).await?;
+        if retry_after.is_some() {
+            return Ok(retry_after);
+        }
+        let now = time_utils::now_as_secs();
+        if login_attempt_service
+            .register_failure(
+                author_id,
+                ip,
+                &failures,
+                &now,
+                &now.saturating_sub(FAILURES_LIFETIME),
+            )
+            .await?
+        {
+            return Ok(None);
+        }
+    }
+    Ok(Some(BASE_DELAY))
+}
+
+/// Counts the attempt as failed before its outcome is known, so concurrent guesses
+/// can't all pass the same check; `register_success` forgets it with the earlier ones.
+/// Shared by every step of a login, so a second factor can't be brute-forced separately
+/// from the password. Returns the seconds left when the attempt isn't allowed yet.
+pub async fn begin_attempt(
+    author_id: &u64,
+    ip: &String,
+    login_attempt_service: Arc<dyn LoginAttemptService>,
+) -> DResult<Option<u64>> {
+    let now = time_utils::now_as_secs();
+    // Failures from elsewhere never lock the owner out of an address they signed in from.
+    let is_known_address = login_attempt_service
+        .is_known_address(author_id, ip, &now.saturating_sub(KNOWN_ADDRESS_LIFETIME))
+        .await?;
+    if is_known_address {
+        return count_failure(author_id, ip, FREE_ATTEMPTS, &login_attempt_service).await;
+    }
+    let any_address = ANY_ADDRESS.to_string();
+    // Checked up front, so a throttled author doesn't charge the address of its owner.
+    let (retry_after, _) = retry_after(
+        author_id,
+        &any_address,
+        AUTHOR_FREE_ATTEMPTS,
+        &login_attempt_service,
+    )
+    .await?;
+    if retry_after.is_some() {
+        return Ok(retry_after);
+    }
+    if let Some(retry_after) =
+        count_failure(author_id, ip, FREE_ATTEMPTS, &login_attempt_service).await?
+    {
+        return Ok(Some(retry_after));
+    }
+    count_failure(
+        author_id,
+        &any_address,
+        AUTHOR_FREE_ATTEMPTS,
+        &login_attempt_service,
+    )
+    .await
+}
+
+pub async fn register_success(
+    author_id: &u64,
+    ip: &String,
+    login_attempt_service: Arc<dyn LoginAttemptService>,
+) -> DResult<()> {
+    login_attempt_service.clear_failures(author_id, ip).await?;
+    login_attempt_service
+        .clear_failures(author_id, &ANY_ADDRESS.to_string())
+        .await?;
+    login_attempt_service
+        .register_success(author_id, ip, &time_utils::now_as_secs())
+        .await
+}
+
+/// Drops the records `begin_attempt` doesn't look at any more.
+pub async fn delete_stale(login_attempt_service: Arc<dyn LoginAttemptService>) -> DResult<()> {
+    let now = time_utils::now_as_secs();
+    login_attempt_service
+        .delete_stale(
+            &now.saturating_sub(FAILURES_LIFETIME),
+            &now.saturating_sub(KNOWN_ADDRESS_LIFETIME),
+        )
+        .await
+}
@@ -1,3 +1,11 @@
 pub mod auth;
+pub mod email_digest_job;
+pub mod email_subscription;
 pub mod jwt;
+pub mod login_attempts;
+pub mod oidc;
 pub mod password;
+pub mod request_throttle;
+pub mod social_auth;
+pub mod stale_records_job;
+pub mod two_factor;
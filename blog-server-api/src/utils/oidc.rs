@@ -0,0 +1,88 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use jsonwebtoken::errors::Result as JwtResult;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub const FLOW_TOKEN_LIFETIME: u64 = 60 * 10;
+const FLOW_TOKEN_SECRET: &str = "oidc_flow";
+
+/// Posted by the client after the provider redirects back.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Callback {
+    /// Returned by the authorize endpoint.
+    pub flow_token: String,
+    /// `code` and `state` query parameters of the provider callback.
+    pub code: String,
+    pub state: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FlowData {
+    provider: String,
+    state: String,
+    exp: u64,
+}
+
+/// One authorization code flow, kept by the client between the redirect to the
+/// provider and the callback as a signed flow token.
+pub struct Flow {
+    pub provider: String,
+    pub state: String,
+}
+
+impl Flow {
+    pub fn start(provider: &String) -> Flow {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        Flow {
+            provider: provider.clone(),
+            state: hex::encode(bytes),
+        }
+    }
+
+    pub fn from_token(token: &str) -> JwtResult<Flow> {
+        let flow_data = super::jwt::decode::<FlowData>(token, &FLOW_TOKEN_SECRET.to_string())?;
+        Ok(Flow {
+            provider: flow_data.provider,
+            state: flow_data.state,
+        })
+    }
+
+    pub fn token(&self) -> JwtResult<String> {
+        super::jwt::encode(
+            &FlowData {
+                provider: self.provider.clone(),
+                state: self.state.clone(),
+                exp: jsonwebtoken::get_current_timestamp() + FLOW_TOKEN_LIFETIME,
+            },
+            &FLOW_TOKEN_SECRET.to_string(),
+        )
+    }
+
+    /// Derived from the state with the server secret, so the flow token
+    /// (readable by the client) doesn't carry the PKCE verifier itself.
+    fn derived(&self, purpose: &str) -> String {
+        hex::encode(Sha256::digest(
+            format!("{}:{}:{}", *crate::JWT_SECRET, purpose, self.state).as_bytes(),
+        ))
+    }
+
+    pub fn nonce(&self) -> String {
+        self.derived("oidc_nonce")
+    }
+
+    pub fn code_verifier(&self) -> String {
+        self.derived("oidc_code_verifier")
+    }
+
+    /// Page of the client app that receives the code and posts it to the login endpoint.
+    pub fn redirect_uri(&self) -> String {
+        format!(
+            "{}/oidc/{}/callback",
+            crate::SITE_URL.trim_end_matches('/'),
+            self.provider
+        )
+    }
+}
@@ -4,6 +4,14 @@ use argon2::{
         PasswordHash, PasswordHasher, PasswordVerifier, Result, SaltString, rand_core::OsRng,
     },
 };
+use rand::seq::SliceRandom;
+
+pub const MIN_LENGTH: usize = 8;
+pub const MAX_LENGTH: usize = 128;
+
+pub fn is_acceptable(password: &str) -> bool {
+    (MIN_LENGTH..=MAX_LENGTH).contains(&password.chars().count())
+}
 
 pub fn hash(password: &String) -> Result<String> {
     let salt = SaltString::generate(&mut OsRng);
@@ -20,3 +28,12 @@ pub fn verify(password: &String, password_hash: &String) -> Result<()> {
 
     Ok(())
 }
+
+const GENERATED_ALPHABET: &[u8] = b"abcdefghijkmnpqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Random password without look-alike characters, for printing to an operator.
+pub fn generate(length: usize) -> String {
+    (0..length)
+        .map(|_| *GENERATED_ALPHABET.choose(&mut rand::rngs::OsRng).unwrap() as char)
+        .collect()
+}
@@ -0,0 +1,26 @@
+use blog_server_services::traits::request_throttle_service::RequestThrottleService;
+use blog_server_services::utils::time_utils;
+use screw_components::dyn_result::DResult;
+use std::sync::Arc;
+
+/// Shared by every throttled key, stale windows of all of them are dropped together.
+const WINDOW: u64 = 60 * 60;
+
+/// Counts the request under `key`. Returns the seconds left until the window restarts
+/// once more than `max_requests` were counted in it.
+pub async fn retry_after(
+    key: String,
+    max_requests: u64,
+    request_throttle_service: Arc<dyn RequestThrottleService>,
+) -> DResult<Option<u64>> {
+    let now = time_utils::now_as_secs();
+    let window = request_throttle_service
+        .hit(&key, &now, &now.saturating_sub(WINDOW))
+        .await?;
+    if window.request_count <= max_requests {
+        return Ok(None);
+    }
+    Ok(Some(
+        (window.started_at + WINDOW).saturating_sub(now).max(1),
+    ))
+}
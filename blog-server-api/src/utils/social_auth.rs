@@ -0,0 +1,213 @@
+use blog_generic::*;
+use blog_server_services::traits::author_service::BaseMinimalAuthor;
+use blog_server_services::traits::oidc_service::OidcService;
+use blog_server_services::traits::social_service::SocialId;
+use std::sync::Arc;
+
+#[cfg(any(feature = "yandex", feature = "telegram"))]
+use blog_server_services::utils::time_utils;
+
+/// Checks identity proofs of social providers, shared by the login endpoints
+/// and account linking.
+#[derive(Debug)]
+pub enum Error {
+    ProviderNotFound,
+    /// The OIDC flow token is invalid, expired or issued for another callback.
+    InvalidFlow,
+    Provider(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ProviderNotFound => write!(f, "provider not found"),
+            Error::InvalidFlow => write!(f, "invalid flow"),
+            Error::Provider(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Verified provider identity and the profile data it comes with.
+pub struct SocialAuthor {
+    pub social_id: SocialId,
+    pub base_minimal_author: BaseMinimalAuthor,
+}
+
+#[cfg(feature = "yandex")]
+#[derive(Clone, Debug, serde::Deserialize)]
+struct YandexLoginPhoneResponse {
+    id: u64,
+    number: String,
+}
+
+#[cfg(feature = "yandex")]
+#[derive(Clone, Debug, serde::Deserialize)]
+struct YandexLoginResponse {
+    id: String,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    login: String,
+    default_email: Option<String>,
+    default_phone: Option<YandexLoginPhoneResponse>,
+    #[serde(default)]
+    is_avatar_empty: bool,
+    #[serde(default)]
+    default_avatar_id: String,
+}
+
+#[cfg(feature = "yandex")]
+pub async fn yandex(question: entities::LoginYandexQuestion) -> Result<SocialAuthor, Error> {
+    let entities::LoginYandexQuestion {
+        access_token,
+        token_type: _,
+        expires_in: _,
+    } = question;
+
+    let yandex_login_response = reqwest::Client::new()
+        .get("https://login.yandex.ru/info")
+        .header("Authorization", format!("OAuth {access_token}"))
+        .send()
+        .await
+        .map_err(|e| Error::Provider(format!("request: {}", e.to_string())))?
+        .json::<YandexLoginResponse>()
+        .await
+        .map_err(|e| Error::Provider(format!("parse: {}", e.to_string())))?;
+
+    let Some(yandex_id) = yandex_login_response.id.parse::<u64>().ok() else {
+        return Err(Error::Provider("wrong yandex id format".to_string()));
+    };
+
+    Ok(SocialAuthor {
+        social_id: SocialId::YandexId(yandex_id),
+        base_minimal_author: BaseMinimalAuthor {
+            slug: author_slug_utils::extend(&yandex_login_response.login, &"y".to_string()),
+            first_name: yandex_login_response.first_name,
+            last_name: yandex_login_response.last_name,
+            image_url: if !yandex_login_response.is_avatar_empty {
+                Some(format!(
+                    "https://avatars.yandex.net/get-yapic/{avatar_id}/islands-200?timestamp={timestamp}",
+                    avatar_id = yandex_login_response.default_avatar_id,
+                    timestamp = time_utils::now_as_secs()
+                ))
+            } else {
+                None
+            },
+        },
+    })
+}
+
+#[cfg(feature = "telegram")]
+fn hmac_sha256(key: &[u8], data: &str) -> String {
+    use hmac::Mac;
+    let mut mac =
+        hmac::Hmac::<sha2::Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[cfg(feature = "telegram")]
+pub fn telegram(question: entities::LoginTelegramQuestion) -> Result<SocialAuthor, Error> {
+    use sha2::{Digest, Sha256};
+
+    let entities::LoginTelegramQuestion {
+        id,
+        first_name,
+        last_name,
+        username,
+        photo_url,
+        auth_date,
+        hash,
+    } = question;
+
+    let secret_key = Sha256::digest(crate::TELEGRAM_BOT_TOKEN.as_bytes());
+    let check_string = {
+        let mut parts = vec![format!("id={}", id)];
+
+        if let Some(first_name) = &first_name {
+            parts.push(format!("first_name={}", first_name));
+        }
+
+        if let Some(last_name) = &last_name {
+            parts.push(format!("last_name={}", last_name));
+        }
+
+        parts.push(format!("auth_date={}", auth_date));
+
+        if let Some(username) = &username {
+            parts.push(format!("username={}", username));
+        }
+
+        if let Some(photo_url) = &photo_url {
+            parts.push(format!("photo_url={}", photo_url));
+        }
+
+        parts.sort();
+        parts.join("\n")
+    };
+
+    let computed_hash = hmac_sha256(&secret_key, &check_string);
+
+    if computed_hash != hash {
+        return Err(Error::Provider("incorrect signature".to_string()));
+    }
+
+    Ok(SocialAuthor {
+        social_id: SocialId::TelegramId(id),
+        base_minimal_author: BaseMinimalAuthor {
+            slug: author_slug_utils::extend(&username.unwrap_or(id.to_string()), &"t".to_string()),
+            first_name,
+            last_name,
+            image_url: photo_url.map(|u| {
+                format!(
+                    "{photo_url}?timestamp={timestamp}",
+                    photo_url = u,
+                    timestamp = time_utils::now_as_secs()
+                )
+            }),
+        },
+    })
+}
+
+pub async fn oidc(
+    provider: Option<String>,
+    callback: super::oidc::Callback,
+    oidc_service: Arc<dyn OidcService>,
+) -> Result<SocialAuthor, Error> {
+    let provider = provider.ok_or(Error::ProviderNotFound)?;
+    let provider_config = oidc_service
+        .provider(&provider)
+        .ok_or(Error::ProviderNotFound)?;
+
+    let flow =
+        super::oidc::Flow::from_token(&callback.flow_token).map_err(|_| Error::InvalidFlow)?;
+    if flow.provider != provider || flow.state != callback.state {
+        return Err(Error::InvalidFlow);
+    }
+
+    let user_info = oidc_service
+        .user_info(
+            &provider,
+            &flow.redirect_uri(),
+            &callback.code,
+            &flow.code_verifier(),
+            &flow.nonce(),
+        )
+        .await
+        .map_err(|e| Error::Provider(e.to_string()))?;
+
+    Ok(SocialAuthor {
+        base_minimal_author: BaseMinimalAuthor {
+            slug: author_slug_utils::extend(
+                user_info.username.as_ref().unwrap_or(&user_info.subject),
+                &provider_config.slug_suffix,
+            ),
+            first_name: user_info.first_name,
+            last_name: user_info.last_name,
+            image_url: user_info.picture,
+        },
+        social_id: SocialId::Identity {
+            provider,
+            subject: user_info.subject,
+        },
+    })
+}
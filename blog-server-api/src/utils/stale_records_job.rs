@@ -0,0 +1,19 @@
+use crate::utils::login_attempts;
+use blog_server_services::traits::login_attempt_service::LoginAttemptService;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often expired throttling records are dropped.
+const CLEANUP_INTERVAL: u64 = 60 * 60;
+
+pub fn spawn(login_attempt_service: Arc<dyn LoginAttemptService>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(CLEANUP_INTERVAL));
+        loop {
+            interval.tick().await;
+            if let Err(err) = login_attempts::delete_stale(login_attempt_service.clone()).await {
+                println!("Failed to delete stale login attempts: {err}");
+            }
+        }
+    });
+}
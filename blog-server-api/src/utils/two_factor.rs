@@ -0,0 +1,79 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use blog_server_services::traits::author_service::Author;
+use blog_server_services::traits::two_factor_service::{AuthorTotp, TwoFactorService};
+use blog_server_services::utils::{time_utils, totp};
+use screw_components::dyn_result::DResult;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+const SECRET_LENGTH: usize = 20;
+pub const RECOVERY_CODES_COUNT: usize = 10;
+
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; SECRET_LENGTH];
+    OsRng.fill_bytes(&mut bytes);
+    totp::base32_encode(&bytes)
+}
+
+pub fn otpauth_uri(author: &Author, secret: &str) -> String {
+    let issuer = crate::SITE_URL
+        .split("://")
+        .last()
+        .unwrap_or_default()
+        .trim_end_matches('/');
+    totp::otpauth_uri(issuer, &author.base.slug, secret)
+}
+
+/// `xxxxx-xxxxx` hex codes, shown once right after enrollment.
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODES_COUNT)
+        .map(|_| {
+            let mut bytes = [0u8; 5];
+            OsRng.fill_bytes(&mut bytes);
+            let code = hex::encode(bytes);
+            format!("{}-{}", &code[..5], &code[5..])
+        })
+        .collect()
+}
+
+/// Codes are random enough for a plain hash, the way refresh tokens are stored.
+pub fn recovery_code_hash(code: &str) -> String {
+    let normalized = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+/// Accepts a current TOTP code, or an unused recovery code once 2FA is enabled.
+/// Both are consumed on success.
+pub async fn verify_code(
+    author_totp: &AuthorTotp,
+    code: &str,
+    two_factor_service: Arc<dyn TwoFactorService>,
+) -> DResult<bool> {
+    let code = code.trim().replace(' ', "");
+    if let Some(step) = totp::verify(&author_totp.secret, &code, time_utils::now_as_secs()) {
+        return two_factor_service
+            .use_totp_step(&author_totp.author_id, &step)
+            .await;
+    }
+    if author_totp.enabled == 0 {
+        return Ok(false);
+    }
+    two_factor_service
+        .use_recovery_code(&author_totp.author_id, &recovery_code_hash(&code))
+        .await
+}
+
+/// `Some` only for authors that have confirmed enrollment.
+pub async fn enabled_totp(
+    author_id: &u64,
+    two_factor_service: Arc<dyn TwoFactorService>,
+) -> DResult<Option<AuthorTotp>> {
+    Ok(two_factor_service
+        .totp_by_author_id(author_id)
+        .await?
+        .filter(|t| t.enabled != 0))
+}
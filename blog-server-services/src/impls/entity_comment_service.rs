@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::traits::author_service::{Author, AuthorService};
+use crate::traits::author_service::{Author, AuthorService, AuthorView};
 use crate::traits::comment_service::Comment;
 use crate::traits::entity_comment_service::EntityCommentService as EntityCommentServiceTrait;
 use blog_generic::entities::Comment as EComment;
@@ -13,7 +13,7 @@ pub fn create_entity_comment_service(
     Arc::new(EntityCommentService { author_service })
 }
 
-struct ECommentBuilder(Comment, Author);
+struct ECommentBuilder(Comment, Author, AuthorView);
 
 impl Into<EComment> for ECommentBuilder {
     fn into(self) -> EComment {
@@ -26,7 +26,7 @@ impl Into<EComment> for ECommentBuilder {
             } else {
                 None
             },
-            author: self.1.into(),
+            author: self.1.entity(self.2),
         }
     }
 }
@@ -37,7 +37,11 @@ struct EntityCommentService {
 
 #[async_trait]
 impl EntityCommentServiceTrait for EntityCommentService {
-    async fn comments_entities(&self, comments: Vec<Comment>) -> DResult<Vec<EComment>> {
+    async fn comments_entities(
+        &self,
+        comments: Vec<Comment>,
+        viewer: Option<&Author>,
+    ) -> DResult<Vec<EComment>> {
         let authors_ids = comments
             .iter()
             .map(|c| c.base.author_id)
@@ -56,7 +60,8 @@ impl EntityCommentServiceTrait for EntityCommentService {
                 .get(&comment.base.author_id)
                 .cloned()
                 .ok_or::<DError>("wrong authors map".into())?;
-            let comment_entity = ECommentBuilder(comment, comment_author).into();
+            let view = AuthorView::of(&comment_author.id, viewer);
+            let comment_entity = ECommentBuilder(comment, comment_author, view).into();
             comments_entities.push(comment_entity);
         }
         Ok(comments_entities)
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::traits::author_service::{Author, AuthorService};
+use crate::traits::author_service::{Author, AuthorService, AuthorView};
 use crate::traits::entity_post_service::EntityPostService as EntityPostServiceTrait;
 use crate::traits::post_service::{BasePost, Post};
 use crate::utils::image_signer::{ImageVariant, processed_image_urls};
@@ -14,7 +14,7 @@ pub fn create_entity_post_service(
     Arc::new(EntityPostService { author_service })
 }
 
-struct EPostBuilder(Post, Author);
+struct EPostBuilder(Post, Author, AuthorView);
 
 impl Into<EPost> for EPostBuilder {
     fn into(self) -> EPost {
@@ -45,7 +45,7 @@ impl Into<EPost> for EPostBuilder {
             recommended: self.0.recommended != 0,
             created_at: self.0.base.created_at,
             content: self.0.base.content,
-            author: self.1.into(),
+            author: self.1.entity(self.2),
             tags: self
                 .0
                 .tags
@@ -69,7 +69,11 @@ struct EntityPostService {
 
 #[async_trait]
 impl EntityPostServiceTrait for EntityPostService {
-    async fn posts_entities(&self, posts: Vec<Post>) -> DResult<Vec<EPost>> {
+    async fn posts_entities(
+        &self,
+        posts: Vec<Post>,
+        viewer: Option<&Author>,
+    ) -> DResult<Vec<EPost>> {
         let authors_ids = posts
             .iter()
             .map(|c| c.base.author_id)
@@ -88,7 +92,8 @@ impl EntityPostServiceTrait for EntityPostService {
                 .get(&post.base.author_id)
                 .cloned()
                 .ok_or::<DError>("wrong authors map".into())?;
-            let post_entity = EPostBuilder(post, post_author).into();
+            let view = AuthorView::of(&post_author.id, viewer);
+            let post_entity = EPostBuilder(post, post_author, view).into();
             posts_entities.push(post_entity);
         }
         Ok(posts_entities)
@@ -0,0 +1,62 @@
+use rbatis::rbdc::rt::tokio;
+use screw_components::dyn_result::DResult;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::mail_service::{FileMailConfig, Mail, MailService};
+
+pub fn create_file_mail_service(config: FileMailConfig) -> Arc<dyn MailService> {
+    Arc::new(FileMailService {
+        path: config.path,
+        lock: Arc::new(Mutex::new(())),
+    })
+}
+
+struct FileMailService {
+    path: Option<String>,
+    /// Keeps mails sent at the same time from interleaving.
+    lock: Arc<Mutex<()>>,
+}
+
+impl FileMailService {
+    fn format(mail: &Mail) -> String {
+        let mut formatted = format!(
+            "To: {to}\nSubject: {subject}\n\n{body}\n",
+            to = mail.to,
+            subject = mail.subject,
+            body = mail.body,
+        );
+        if let Some(html) = &mail.html {
+            formatted += &format!("\n--- html ---\n{html}\n");
+        }
+        formatted += "\n=====\n\n";
+        formatted
+    }
+}
+
+#[async_trait]
+impl MailService for FileMailService {
+    async fn send(&self, mail: Mail) -> DResult<()> {
+        let formatted = Self::format(&mail);
+        let path = self.path.clone();
+        let lock = self.lock.clone();
+        // The lock and the file write block, they run off the async workers.
+        tokio::task::spawn_blocking(move || -> std::io::Result<()> {
+            let _guard = lock
+                .lock()
+                .map_err(|e| std::io::Error::other(e.to_string()))?;
+            match path {
+                Some(path) => OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)?
+                    .write_all(formatted.as_bytes())?,
+                None => print!("{formatted}"),
+            }
+            Ok(())
+        })
+        .await??;
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use blog_generic::events::NewPostPublished;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::traits::Publish;
+use crate::traits::audit_service::{AuditTarget, ModerationDecision};
+use crate::traits::comment_service::{CommentPublished, CommentService};
+use crate::traits::follow_service::FollowService;
+use crate::traits::notification_preference_service::{
+    NotificationChannel, NotificationEvent, NotificationPreferenceService,
+};
+use crate::traits::notification_service::{BaseNotification, NotificationService};
+use crate::traits::post_service::PostService;
+use crate::utils::time_utils;
+
+/// Recent commenters notified of a reply, long discussions don't notify everyone.
+const REPLY_RECIPIENTS_LIMIT: u64 = 50;
+
+pub fn create_in_app_notification_service(
+    notification_service: Arc<dyn NotificationService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    follow_service: Arc<dyn FollowService>,
+    post_service: Arc<dyn PostService>,
+    comment_service: Arc<dyn CommentService>,
+) -> Arc<impl Publish<NewPostPublished> + Publish<CommentPublished> + Publish<ModerationDecision>> {
+    Arc::new(InAppNotificationService {
+        notification_service,
+        notification_preference_service,
+        follow_service,
+        post_service,
+        comment_service,
+    })
+}
+
+struct InAppNotificationService {
+    notification_service: Arc<dyn NotificationService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    follow_service: Arc<dyn FollowService>,
+    post_service: Arc<dyn PostService>,
+    comment_service: Arc<dyn CommentService>,
+}
+
+impl InAppNotificationService {
+    /// The recipient is filled in by `notify`.
+    fn notification(
+        event: NotificationEvent,
+        actor_id: u64,
+        link: Option<String>,
+    ) -> BaseNotification {
+        BaseNotification {
+            author_id: 0,
+            event,
+            actor_id,
+            action: None,
+            link,
+            created_at: time_utils::now_as_secs(),
+            read: 0,
+        }
+    }
+
+    async fn post_link(&self, post_id: &u64) -> Option<String> {
+        let post = self.post_service.post_by_id(post_id).await.ok()??;
+        Some(format!("/post/{}/{}", post.base.slug, post.id))
+    }
+
+    /// Stores a copy of `notification` for each recipient keeping in-app
+    /// notifications of its event on.
+    async fn notify(&self, recipient_ids: HashSet<u64>, notification: BaseNotification) {
+        if recipient_ids.is_empty() {
+            return;
+        }
+        let Ok(preferences) = self
+            .notification_preference_service
+            .notification_preferences_by_author_ids(&recipient_ids)
+            .await
+        else {
+            return;
+        };
+        let notifications: Vec<BaseNotification> = preferences
+            .into_iter()
+            .filter(|p| p.wants(NotificationChannel::InApp, notification.event))
+            .map(|p| BaseNotification {
+                author_id: p.author_id,
+                ..notification.clone()
+            })
+            .collect();
+        let _ = self
+            .notification_service
+            .create_notifications(&notifications)
+            .await;
+    }
+}
+
+#[async_trait]
+impl Publish<NewPostPublished> for InAppNotificationService {
+    async fn publish(&self, event: NewPostPublished) {
+        let Ok(follower_ids) = self.follow_service.follower_ids(&event.blog_user_id).await else {
+            return;
+        };
+        self.notify(
+            follower_ids.into_iter().collect(),
+            Self::notification(
+                NotificationEvent::FollowedAuthorPost,
+                event.blog_user_id,
+                Some(event.post_sub_url),
+            ),
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl Publish<CommentPublished> for InAppNotificationService {
+    async fn publish(&self, event: CommentPublished) {
+        let Ok(Some(post)) = self.post_service.post_by_id(&event.post_id).await else {
+            return;
+        };
+        let link = format!("/post/{}/{}", post.base.slug, post.id);
+
+        if post.base.author_id != event.author_id {
+            self.notify(
+                HashSet::from([post.base.author_id]),
+                Self::notification(
+                    NotificationEvent::PostComment,
+                    event.author_id,
+                    Some(link.clone()),
+                ),
+            )
+            .await;
+        }
+
+        let Ok(commenter_ids) = self
+            .comment_service
+            .commenter_ids_before(&event.post_id, &event.comment_id, &REPLY_RECIPIENTS_LIMIT)
+            .await
+        else {
+            return;
+        };
+        let recipient_ids = event.reply_recipient_ids(commenter_ids, post.base.author_id);
+        self.notify(
+            recipient_ids,
+            Self::notification(NotificationEvent::CommentReply, event.author_id, Some(link)),
+        )
+        .await;
+    }
+}
+
+#[async_trait]
+impl Publish<ModerationDecision> for InAppNotificationService {
+    async fn publish(&self, event: ModerationDecision) {
+        if event.author_id == event.moderator_id {
+            return;
+        }
+        let link = match event.target_type {
+            AuditTarget::Author => None,
+            AuditTarget::Post => self.post_link(&event.target_id).await,
+            AuditTarget::Comment => {
+                match self.comment_service.comment_by_id(&event.target_id).await {
+                    Ok(Some(comment)) => self.post_link(&comment.base.post_id).await,
+                    _ => None,
+                }
+            }
+        };
+        self.notify(
+            HashSet::from([event.author_id]),
+            BaseNotification {
+                action: Some(event.action),
+                ..Self::notification(
+                    NotificationEvent::ModerationDecision,
+                    event.moderator_id,
+                    link,
+                )
+            },
+        )
+        .await;
+    }
+}
@@ -0,0 +1,69 @@
+use rbatis::rbdc::rt::tokio;
+use screw_components::dyn_result::DResult;
+use sha2::{Digest, Sha256};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::traits::image_storage_service::{ImageStorageService, StoredImage};
+use crate::utils::image_upload::{self, UploadFormat};
+
+pub fn create_local_image_storage_service(
+    path: String,
+    public_url: String,
+) -> Arc<dyn ImageStorageService> {
+    Arc::new(LocalImageStorageService {
+        path: PathBuf::from(path),
+        public_url: public_url.trim_end_matches('/').to_string(),
+    })
+}
+
+struct LocalImageStorageService {
+    path: PathBuf,
+    public_url: String,
+}
+
+#[async_trait]
+impl ImageStorageService for LocalImageStorageService {
+    async fn store_image(&self, data: &[u8], format: UploadFormat) -> DResult<String> {
+        let path = self.path.clone();
+        let data = data.to_vec();
+        // Hashing and file writes block, they run off the async workers.
+        let name = tokio::task::spawn_blocking(move || -> std::io::Result<String> {
+            // Named by content, so the same image uploaded twice is stored once.
+            let name = format!(
+                "{hash}.{extension}",
+                hash = hex::encode(Sha256::digest(&data)),
+                extension = format.extension(),
+            );
+            std::fs::create_dir_all(&path)?;
+            let file_path = path.join(&name);
+            if !file_path.exists() {
+                // Written aside first, readers never see a partial file.
+                let temp_path = path.join(format!("{name}.tmp"));
+                std::fs::write(&temp_path, &data)?;
+                std::fs::rename(&temp_path, &file_path)?;
+            }
+            Ok(name)
+        })
+        .await??;
+        Ok(format!("{}/{name}", self.public_url))
+    }
+    async fn image_by_name(&self, name: &String) -> DResult<Option<StoredImage>> {
+        if !image_upload::is_stored_name(name) {
+            return Ok(None);
+        }
+        let Some(format) = name
+            .rsplit_once('.')
+            .and_then(|(_, extension)| UploadFormat::from_extension(extension))
+        else {
+            return Ok(None);
+        };
+        let file_path = self.path.join(name);
+        match tokio::task::spawn_blocking(move || std::fs::read(file_path)).await? {
+            Ok(data) => Ok(Some(StoredImage { data, format })),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::login_attempt_service::{LoginAttemptService, LoginFailures};
+use screw_components::dyn_result::DResult;
+
+/// Process-local storage, for tests and single instance setups without a database.
+pub fn create_memory_login_attempt_service() -> Arc<dyn LoginAttemptService> {
+    Arc::new(MemoryLoginAttemptService {
+        storage: Mutex::new(HashMap::new()),
+        known_addresses: Mutex::new(HashMap::new()),
+    })
+}
+
+struct MemoryLoginAttemptService {
+    storage: Mutex<HashMap<(u64, String), LoginFailures>>,
+    /// Time of the latest successful sign in, by author and address.
+    known_addresses: Mutex<HashMap<(u64, String), u64>>,
+}
+
+#[async_trait]
+impl LoginAttemptService for MemoryLoginAttemptService {
+    async fn failures(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        since: &u64,
+    ) -> DResult<Option<LoginFailures>> {
+        let storage = self.storage.lock().map_err(|e| e.to_string())?;
+        Ok(storage
+            .get(&(*author_id, ip.clone()))
+            .filter(|f| f.last_failed_at >= *since)
+            .copied())
+    }
+    async fn register_failure(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        current: &LoginFailures,
+        failed_at: &u64,
+        reset_before: &u64,
+    ) -> DResult<bool> {
+        let mut storage = self.storage.lock().map_err(|e| e.to_string())?;
+        let failures = storage.entry((*author_id, ip.clone())).or_default();
+        if failures.last_failed_at < *reset_before {
+            *failures = LoginFailures::default();
+        }
+        if failures != current {
+            return Ok(false);
+        }
+        failures.failed_count += 1;
+        failures.last_failed_at = *failed_at;
+        Ok(true)
+    }
+    async fn clear_failures(&self, author_id: &u64, ip: &String) -> DResult<()> {
+        let mut storage = self.storage.lock().map_err(|e| e.to_string())?;
+        storage.remove(&(*author_id, ip.clone()));
+        Ok(())
+    }
+    async fn register_success(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        succeeded_at: &u64,
+    ) -> DResult<()> {
+        let mut known_addresses = self.known_addresses.lock().map_err(|e| e.to_string())?;
+        known_addresses.insert((*author_id, ip.clone()), *succeeded_at);
+        Ok(())
+    }
+    async fn is_known_address(&self, author_id: &u64, ip: &String, since: &u64) -> DResult<bool> {
+        let known_addresses = self.known_addresses.lock().map_err(|e| e.to_string())?;
+        Ok(known_addresses
+            .get(&(*author_id, ip.clone()))
+            .is_some_and(|succeeded_at| succeeded_at >= since))
+    }
+    async fn delete_stale(&self, failed_before: &u64, succeeded_before: &u64) -> DResult<()> {
+        let mut storage = self.storage.lock().map_err(|e| e.to_string())?;
+        storage.retain(|_, f| f.last_failed_at >= *failed_before);
+        let mut known_addresses = self.known_addresses.lock().map_err(|e| e.to_string())?;
+        known_addresses.retain(|_, succeeded_at| *succeeded_at >= *succeeded_before);
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::request_throttle_service::{RequestThrottleService, RequestWindow};
+use screw_components::dyn_result::DResult;
+
+/// Process-local storage, for tests and single instance setups without a database.
+pub fn create_memory_request_throttle_service() -> Arc<dyn RequestThrottleService> {
+    Arc::new(MemoryRequestThrottleService {
+        storage: Mutex::new(HashMap::new()),
+    })
+}
+
+struct MemoryRequestThrottleService {
+    storage: Mutex<HashMap<String, RequestWindow>>,
+}
+
+#[async_trait]
+impl RequestThrottleService for MemoryRequestThrottleService {
+    async fn hit(&self, key: &String, now: &u64, window_start: &u64) -> DResult<RequestWindow> {
+        let mut storage = self.storage.lock().map_err(|e| e.to_string())?;
+        storage.retain(|_, w| w.started_at >= *window_start);
+        let window = storage.entry(key.clone()).or_insert(RequestWindow {
+            request_count: 0,
+            started_at: *now,
+        });
+        window.request_count += 1;
+        Ok(*window)
+    }
+}
@@ -1,10 +1,29 @@
 mod discord_new_post_published_service;
 mod entity_comment_service;
 mod entity_post_service;
+mod file_mail_service;
+mod in_app_notification_service;
+mod local_image_storage_service;
+mod memory_login_attempt_service;
+mod memory_request_throttle_service;
+mod oidc_service;
 mod rabbitmq_event_bus_service;
+mod rbatis_access_token_service;
+mod rbatis_audit_service;
 mod rbatis_author_service;
+mod rbatis_author_stats_service;
 mod rbatis_comment_service;
+mod rbatis_email_subscription_service;
+mod rbatis_follow_service;
+mod rbatis_identity_service;
+mod rbatis_login_attempt_service;
+mod rbatis_notification_preference_service;
+mod rbatis_notification_service;
 mod rbatis_post_service;
+mod rbatis_request_throttle_service;
+mod rbatis_session_service;
+mod rbatis_two_factor_service;
+mod smtp_mail_service;
 mod social_service;
 mod telegram_new_post_published_service;
 mod telegram_user_updates_service;
@@ -12,10 +31,29 @@ mod telegram_user_updates_service;
 pub use discord_new_post_published_service::create_discord_new_post_published_service;
 pub use entity_comment_service::create_entity_comment_service;
 pub use entity_post_service::create_entity_post_service;
+pub use file_mail_service::create_file_mail_service;
+pub use in_app_notification_service::create_in_app_notification_service;
+pub use local_image_storage_service::create_local_image_storage_service;
+pub use memory_login_attempt_service::create_memory_login_attempt_service;
+pub use memory_request_throttle_service::create_memory_request_throttle_service;
+pub use oidc_service::create_oidc_service;
 pub use rabbitmq_event_bus_service::create_rabbit_event_bus_service;
+pub use rbatis_access_token_service::create_rbatis_access_token_service;
+pub use rbatis_audit_service::create_rbatis_audit_service;
 pub use rbatis_author_service::create_rbatis_author_service;
+pub use rbatis_author_stats_service::create_rbatis_author_stats_service;
 pub use rbatis_comment_service::create_rbatis_comment_service;
+pub use rbatis_email_subscription_service::create_rbatis_email_subscription_service;
+pub use rbatis_follow_service::create_rbatis_follow_service;
+pub use rbatis_identity_service::create_rbatis_identity_service;
+pub use rbatis_login_attempt_service::create_rbatis_login_attempt_service;
+pub use rbatis_notification_preference_service::create_rbatis_notification_preference_service;
+pub use rbatis_notification_service::create_rbatis_notification_service;
 pub use rbatis_post_service::create_rbatis_post_service;
+pub use rbatis_request_throttle_service::create_rbatis_request_throttle_service;
+pub use rbatis_session_service::create_rbatis_session_service;
+pub use rbatis_two_factor_service::create_rbatis_two_factor_service;
+pub use smtp_mail_service::create_smtp_mail_service;
 pub use social_service::create_social_service;
 pub use telegram_new_post_published_service::create_telegram_new_post_published_service;
 pub use telegram_user_updates_service::create_telegram_user_updates_service;
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::traits::oidc_service::{OidcProviderConfig, OidcService, OidcUserInfo};
+use crate::utils::{id_token, time_utils};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::JwkSet;
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+/// Discovery documents and signing keys are refetched after this many seconds,
+/// keys also when an ID token refers to an unknown one.
+const CACHE_LIFETIME: u64 = 60 * 60;
+/// Keys are refetched for unknown ones at most this often, so tokens with made up
+/// key ids don't turn into requests to the provider.
+const KEYS_REFETCH_INTERVAL: u64 = 60;
+
+pub fn create_oidc_service(providers: HashMap<String, OidcProviderConfig>) -> Arc<dyn OidcService> {
+    Arc::new(HttpOidcService {
+        providers,
+        client: reqwest::Client::new(),
+        endpoints_cache: Mutex::new(HashMap::new()),
+        keys_cache: Mutex::new(HashMap::new()),
+    })
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Endpoints {
+    issuer: Option<String>,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: Option<String>,
+    jwks_uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    id_token: Option<String>,
+}
+
+struct HttpOidcService {
+    providers: HashMap<String, OidcProviderConfig>,
+    client: reqwest::Client,
+    endpoints_cache: Mutex<HashMap<String, (u64, Endpoints)>>,
+    keys_cache: Mutex<HashMap<String, (u64, JwkSet)>>,
+}
+
+fn claim_string(claims: &Map<String, Value>, name: &str) -> Option<String> {
+    match claims.get(name)? {
+        Value::String(value) => Some(value.clone()).filter(|v| !v.is_empty()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+impl HttpOidcService {
+    fn config(&self, name: &String) -> DResult<&OidcProviderConfig> {
+        self.providers
+            .get(name)
+            .ok_or_else(|| format!("unknown OIDC provider `{}`", name).into())
+    }
+
+    async fn endpoints(&self, config: &OidcProviderConfig) -> DResult<Endpoints> {
+        let Some(issuer) = &config.issuer else {
+            return Ok(Endpoints {
+                issuer: None,
+                authorization_endpoint: config
+                    .authorization_endpoint
+                    .clone()
+                    .ok_or("authorization_endpoint is required without issuer")?,
+                token_endpoint: config
+                    .token_endpoint
+                    .clone()
+                    .ok_or("token_endpoint is required without issuer")?,
+                userinfo_endpoint: Some(
+                    config
+                        .userinfo_endpoint
+                        .clone()
+                        .ok_or("userinfo_endpoint is required without issuer")?,
+                ),
+                jwks_uri: None,
+            });
+        };
+        let now = time_utils::now_as_secs();
+        let cached = self
+            .endpoints_cache
+            .lock()
+            .map_err(|e| e.to_string())?
+            .get(issuer)
+            .filter(|(fetched_at, _)| fetched_at + CACHE_LIFETIME > now)
+            .map(|(_, endpoints)| endpoints.clone());
+        if let Some(endpoints) = cached {
+            return Ok(endpoints);
+        }
+        let discovery_url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        let mut endpoints = self
+            .client
+            .get(discovery_url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Endpoints>()
+            .await?;
+        if endpoints.issuer.as_ref() != Some(issuer) {
+            return Err(format!("discovery document issuer differs from `{}`", issuer).into());
+        }
+        if endpoints.jwks_uri.is_none() {
+            return Err("discovery document has no jwks_uri".into());
+        }
+        if let Some(userinfo_endpoint) = &config.userinfo_endpoint {
+            endpoints.userinfo_endpoint = Some(userinfo_endpoint.clone());
+        }
+        self.endpoints_cache
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(issuer.clone(), (now, endpoints.clone()));
+        Ok(endpoints)
+    }
+
+    /// Cached keys unless they are too old, or `refetch` is asked for after
+    /// an unknown key and they weren't fetched just now.
+    async fn keys(&self, jwks_uri: &String, refetch: bool) -> DResult<JwkSet> {
+        let now = time_utils::now_as_secs();
+        {
+            let mut keys_cache = self.keys_cache.lock().map_err(|e| e.to_string())?;
+            if let Some((fetched_at, keys)) = keys_cache.get_mut(jwks_uri) {
+                let interval = if refetch {
+                    KEYS_REFETCH_INTERVAL
+                } else {
+                    CACHE_LIFETIME
+                };
+                if *fetched_at + interval > now {
+                    return Ok(keys.clone());
+                }
+                // Concurrent requests keep using these until the new ones arrive.
+                *fetched_at = now;
+            }
+        }
+        let keys = self
+            .client
+            .get(jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<JwkSet>()
+            .await?;
+        self.keys_cache
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(jwks_uri.clone(), (now, keys.clone()));
+        Ok(keys)
+    }
+
+    async fn id_token_claims(
+        &self,
+        config: &OidcProviderConfig,
+        endpoints: &Endpoints,
+        id_token: &str,
+        nonce: &String,
+    ) -> DResult<Map<String, Value>> {
+        let jwks_uri = endpoints.jwks_uri.as_ref().ok_or("no jwks_uri")?;
+        let issuer = endpoints.issuer.as_ref().ok_or("no issuer")?;
+        let validate = |keys: &JwkSet| {
+            id_token::validate(
+                id_token,
+                keys,
+                &config.algorithms,
+                issuer,
+                &config.client_id,
+                nonce,
+            )
+        };
+        match validate(&self.keys(jwks_uri, false).await?) {
+            Err(id_token::Error::UnknownKey) => Ok(validate(&self.keys(jwks_uri, true).await?)?),
+            claims => Ok(claims?),
+        }
+    }
+}
+
+#[async_trait]
+impl OidcService for HttpOidcService {
+    fn providers(&self) -> Vec<(String, String)> {
+        let mut providers: Vec<(String, String)> = self
+            .providers
+            .iter()
+            .map(|(name, config)| {
+                (
+                    name.clone(),
+                    config.title.clone().unwrap_or_else(|| name.clone()),
+                )
+            })
+            .collect();
+        providers.sort();
+        providers
+    }
+
+    fn provider(&self, name: &String) -> Option<OidcProviderConfig> {
+        self.providers.get(name).cloned()
+    }
+
+    async fn authorization_url(
+        &self,
+        name: &String,
+        redirect_uri: &String,
+        state: &String,
+        nonce: &String,
+        code_verifier: &String,
+    ) -> DResult<String> {
+        let config = self.config(name)?;
+        let endpoints = self.endpoints(config).await?;
+        let mut params = vec![
+            ("response_type", "code".to_string()),
+            ("client_id", config.client_id.clone()),
+            ("redirect_uri", redirect_uri.clone()),
+            ("scope", config.scopes.join(" ")),
+            ("state", state.clone()),
+            (
+                "code_challenge",
+                URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes())),
+            ),
+            ("code_challenge_method", "S256".to_string()),
+        ];
+        if endpoints.issuer.is_some() {
+            params.push(("nonce", nonce.clone()));
+        }
+        Ok(reqwest::Url::parse_with_params(&endpoints.authorization_endpoint, params)?.to_string())
+    }
+
+    async fn user_info(
+        &self,
+        name: &String,
+        redirect_uri: &String,
+        code: &String,
+        code_verifier: &String,
+        nonce: &String,
+    ) -> DResult<OidcUserInfo> {
+        let config = self.config(name)?;
+        let endpoints = self.endpoints(config).await?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("client_id", config.client_id.as_str()),
+            ("code_verifier", code_verifier.as_str()),
+        ];
+        if let Some(client_secret) = &config.client_secret {
+            form.push(("client_secret", client_secret.as_str()));
+        }
+        let tokens = self
+            .client
+            .post(&endpoints.token_endpoint)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&form)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TokenResponse>()
+            .await?;
+
+        let mut claims = match (&endpoints.issuer, &tokens.id_token) {
+            (Some(_), Some(id_token)) => {
+                self.id_token_claims(config, &endpoints, id_token, nonce)
+                    .await?
+            }
+            (Some(_), None) => return Err("token response has no id_token".into()),
+            (None, _) => Map::new(),
+        };
+
+        if let Some(userinfo_endpoint) = &endpoints.userinfo_endpoint {
+            let userinfo = self
+                .client
+                .get(userinfo_endpoint)
+                .bearer_auth(&tokens.access_token)
+                .header(reqwest::header::ACCEPT, "application/json")
+                .header(reqwest::header::USER_AGENT, "blog-server")
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<Map<String, Value>>()
+                .await?;
+            if endpoints.issuer.is_some()
+                && claim_string(&userinfo, "sub") != claim_string(&claims, "sub")
+            {
+                return Err("userinfo subject differs from ID token".into());
+            }
+            for (key, value) in userinfo {
+                claims.entry(key).or_insert(value);
+            }
+        }
+
+        let names = &config.claims;
+        Ok(OidcUserInfo {
+            subject: claim_string(&claims, &names.subject).ok_or("no subject claim")?,
+            username: claim_string(&claims, &names.username),
+            first_name: claim_string(&claims, &names.first_name),
+            last_name: claim_string(&claims, &names.last_name),
+            picture: claim_string(&claims, &names.picture),
+        })
+    }
+}
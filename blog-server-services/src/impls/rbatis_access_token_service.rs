@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::traits::access_token_service::{AccessToken, AccessTokenService, BaseAccessToken};
+use rbatis::rbatis::RBatis;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_access_token_service(rb: RBatis) -> Arc<dyn AccessTokenService> {
+    Arc::new(RbatisAccessTokenService { rb })
+}
+
+impl BaseAccessToken {
+    #[py_sql(
+        "
+        INSERT INTO author_access_token
+        (author_id, name, token_hash, scopes, created_at, expires_at)
+        VALUES
+        (#{access_token.author_id}, #{access_token.name}, #{access_token.token_hash}, #{access_token.scopes}, to_timestamp(#{access_token.created_at}), to_timestamp(#{access_token.expires_at}))
+        RETURNING id
+    "
+    )]
+    async fn insert(rb: &RBatis, access_token: &BaseAccessToken) -> rbatis::Result<u64> {
+        impled!()
+    }
+}
+
+impl AccessToken {
+    #[py_sql(
+        "
+        SELECT \
+            author_access_token.* \
+        FROM author_access_token \
+        WHERE author_access_token.author_id = #{author_id} \
+            AND author_access_token.revoked = 0 \
+            AND author_access_token.expires_at > now() \
+        ORDER BY author_access_token.created_at DESC \
+    "
+    )]
+    async fn select_active_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Vec<AccessToken>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_access_token \
+        SET last_used_at = now() \
+        WHERE id = #{id} \
+            AND token_hash = #{token_hash} \
+            AND revoked = 0 \
+            AND expires_at > now() \
+        RETURNING * \
+    "
+    )]
+    async fn touch(
+        rb: &RBatis,
+        id: &u64,
+        token_hash: &String,
+    ) -> rbatis::Result<Option<AccessToken>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_access_token \
+        SET revoked = 1 \
+        WHERE id = #{id} \
+            AND author_id = #{author_id} \
+            AND revoked = 0 \
+            AND expires_at > now() \
+        RETURNING * \
+    "
+    )]
+    async fn revoke(rb: &RBatis, id: &u64, author_id: &u64) -> rbatis::Result<Option<AccessToken>> {
+        impled!()
+    }
+}
+
+struct RbatisAccessTokenService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl AccessTokenService for RbatisAccessTokenService {
+    async fn create_access_token(&self, access_token: &BaseAccessToken) -> DResult<u64> {
+        Ok(BaseAccessToken::insert(&self.rb, access_token).await?)
+    }
+    async fn active_access_tokens_by_author_id(
+        &self,
+        author_id: &u64,
+    ) -> DResult<Vec<AccessToken>> {
+        Ok(AccessToken::select_active_by_author_id(&self.rb, author_id).await?)
+    }
+    async fn use_access_token(
+        &self,
+        id: &u64,
+        token_hash: &String,
+    ) -> DResult<Option<AccessToken>> {
+        Ok(AccessToken::touch(&self.rb, id, token_hash).await?)
+    }
+    async fn revoke_access_token(&self, id: &u64, author_id: &u64) -> DResult<bool> {
+        Ok(AccessToken::revoke(&self.rb, id, author_id)
+            .await?
+            .is_some())
+    }
+}
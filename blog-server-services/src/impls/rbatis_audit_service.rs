@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::traits::audit_service::{AuditQuery, AuditRecord, AuditService, BaseAuditRecord};
+use rbatis::executor::RBatisTxExecutorGuard;
+use rbatis::rbatis::RBatis;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_audit_service(rb: RBatis) -> Arc<dyn AuditService> {
+    Arc::new(RbatisAuditService { rb })
+}
+
+impl BaseAuditRecord {
+    /// Records are written by the services doing the audited actions,
+    /// in the same transaction.
+    #[py_sql(
+        "
+        INSERT INTO audit_log
+        (actor_id, action, target_type, target_id, before, after, created_at)
+        VALUES
+        (#{record.actor_id}, #{record.action}, #{record.target_type}, #{record.target_id}, #{record.before}, #{record.after}, to_timestamp(#{record.created_at}))
+        RETURNING id
+    "
+    )]
+    pub(crate) async fn insert(
+        rb: &mut RBatisTxExecutorGuard,
+        record: &BaseAuditRecord,
+    ) -> rbatis::Result<u64> {
+        impled!()
+    }
+}
+
+impl AuditRecord {
+    #[py_sql(
+        "
+        SELECT COUNT(1) \
+        FROM audit_log \
+        WHERE 1 = 1
+            if actor_id != null:
+                AND actor_id = #{actor_id}
+            if action != null:
+                AND action = #{action}
+            if target_type != null:
+                AND target_type = #{target_type}
+            if target_id != null:
+                AND target_id = #{target_id}
+    "
+    )]
+    async fn count_by_query(
+        rb: &RBatis,
+        actor_id: Option<&u64>,
+        action: Option<&str>,
+        target_type: Option<&str>,
+        target_id: Option<&u64>,
+    ) -> rbatis::Result<u64> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT \
+            audit_log.* \
+        FROM audit_log \
+        WHERE 1 = 1
+            if actor_id != null:
+                AND actor_id = #{actor_id}
+            if action != null:
+                AND action = #{action}
+            if target_type != null:
+                AND target_type = #{target_type}
+            if target_id != null:
+                AND target_id = #{target_id}
+        ORDER BY audit_log.id DESC \
+        LIMIT #{limit} \
+        OFFSET #{offset} \
+    "
+    )]
+    async fn select_by_query(
+        rb: &RBatis,
+        actor_id: Option<&u64>,
+        action: Option<&str>,
+        target_type: Option<&str>,
+        target_id: Option<&u64>,
+        offset: &u64,
+        limit: &u64,
+    ) -> rbatis::Result<Vec<AuditRecord>> {
+        impled!()
+    }
+}
+
+struct RbatisAuditService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl AuditService for RbatisAuditService {
+    async fn records_count(&self, query: &AuditQuery) -> DResult<u64> {
+        Ok(AuditRecord::count_by_query(
+            &self.rb,
+            query.actor_id.as_ref(),
+            query.action.as_ref().map(|a| a.as_str()),
+            query.target_type.as_ref().map(|t| t.as_str()),
+            query.target_id.as_ref(),
+        )
+        .await?)
+    }
+    async fn records(
+        &self,
+        query: &AuditQuery,
+        offset: &u64,
+        limit: &u64,
+    ) -> DResult<Vec<AuditRecord>> {
+        Ok(AuditRecord::select_by_query(
+            &self.rb,
+            query.actor_id.as_ref(),
+            query.action.as_ref().map(|a| a.as_str()),
+            query.target_type.as_ref().map(|t| t.as_str()),
+            query.target_id.as_ref(),
+            offset,
+            limit,
+        )
+        .await?)
+    }
+}
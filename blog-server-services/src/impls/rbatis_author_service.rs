@@ -1,12 +1,17 @@
+use rbatis::executor::RBatisTxExecutorGuard;
 use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use rbs::value;
 use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use crate::traits::audit_service::BaseAuditRecord;
 use crate::traits::author_service::{
-    Author, AuthorService, BaseMinimalAuthor, BaseSecondaryAuthor,
+    Author, AuthorRole, AuthorService, BaseMinimalAuthor, BaseSecondaryAuthor, DeletedAuthorPosts,
 };
-use crate::utils::time_utils;
+use crate::utils::{author_slug, time_utils};
 
 pub fn create_rbatis_author_service(rb: RBatis) -> Arc<dyn AuthorService> {
     Arc::new(RbatisAuthorService { rb })
@@ -18,12 +23,32 @@ impl_select!(Author {select_by_yandex_id(yandex_id: &u64) -> Option =>
     "`WHERE yandex_id = #{yandex_id} LIMIT 1`"});
 impl_select!(Author {select_by_telegram_id(telegram_id: &u64) -> Option =>
     "`WHERE telegram_id = #{telegram_id} LIMIT 1`"});
+impl_select!(Author {select_by_email(email: &String) -> Option =>
+    "`WHERE lower(email) = lower(#{email}) LIMIT 1`"});
 impl_select!(Author {select_by_slug(slug: &String) -> Option => 
     "`WHERE slug = #{slug} LIMIT 1`"});
 impl_select!(Author {select_all_with_offset_and_limit(offset: &u64, limit: &u64) => 
-    "`ORDER BY author.editor DESC, author.blocked ASC, author.registered_at ASC LIMIT #{limit} OFFSET #{offset}`"});
-impl_select!(Author {select_all_by_query_with_offset_and_limit(query: &String, offset: &u64, limit: &u64) => 
-    "`WHERE author.slug ILIKE '%' || #{query} || '%' OR author.first_name ILIKE '%' || #{query} || '%' OR author.middle_name ILIKE '%' || #{query} || '%' OR author.last_name ILIKE '%' || #{query} || '%' ORDER BY author.editor DESC, author.blocked ASC, author.registered_at ASC LIMIT #{limit} OFFSET #{offset}`"});
+    "`ORDER BY author.role IN ('editor', 'admin') DESC, author.blocked ASC, author.registered_at ASC LIMIT #{limit} OFFSET #{offset}`"});
+
+// Shared by the search count and page queries, so both always see the same rows.
+// `<%` is typo tolerant (pg_trgm word similarity), `ILIKE` keeps exact substring hits.
+const SEARCH_FROM_WHERE: &'static str = "\
+    FROM author, (SELECT ?::text AS query) search \
+    WHERE search.query <% author.slug \
+    OR search.query <% author.first_name \
+    OR search.query <% author.middle_name \
+    OR search.query <% author.last_name \
+    OR author.slug ILIKE '%' || search.query || '%' \
+    OR author.first_name ILIKE '%' || search.query || '%' \
+    OR author.middle_name ILIKE '%' || search.query || '%' \
+    OR author.last_name ILIKE '%' || search.query || '%'";
+const SEARCH_RANK: &'static str = "\
+    GREATEST(\
+        word_similarity(search.query, author.slug), \
+        word_similarity(search.query, author.first_name), \
+        word_similarity(search.query, author.middle_name), \
+        word_similarity(search.query, author.last_name)\
+    )";
 
 impl Author {
     #[py_sql(
@@ -35,16 +60,6 @@ impl Author {
     async fn count(rb: &RBatis) -> rbatis::Result<u64> {
         impled!()
     }
-    #[py_sql(
-        "
-        SELECT COUNT(1) \
-        FROM author \
-        WHERE author.slug ILIKE '%' || #{query} || '%' OR author.first_name ILIKE '%' || #{query} || '%' OR author.middle_name ILIKE '%' || #{query} || '%' OR author.last_name ILIKE '%' || #{query} || '%'
-    "
-    )]
-    async fn count_by_query(rb: &RBatis, query: &String) -> rbatis::Result<u64> {
-        impled!()
-    }
     #[py_sql(
         "
         SELECT \
@@ -68,7 +83,42 @@ impl Author {
         WHERE id = #{id}
     "
     )]
-    async fn set_blocked_by_id(rb: &RBatis, id: &u64, is_blocked: &u8) -> rbatis::Result<()> {
+    async fn set_blocked_by_id(
+        rb: &mut RBatisTxExecutorGuard,
+        id: &u64,
+        is_blocked: &u8,
+    ) -> rbatis::Result<()> {
+        impled!()
+    }
+    #[py_sql(
+        "
+        UPDATE author \
+        SET \
+            role = #{role} \
+        WHERE id = #{id}
+    "
+    )]
+    async fn set_role_by_id(
+        rb: &mut RBatisTxExecutorGuard,
+        id: &u64,
+        role: &AuthorRole,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+    #[py_sql(
+        "
+        UPDATE author \
+        SET \
+            password_hash = #{password_hash}, \
+            must_change_password = 0 \
+        WHERE id = #{id}
+    "
+    )]
+    async fn set_password_hash_by_id(
+        rb: &RBatis,
+        id: &u64,
+        password_hash: &String,
+    ) -> rbatis::Result<ExecResult> {
         impled!()
     }
     #[py_sql(
@@ -103,11 +153,135 @@ impl Author {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AuthorSocialIds {
+    yandex_id: Option<u64>,
+    telegram_id: Option<u64>,
+}
+
+impl AuthorSocialIds {
+    #[py_sql(
+        "
+        UPDATE author \
+        SET \
+            yandex_id = #{yandex_id}, \
+            telegram_id = #{telegram_id} \
+        WHERE id = #{id}
+    "
+    )]
+    async fn set_by_id(
+        rb: &RBatis,
+        id: &u64,
+        yandex_id: Option<&u64>,
+        telegram_id: Option<&u64>,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    /// Returns the ids the author had, they are unique and have to be freed
+    /// before they can be given to another author.
+    #[py_sql(
+        "
+        UPDATE author \
+        SET \
+            yandex_id = NULL, \
+            telegram_id = NULL, \
+            blocked = 1 \
+        FROM (SELECT id, yandex_id, telegram_id FROM author WHERE id = #{id} FOR UPDATE) previous \
+        WHERE author.id = previous.id \
+        RETURNING previous.yandex_id, previous.telegram_id
+    "
+    )]
+    async fn take_and_block_by_id(
+        rb: &mut RBatisTxExecutorGuard,
+        id: &u64,
+    ) -> rbatis::Result<Option<AuthorSocialIds>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author \
+        SET \
+            yandex_id = COALESCE(yandex_id, #{social_ids.yandex_id}), \
+            telegram_id = COALESCE(telegram_id, #{social_ids.telegram_id}) \
+        WHERE id = #{id}
+    "
+    )]
+    async fn fill_missing_by_id(
+        rb: &mut RBatisTxExecutorGuard,
+        id: &u64,
+        social_ids: &AuthorSocialIds,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
 struct RbatisAuthorService {
     rb: RBatis,
 }
 
+/// Candidates of a slug looked up at once, a sign-up fails when all of them are taken.
+const SLUG_CANDIDATES: usize = 100;
+/// Sign-ups racing for the same slug retry the allocation this many times.
+const SLUG_ALLOCATION_ATTEMPTS: usize = 3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SlugOwner {
+    id: u64,
+    slug: String,
+}
+
+fn is_slug_conflict(e: &rbatis::Error) -> bool {
+    e.to_string().contains("uq_author_slug")
+}
+
+/// Rows holding personal data of an author, as table and author column pairs.
+/// Deleting them frees every foreign key to the author except posts, comments and the audit log.
+const AUTHOR_PERSONAL_ROWS: [(&str, &str); 14] = [
+    ("author_session_rotated_token", "author_id"),
+    ("author_session", "author_id"),
+    ("author_access_token", "author_id"),
+    ("author_totp", "author_id"),
+    ("author_recovery_code", "author_id"),
+    ("author_identity", "author_id"),
+    ("login_attempt", "author_id"),
+    ("login_known_address", "author_id"),
+    ("author_notification_preference", "author_id"),
+    ("author_notification", "author_id"),
+    ("author_notification", "actor_id"),
+    ("author_follow", "follower_id"),
+    ("author_follow", "author_id"),
+    ("tag_follow", "follower_id"),
+];
+
 impl RbatisAuthorService {
+    /// First free candidate of the slug suggested by a login provider. The current slug
+    /// of `author_id` is kept when it's one of the candidates, so links to it don't break.
+    async fn allocate_slug(&self, slug: &str, author_id: Option<&u64>) -> DResult<String> {
+        let slug = author_slug::normalize(slug);
+        let candidates: Vec<String> = (0..SLUG_CANDIDATES)
+            .map(|attempt| author_slug::candidate(&slug, attempt))
+            .collect();
+        let owners: Vec<SlugOwner> = self
+            .rb
+            .query_decode(
+                &format!(
+                    "SELECT id, slug FROM author WHERE slug IN ({})",
+                    vec!["?"; candidates.len()].join(", ")
+                ),
+                candidates.iter().map(|c| value!(c)).collect(),
+            )
+            .await?;
+        if let Some(owner) = owners.iter().find(|o| Some(&o.id) == author_id) {
+            return Ok(owner.slug.clone());
+        }
+        Ok(candidates
+            .into_iter()
+            .find(|c| !owners.iter().any(|o| &o.slug == c))
+            .ok_or("no free slug left")?)
+    }
+
     #[py_sql(
         "
         INSERT INTO author (
@@ -141,6 +315,37 @@ impl RbatisAuthorService {
         impled!()
     }
 
+    #[py_sql(
+        "
+        INSERT INTO author (
+            slug,
+            first_name,
+            last_name,
+            email,
+            password_hash,
+            registered_at,
+            override_social_data
+        ) VALUES (
+            #{base_minimal_author.slug},
+            #{base_minimal_author.first_name},
+            #{base_minimal_author.last_name},
+            #{email},
+            #{password_hash},
+            to_timestamp(#{registered_at}),
+            0
+        ) RETURNING id
+    "
+    )]
+    async fn insert_password_author(
+        rb: &RBatis,
+        base_minimal_author: &BaseMinimalAuthor,
+        registered_at: &u64,
+        email: Option<&String>,
+        password_hash: &String,
+    ) -> rbatis::Result<u64> {
+        impled!()
+    }
+
     #[py_sql(
         "
         UPDATE author \
@@ -193,7 +398,13 @@ impl RbatisAuthorService {
         SET \
             email = #{base_secondary_author.email}, \
             mobile = #{base_secondary_author.mobile}, \
-            status = #{base_secondary_author.status} \
+            status = #{base_secondary_author.status}, \
+            bio = #{base_secondary_author.bio}, \
+            links = #{base_secondary_author.links}, \
+            cover_url = #{base_secondary_author.cover_url}, \
+            location = #{base_secondary_author.location}, \
+            email_public = #{base_secondary_author.email_public}, \
+            mobile_public = #{base_secondary_author.mobile_public} \
         WHERE id = #{author_id} \
         RETURNING id
     "
@@ -205,12 +416,205 @@ impl RbatisAuthorService {
     ) -> rbatis::Result<u64> {
         impled!()
     }
+
+    #[py_sql(
+        "
+        UPDATE post \
+        SET author_id = #{target_id} \
+        WHERE author_id = #{source_id}
+    "
+    )]
+    async fn move_posts(
+        rb: &mut RBatisTxExecutorGuard,
+        source_id: &u64,
+        target_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE post_comment \
+        SET author_id = #{target_id} \
+        WHERE author_id = #{source_id}
+    "
+    )]
+    async fn move_comments(
+        rb: &mut RBatisTxExecutorGuard,
+        source_id: &u64,
+        target_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_identity \
+        SET author_id = #{target_id} \
+        WHERE author_id = #{source_id}
+    "
+    )]
+    async fn move_identities(
+        rb: &mut RBatisTxExecutorGuard,
+        source_id: &u64,
+        target_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM ${table} \
+        WHERE ${column} = #{author_id}
+    "
+    )]
+    async fn delete_personal_rows(
+        rb: &mut RBatisTxExecutorGuard,
+        table: &str,
+        column: &str,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM email_subscription \
+        WHERE email = (SELECT lower(email) FROM author WHERE id = #{author_id})
+    "
+    )]
+    async fn delete_email_subscription(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM post_tag \
+        WHERE post_id IN (SELECT id FROM post WHERE author_id = #{author_id})
+    "
+    )]
+    async fn delete_posts_tags(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM post_comment \
+        WHERE post_id IN (SELECT id FROM post WHERE author_id = #{author_id})
+    "
+    )]
+    async fn delete_posts_comments(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM post \
+        WHERE author_id = #{author_id}
+    "
+    )]
+    async fn delete_posts(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE post_comment \
+        SET content = '' \
+        WHERE author_id = #{author_id}
+    "
+    )]
+    async fn clear_comments(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author \
+        SET slug = 'deleted-' || id, \
+        first_name = NULL, \
+        middle_name = NULL, \
+        last_name = NULL, \
+        mobile = NULL, \
+        email = NULL, \
+        password_hash = NULL, \
+        status = NULL, \
+        image_url = NULL, \
+        bio = NULL, \
+        links = NULL, \
+        cover_url = NULL, \
+        location = NULL, \
+        yandex_id = NULL, \
+        telegram_id = NULL, \
+        notification_subscribed = 0, \
+        email_public = 0, \
+        mobile_public = 0, \
+        must_change_password = 0, \
+        blocked = 1 \
+        WHERE id = #{author_id}
+    "
+    )]
+    async fn anonymize(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_session \
+        SET revoked = 1 \
+        WHERE author_id = #{author_id}
+    "
+    )]
+    async fn revoke_sessions(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_access_token \
+        SET revoked = 1 \
+        WHERE author_id = #{author_id}
+    "
+    )]
+    async fn revoke_access_tokens(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
 }
 
 #[async_trait]
 impl AuthorService for RbatisAuthorService {
     async fn authors_count_by_query(&self, query: &String) -> DResult<u64> {
-        Ok(Author::count_by_query(&self.rb, query).await?)
+        Ok(self
+            .rb
+            .query_decode(
+                &format!("SELECT COUNT(1) {SEARCH_FROM_WHERE}"),
+                vec![value!(query)],
+            )
+            .await?)
     }
     async fn authors_by_query(
         &self,
@@ -218,13 +622,16 @@ impl AuthorService for RbatisAuthorService {
         offset: &u64,
         limit: &u64,
     ) -> DResult<Vec<Author>> {
-        Ok(Author::select_all_by_query_with_offset_and_limit(
-            &mut self.rb.clone(),
-            query,
-            offset,
-            limit,
-        )
-        .await?)
+        Ok(self
+            .rb
+            .query_decode(
+                &format!(
+                    "SELECT author.*, {SEARCH_RANK} AS rank {SEARCH_FROM_WHERE} \
+                    ORDER BY rank DESC, author.id ASC LIMIT ? OFFSET ?"
+                ),
+                vec![value!(query), value!(limit), value!(offset)],
+            )
+            .await?)
     }
     async fn authors_count(&self) -> DResult<u64> {
         Ok(Author::count(&self.rb).await?)
@@ -250,6 +657,9 @@ impl AuthorService for RbatisAuthorService {
     async fn author_by_telegram_id(&self, telegram_id: &u64) -> DResult<Option<Author>> {
         Ok(Author::select_by_telegram_id(&mut self.rb.clone(), telegram_id).await?)
     }
+    async fn author_by_email(&self, email: &String) -> DResult<Option<Author>> {
+        Ok(Author::select_by_email(&mut self.rb.clone(), email).await?)
+    }
     async fn set_author_override_social_data_by_id(
         &self,
         id: &u64,
@@ -280,15 +690,27 @@ impl AuthorService for RbatisAuthorService {
         yandex_id: Option<&u64>,
         telegram_id: Option<&u64>,
     ) -> DResult<u64> {
-        let updated_id = RbatisAuthorService::update_minimal_social_author_by_id(
-            &mut self.rb.clone(),
-            &id,
-            base_minimal_author,
-            yandex_id,
-            telegram_id,
-        )
-        .await?;
-        Ok(updated_id)
+        for _ in 0..SLUG_ALLOCATION_ATTEMPTS {
+            let base_minimal_author = BaseMinimalAuthor {
+                slug: self
+                    .allocate_slug(&base_minimal_author.slug, Some(id))
+                    .await?,
+                ..base_minimal_author.clone()
+            };
+            match RbatisAuthorService::update_minimal_social_author_by_id(
+                &mut self.rb.clone(),
+                &id,
+                &base_minimal_author,
+                yandex_id,
+                telegram_id,
+            )
+            .await
+            {
+                Err(e) if is_slug_conflict(&e) => continue,
+                updated_id => return Ok(updated_id?),
+            }
+        }
+        Err("slug allocation failed".into())
     }
     async fn insert_minimal_social_author(
         &self,
@@ -296,12 +718,38 @@ impl AuthorService for RbatisAuthorService {
         yandex_id: Option<&u64>,
         telegram_id: Option<&u64>,
     ) -> DResult<u64> {
-        let inserted_id = RbatisAuthorService::insert_minimal_social_author(
+        for _ in 0..SLUG_ALLOCATION_ATTEMPTS {
+            let base_minimal_author = BaseMinimalAuthor {
+                slug: self.allocate_slug(&base_minimal_author.slug, None).await?,
+                ..base_minimal_author.clone()
+            };
+            match RbatisAuthorService::insert_minimal_social_author(
+                &mut self.rb.clone(),
+                &base_minimal_author,
+                &time_utils::now_as_secs(),
+                yandex_id,
+                telegram_id,
+            )
+            .await
+            {
+                Err(e) if is_slug_conflict(&e) => continue,
+                inserted_id => return Ok(inserted_id?),
+            }
+        }
+        Err("slug allocation failed".into())
+    }
+    async fn insert_password_author(
+        &self,
+        base_minimal_author: &BaseMinimalAuthor,
+        email: Option<&String>,
+        password_hash: &String,
+    ) -> DResult<u64> {
+        let inserted_id = RbatisAuthorService::insert_password_author(
             &mut self.rb.clone(),
             base_minimal_author,
             &time_utils::now_as_secs(),
-            yandex_id,
-            telegram_id,
+            email,
+            password_hash,
         )
         .await?;
         Ok(inserted_id)
@@ -319,8 +767,46 @@ impl AuthorService for RbatisAuthorService {
         .await?;
         Ok(updated_id)
     }
-    async fn set_author_blocked_by_id(&self, id: &u64, is_blocked: &u8) -> DResult<()> {
-        let _ = Author::set_blocked_by_id(&mut self.rb.clone(), &id, &is_blocked).await;
+    async fn set_author_password_hash_by_id(
+        &self,
+        id: &u64,
+        password_hash: &String,
+    ) -> DResult<()> {
+        Author::set_password_hash_by_id(&self.rb, id, password_hash).await?;
+        Ok(())
+    }
+    async fn set_author_blocked_by_id(
+        &self,
+        id: &u64,
+        is_blocked: &u8,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        Author::set_blocked_by_id(&mut tx, id, is_blocked).await?;
+        BaseAuditRecord::insert(&mut tx, audit_record).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    async fn set_author_role_by_id(
+        &self,
+        id: &u64,
+        role: &AuthorRole,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        Author::set_role_by_id(&mut tx, id, role).await?;
+        BaseAuditRecord::insert(&mut tx, audit_record).await?;
+        tx.commit().await?;
         Ok(())
     }
     async fn set_author_subscription_by_id(&self, id: &u64, is_subscribed: &u8) -> DResult<()> {
@@ -329,4 +815,146 @@ impl AuthorService for RbatisAuthorService {
                 .await;
         Ok(())
     }
+    async fn set_author_social_ids_by_id(
+        &self,
+        id: &u64,
+        yandex_id: Option<&u64>,
+        telegram_id: Option<&u64>,
+    ) -> DResult<()> {
+        AuthorSocialIds::set_by_id(&self.rb, id, yandex_id, telegram_id).await?;
+        Ok(())
+    }
+    async fn merge_authors(
+        &self,
+        source_id: &u64,
+        target_id: &u64,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        RbatisAuthorService::move_posts(&mut tx, source_id, target_id).await?;
+        RbatisAuthorService::move_comments(&mut tx, source_id, target_id).await?;
+        RbatisAuthorService::move_identities(&mut tx, source_id, target_id).await?;
+        RbatisAuthorService::revoke_sessions(&mut tx, source_id).await?;
+        RbatisAuthorService::revoke_access_tokens(&mut tx, source_id).await?;
+        let social_ids = AuthorSocialIds::take_and_block_by_id(&mut tx, source_id)
+            .await?
+            .ok_or("source author not found")?;
+        AuthorSocialIds::fill_missing_by_id(&mut tx, target_id, &social_ids).await?;
+        BaseAuditRecord::insert(&mut tx, audit_record).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    async fn delete_author(&self, id: &u64, posts: &DeletedAuthorPosts) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        RbatisAuthorService::delete_email_subscription(&mut tx, id).await?;
+        match posts {
+            DeletedAuthorPosts::Delete => {
+                RbatisAuthorService::delete_posts_tags(&mut tx, id).await?;
+                RbatisAuthorService::delete_posts_comments(&mut tx, id).await?;
+                RbatisAuthorService::delete_posts(&mut tx, id).await?;
+            }
+            DeletedAuthorPosts::Reassign(target_id) => {
+                RbatisAuthorService::move_posts(&mut tx, id, target_id).await?;
+            }
+        }
+        RbatisAuthorService::clear_comments(&mut tx, id).await?;
+        for (table, column) in AUTHOR_PERSONAL_ROWS {
+            RbatisAuthorService::delete_personal_rows(&mut tx, table, column, id).await?;
+        }
+        RbatisAuthorService::anonymize(&mut tx, id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Foreign keys `delete_author` handles on its own: posts are deleted or reassigned,
+    /// comments are cleared and the audit log keeps the anonymized actor.
+    const AUTHOR_KEPT_ROWS: [(&str, &str); 3] = [
+        ("post", "author_id"),
+        ("post_comment", "author_id"),
+        ("audit_log", "actor_id"),
+    ];
+
+    /// Table and column pairs referencing `author (id)` in the migration.
+    fn author_foreign_keys() -> Vec<(String, String)> {
+        let sql = include_str!("../../../blog-server-api/src/migrations/table_pg.sql");
+        let mut table = "";
+        let mut column = "";
+        let mut foreign_keys = vec![];
+        for line in sql.lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("CREATE TABLE ") {
+                table = name.trim_end_matches('(').trim();
+            } else if let Some(name) = line.strip_prefix("FOREIGN KEY (") {
+                column = name.trim_end_matches(')');
+            } else if line.starts_with("REFERENCES author (id)") {
+                foreign_keys.push((table.to_string(), column.to_string()));
+            }
+        }
+        foreign_keys
+    }
+
+    #[test]
+    fn deletion_clears_every_author_foreign_key() {
+        let foreign_keys = author_foreign_keys();
+        assert!(foreign_keys.len() > AUTHOR_KEPT_ROWS.len());
+        for (table, column) in &foreign_keys {
+            let pair = (table.as_str(), column.as_str());
+            assert!(
+                AUTHOR_PERSONAL_ROWS.contains(&pair) || AUTHOR_KEPT_ROWS.contains(&pair),
+                "{table}.{column} isn't cleared when an author is deleted"
+            );
+        }
+        for (table, column) in AUTHOR_PERSONAL_ROWS.iter().chain(AUTHOR_KEPT_ROWS.iter()) {
+            assert!(
+                foreign_keys.contains(&(table.to_string(), column.to_string())),
+                "{table}.{column} doesn't reference an author"
+            );
+        }
+    }
+
+    #[test]
+    fn rotated_tokens_are_deleted_before_their_sessions() {
+        let position = |table: &str| {
+            AUTHOR_PERSONAL_ROWS
+                .iter()
+                .position(|(t, _)| *t == table)
+                .unwrap()
+        };
+        assert!(position("author_session_rotated_token") < position("author_session"));
+    }
+
+    /// SQL of the `py_sql` query right above `async fn {name}` in this file.
+    fn py_sql_of(name: &str) -> String {
+        let source = include_str!("rbatis_author_service.rs");
+        let end = source
+            .find(&format!("async fn {name}("))
+            .unwrap_or_else(|| panic!("{name} not found"));
+        let start = source[..end].rfind("#[py_sql(").unwrap();
+        source[start..end]
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn new_password_clears_the_change_requirement() {
+        let sql = py_sql_of("set_password_hash_by_id");
+        assert!(sql.contains("password_hash = #{password_hash}"));
+        assert!(sql.contains("must_change_password = 0"));
+        assert!(sql.contains("WHERE id = #{id}"));
+    }
 }
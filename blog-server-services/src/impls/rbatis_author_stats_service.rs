@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::traits::author_stats_service::{AuthorStats, AuthorStatsService, MonthlyCount};
+use crate::utils::author_stats;
+use blog_generic::entities::PublishType;
+use rbatis::rbatis::RBatis;
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+pub fn create_rbatis_author_stats_service(rb: RBatis) -> Arc<dyn AuthorStatsService> {
+    Arc::new(RbatisAuthorStatsService { rb })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct PublishTypeCount {
+    publish_type: PublishType,
+    count: u64,
+}
+
+impl PublishTypeCount {
+    #[py_sql(
+        "
+        SELECT post.publish_type, COUNT(1) AS count \
+        FROM post \
+        WHERE post.author_id = #{author_id} \
+        GROUP BY post.publish_type \
+    "
+    )]
+    async fn select_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Vec<PublishTypeCount>> {
+        impled!()
+    }
+}
+
+impl MonthlyCount {
+    /// Only months with posts, `author_stats::monthly_histogram` fills in the rest.
+    #[py_sql(
+        "
+        SELECT to_char(date_trunc('month', post.created_at), 'YYYY-MM') AS month, COUNT(1) AS count \
+        FROM post \
+        WHERE post.author_id = #{author_id} \
+            AND post.publish_type = 1 \
+            AND post.created_at >= date_trunc('month', now()) - make_interval(months => #{months}::int - 1) \
+        GROUP BY date_trunc('month', post.created_at) \
+    "
+    )]
+    async fn select_published_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+        months: &u32,
+    ) -> rbatis::Result<Vec<MonthlyCount>> {
+        impled!()
+    }
+
+    /// Of the database clock, the same one posts are dated with.
+    #[py_sql(
+        "
+        SELECT to_char(now(), 'YYYY-MM') \
+    "
+    )]
+    async fn current_month(rb: &RBatis) -> rbatis::Result<String> {
+        impled!()
+    }
+}
+
+struct RbatisAuthorStatsService {
+    rb: RBatis,
+}
+
+impl RbatisAuthorStatsService {
+    #[py_sql(
+        "
+        SELECT COUNT(1) \
+        FROM post_comment \
+        INNER JOIN post ON post.id = post_comment.post_id \
+        WHERE post.author_id = #{author_id} \
+            AND post_comment.author_id <> #{author_id} \
+            AND post_comment.published = 1 \
+    "
+    )]
+    async fn comments_received_count(rb: &RBatis, author_id: &u64) -> rbatis::Result<u64> {
+        impled!()
+    }
+}
+
+#[async_trait]
+impl AuthorStatsService for RbatisAuthorStatsService {
+    async fn author_stats(&self, author_id: &u64) -> DResult<AuthorStats> {
+        let posts = author_stats::posts_counts(
+            PublishTypeCount::select_by_author_id(&self.rb, author_id)
+                .await?
+                .into_iter()
+                .map(|row| (row.publish_type, row.count)),
+        );
+        let published_by_month = author_stats::monthly_histogram(
+            &MonthlyCount::current_month(&self.rb).await?,
+            MonthlyCount::select_published_by_author_id(
+                &self.rb,
+                author_id,
+                &author_stats::HISTOGRAM_MONTHS,
+            )
+            .await?,
+            author_stats::HISTOGRAM_MONTHS,
+        )
+        .ok_or("unexpected current month format")?;
+        Ok(AuthorStats {
+            posts,
+            comments_received: RbatisAuthorStatsService::comments_received_count(
+                &self.rb, author_id,
+            )
+            .await?,
+            published_by_month,
+        })
+    }
+}
@@ -1,8 +1,12 @@
 use std::sync::Arc;
 
-use crate::traits::comment_service::{BaseComment, Comment, CommentService};
+use crate::traits::audit_service::BaseAuditRecord;
+use crate::traits::comment_service::{BaseComment, Comment, CommentService, CommentsCursorAnswer};
+use crate::utils::cursor::Cursor;
+use rbatis::executor::RBatisTxExecutorGuard;
 use rbatis::rbatis::RBatis;
 use screw_components::dyn_result::DResult;
+use serde::Deserialize;
 
 pub fn create_rbatis_comment_service(rb: RBatis) -> Arc<dyn CommentService> {
     Arc::new(RbatisCommentService { rb })
@@ -12,6 +16,8 @@ impl_select!(Comment {select_all_by_post_id_with_limit_and_offset(post_id: &u64,
     "`WHERE post_id = #{post_id} ORDER BY created_at ASC LIMIT #{limit} OFFSET #{offset}`"}, "post_comment");
 impl_select!(Comment {select_by_id(id: &u64) -> Option => 
     "`WHERE id = #{id} LIMIT 1`"}, "post_comment");
+impl_select!(Comment {select_all_by_author_id(author_id: &u64) =>
+    "`WHERE author_id = #{author_id} ORDER BY created_at ASC, id ASC`"}, "post_comment");
 impl_delete!(Comment {delete_all_by_post_id(post_id: &u64) =>
     "`WHERE post_id = #{post_id}`"} , "post_comment");
 
@@ -41,6 +47,28 @@ impl Comment {
     async fn count_by_post_id(rb: &RBatis, post_id: &u64) -> rbatis::Result<u64> {
         impled!()
     }
+    #[py_sql(
+        "
+        SELECT \
+            post_comment.* \
+        FROM post_comment \
+        WHERE post_comment.post_id = #{post_id}
+            if cursor_id != null:
+                AND (post_comment.created_at, post_comment.id) > (
+                    SELECT c.created_at, c.id FROM post_comment c WHERE c.id = #{cursor_id}
+                )
+        ORDER BY post_comment.created_at ASC, post_comment.id ASC \
+        LIMIT #{limit} \
+    "
+    )]
+    async fn select_all_by_post_id_after_cursor(
+        rb: &RBatis,
+        post_id: &u64,
+        cursor_id: Option<&u64>,
+        limit: &u64,
+    ) -> rbatis::Result<Vec<Comment>> {
+        impled!()
+    }
     #[py_sql(
         "
         UPDATE post_comment \
@@ -49,11 +77,37 @@ impl Comment {
         WHERE id = #{id}
     "
     )]
-    async fn mark_deleted_by_id(rb: &RBatis, id: &u64) -> rbatis::Result<()> {
+    async fn mark_deleted_by_id(rb: &mut RBatisTxExecutorGuard, id: &u64) -> rbatis::Result<()> {
+        impled!()
+    }
+    #[py_sql(
+        "
+        SELECT post_comment.author_id \
+        FROM post_comment \
+        WHERE \
+            post_comment.post_id = #{post_id} \
+            AND post_comment.id < #{comment_id} \
+            AND post_comment.published = 1 \
+        GROUP BY post_comment.author_id \
+        ORDER BY MAX(post_comment.id) DESC \
+        LIMIT #{limit} \
+    "
+    )]
+    async fn select_author_ids_by_post_id_before(
+        rb: &RBatis,
+        post_id: &u64,
+        comment_id: &u64,
+        limit: &u64,
+    ) -> rbatis::Result<Vec<CommentAuthorId>> {
         impled!()
     }
 }
 
+#[derive(Clone, Debug, Deserialize)]
+struct CommentAuthorId {
+    author_id: u64,
+}
+
 struct RbatisCommentService {
     rb: RBatis,
 }
@@ -77,6 +131,48 @@ impl CommentService for RbatisCommentService {
         )
         .await?)
     }
+    async fn comments_by_post_id_after_cursor(
+        &self,
+        post_id: &u64,
+        cursor: Option<&Cursor>,
+        limit: &u64,
+    ) -> DResult<CommentsCursorAnswer> {
+        let comments = Comment::select_all_by_post_id_after_cursor(
+            &self.rb,
+            post_id,
+            cursor.map(|c| &c.id),
+            limit,
+        )
+        .await?;
+        let next_cursor = comments
+            .last()
+            .filter(|_| comments.len() as u64 >= *limit)
+            .map(|c| Cursor {
+                key: None,
+                id: c.id,
+            });
+        Ok(CommentsCursorAnswer {
+            comments,
+            next_cursor,
+        })
+    }
+    async fn comments_by_author_id(&self, author_id: &u64) -> DResult<Vec<Comment>> {
+        Ok(Comment::select_all_by_author_id(&mut self.rb.clone(), author_id).await?)
+    }
+    async fn commenter_ids_before(
+        &self,
+        post_id: &u64,
+        comment_id: &u64,
+        limit: &u64,
+    ) -> DResult<Vec<u64>> {
+        Ok(
+            Comment::select_author_ids_by_post_id_before(&self.rb, post_id, comment_id, limit)
+                .await?
+                .into_iter()
+                .map(|c| c.author_id)
+                .collect(),
+        )
+    }
     async fn create_comment(&self, comment: &BaseComment) -> DResult<u64> {
         let inserted_id = BaseComment::insert(&mut self.rb.clone(), comment).await?;
         Ok(inserted_id)
@@ -85,8 +181,22 @@ impl CommentService for RbatisCommentService {
         let comment = Comment::select_by_id(&mut self.rb.clone(), &id).await?;
         Ok(comment)
     }
-    async fn mark_deleted_by_id(&self, id: &u64) -> DResult<()> {
-        Comment::mark_deleted_by_id(&mut self.rb.clone(), &id).await?;
+    async fn mark_deleted_by_id(
+        &self,
+        id: &u64,
+        audit_record: Option<&BaseAuditRecord>,
+    ) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        Comment::mark_deleted_by_id(&mut tx, id).await?;
+        if let Some(audit_record) = audit_record {
+            BaseAuditRecord::insert(&mut tx, audit_record).await?;
+        }
+        tx.commit().await?;
         Ok(())
     }
     async fn delete_by_post_id(&self, post_id: &u64) -> DResult<()> {
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use crate::traits::email_subscription_service::{EmailSubscription, EmailSubscriptionService};
+use crate::traits::notification_preference_service::DigestFrequency;
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_email_subscription_service(rb: RBatis) -> Arc<dyn EmailSubscriptionService> {
+    Arc::new(RbatisEmailSubscriptionService { rb })
+}
+
+impl EmailSubscription {
+    #[py_sql(
+        "
+        INSERT INTO email_subscription \
+        (email, frequency, confirmed, created_at) \
+        VALUES \
+        (#{email}, #{frequency}, 0, now()) \
+        ON CONFLICT (email) DO UPDATE SET \
+            frequency = EXCLUDED.frequency \
+        WHERE email_subscription.confirmed = 0 \
+    "
+    )]
+    async fn upsert(
+        rb: &RBatis,
+        email: &String,
+        frequency: &DigestFrequency,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT email_subscription.* \
+        FROM email_subscription \
+        WHERE email_subscription.email = #{email} \
+    "
+    )]
+    async fn select_by_email(
+        rb: &RBatis,
+        email: &String,
+    ) -> rbatis::Result<Option<EmailSubscription>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT email_subscription.* \
+        FROM email_subscription \
+        WHERE email_subscription.id = #{id} \
+    "
+    )]
+    async fn select_by_id(rb: &RBatis, id: &u64) -> rbatis::Result<Option<EmailSubscription>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE email_subscription \
+        SET confirmed = 1, \
+            confirmed_at = now(), \
+            last_sent_at = now() \
+        WHERE id = #{id} \
+            AND confirmed = 0 \
+    "
+    )]
+    async fn confirm_by_id(rb: &RBatis, id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM email_subscription \
+        WHERE id = #{id} \
+    "
+    )]
+    async fn delete_by_id(rb: &RBatis, id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    /// Moves `last_sent_at` of the due subscriptions to `sent_at` and returns them with
+    /// the previous value. Rows another instance is claiming are skipped, and once it's
+    /// done they aren't due anymore.
+    #[py_sql(
+        "
+        UPDATE email_subscription \
+        SET last_sent_at = to_timestamp(#{sent_at}) \
+        FROM ( \
+            SELECT id, last_sent_at \
+            FROM email_subscription \
+            WHERE email_subscription.confirmed = 1 \
+                AND email_subscription.frequency = #{frequency} \
+                AND email_subscription.last_sent_at < to_timestamp(#{sent_before}) \
+            ORDER BY email_subscription.id ASC \
+            FOR UPDATE SKIP LOCKED \
+        ) AS due \
+        WHERE email_subscription.id = due.id \
+        RETURNING \
+            email_subscription.id, \
+            email_subscription.email, \
+            email_subscription.frequency, \
+            email_subscription.confirmed, \
+            email_subscription.created_at, \
+            email_subscription.confirmed_at, \
+            due.last_sent_at \
+    "
+    )]
+    async fn claim_due(
+        rb: &RBatis,
+        frequency: &DigestFrequency,
+        sent_before: &u64,
+        sent_at: &u64,
+    ) -> rbatis::Result<Vec<EmailSubscription>> {
+        impled!()
+    }
+}
+
+struct RbatisEmailSubscriptionService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl EmailSubscriptionService for RbatisEmailSubscriptionService {
+    async fn create_email_subscription(
+        &self,
+        email: &String,
+        frequency: &DigestFrequency,
+    ) -> DResult<EmailSubscription> {
+        EmailSubscription::upsert(&self.rb, email, frequency).await?;
+        Ok(EmailSubscription::select_by_email(&self.rb, email)
+            .await?
+            .ok_or("email subscription not stored")?)
+    }
+    async fn email_subscription_by_id(&self, id: &u64) -> DResult<Option<EmailSubscription>> {
+        Ok(EmailSubscription::select_by_id(&self.rb, id).await?)
+    }
+    async fn email_subscription_by_email(
+        &self,
+        email: &String,
+    ) -> DResult<Option<EmailSubscription>> {
+        Ok(EmailSubscription::select_by_email(&self.rb, email).await?)
+    }
+    async fn confirm_email_subscription_by_id(&self, id: &u64) -> DResult<()> {
+        EmailSubscription::confirm_by_id(&self.rb, id).await?;
+        Ok(())
+    }
+    async fn delete_email_subscription_by_id(&self, id: &u64) -> DResult<()> {
+        EmailSubscription::delete_by_id(&self.rb, id).await?;
+        Ok(())
+    }
+    async fn claim_due_email_subscriptions(
+        &self,
+        frequency: &DigestFrequency,
+        sent_before: &u64,
+        sent_at: &u64,
+    ) -> DResult<Vec<EmailSubscription>> {
+        Ok(EmailSubscription::claim_due(&self.rb, frequency, sent_before, sent_at).await?)
+    }
+}
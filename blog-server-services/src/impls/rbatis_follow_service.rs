@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use crate::traits::follow_service::{FollowCounts, FollowService};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+pub fn create_rbatis_follow_service(rb: RBatis) -> Arc<dyn FollowService> {
+    Arc::new(RbatisFollowService { rb })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct AuthorFollow {
+    follower_id: u64,
+    author_id: u64,
+}
+
+impl AuthorFollow {
+    #[py_sql(
+        "
+        INSERT INTO author_follow (follower_id, author_id, created_at) \
+        VALUES (#{follower_id}, #{author_id}, now()) \
+        ON CONFLICT DO NOTHING \
+    "
+    )]
+    async fn insert(rb: &RBatis, follower_id: &u64, author_id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM author_follow \
+        WHERE follower_id = #{follower_id} \
+            AND author_id = #{author_id} \
+    "
+    )]
+    async fn delete(rb: &RBatis, follower_id: &u64, author_id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT author_follow.follower_id, author_follow.author_id \
+        FROM author_follow \
+        WHERE author_follow.follower_id = #{follower_id} \
+        ORDER BY author_follow.created_at DESC \
+    "
+    )]
+    async fn select_by_follower_id(
+        rb: &RBatis,
+        follower_id: &u64,
+    ) -> rbatis::Result<Vec<AuthorFollow>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT author_follow.follower_id, author_follow.author_id \
+        FROM author_follow \
+        WHERE author_follow.author_id = #{author_id} \
+    "
+    )]
+    async fn select_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Vec<AuthorFollow>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT \
+            (SELECT COUNT(*) FROM author_follow WHERE author_id = #{author_id}) AS followers, \
+            (SELECT COUNT(*) FROM author_follow WHERE follower_id = #{author_id}) AS following \
+    "
+    )]
+    async fn counts(rb: &RBatis, author_id: &u64) -> rbatis::Result<FollowCounts> {
+        impled!()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct TagFollow {
+    follower_id: u64,
+    tag_id: u64,
+}
+
+impl TagFollow {
+    #[py_sql(
+        "
+        INSERT INTO tag_follow (follower_id, tag_id, created_at) \
+        VALUES (#{follower_id}, #{tag_id}, now()) \
+        ON CONFLICT DO NOTHING \
+    "
+    )]
+    async fn insert(rb: &RBatis, follower_id: &u64, tag_id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM tag_follow \
+        WHERE follower_id = #{follower_id} \
+            AND tag_id = #{tag_id} \
+    "
+    )]
+    async fn delete(rb: &RBatis, follower_id: &u64, tag_id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT tag_follow.follower_id, tag_follow.tag_id \
+        FROM tag_follow \
+        WHERE tag_follow.follower_id = #{follower_id} \
+        ORDER BY tag_follow.created_at DESC \
+    "
+    )]
+    async fn select_by_follower_id(
+        rb: &RBatis,
+        follower_id: &u64,
+    ) -> rbatis::Result<Vec<TagFollow>> {
+        impled!()
+    }
+}
+
+struct RbatisFollowService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl FollowService for RbatisFollowService {
+    async fn follow_author(&self, follower_id: &u64, author_id: &u64) -> DResult<()> {
+        AuthorFollow::insert(&self.rb, follower_id, author_id).await?;
+        Ok(())
+    }
+    async fn unfollow_author(&self, follower_id: &u64, author_id: &u64) -> DResult<()> {
+        AuthorFollow::delete(&self.rb, follower_id, author_id).await?;
+        Ok(())
+    }
+    async fn follow_tag(&self, follower_id: &u64, tag_id: &u64) -> DResult<()> {
+        TagFollow::insert(&self.rb, follower_id, tag_id).await?;
+        Ok(())
+    }
+    async fn unfollow_tag(&self, follower_id: &u64, tag_id: &u64) -> DResult<()> {
+        TagFollow::delete(&self.rb, follower_id, tag_id).await?;
+        Ok(())
+    }
+    async fn followed_author_ids(&self, follower_id: &u64) -> DResult<Vec<u64>> {
+        Ok(AuthorFollow::select_by_follower_id(&self.rb, follower_id)
+            .await?
+            .into_iter()
+            .map(|f| f.author_id)
+            .collect())
+    }
+    async fn followed_tag_ids(&self, follower_id: &u64) -> DResult<Vec<u64>> {
+        Ok(TagFollow::select_by_follower_id(&self.rb, follower_id)
+            .await?
+            .into_iter()
+            .map(|f| f.tag_id)
+            .collect())
+    }
+    async fn follower_ids(&self, author_id: &u64) -> DResult<Vec<u64>> {
+        Ok(AuthorFollow::select_by_author_id(&self.rb, author_id)
+            .await?
+            .into_iter()
+            .map(|f| f.follower_id)
+            .collect())
+    }
+    async fn follow_counts(&self, author_id: &u64) -> DResult<FollowCounts> {
+        Ok(AuthorFollow::counts(&self.rb, author_id).await?)
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::traits::identity_service::{AuthorIdentity, BaseAuthorIdentity, IdentityService};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_identity_service(rb: RBatis) -> Arc<dyn IdentityService> {
+    Arc::new(RbatisIdentityService { rb })
+}
+
+impl BaseAuthorIdentity {
+    #[py_sql(
+        "
+        INSERT INTO author_identity
+        (author_id, provider, subject, created_at)
+        VALUES
+        (#{identity.author_id}, #{identity.provider}, #{identity.subject}, to_timestamp(#{identity.created_at}))
+        ON CONFLICT (provider, subject) DO NOTHING
+    "
+    )]
+    async fn insert_if_missing(
+        rb: &RBatis,
+        identity: &BaseAuthorIdentity,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+impl AuthorIdentity {
+    #[py_sql(
+        "
+        SELECT \
+            author_identity.* \
+        FROM author_identity \
+        WHERE author_identity.provider = #{provider} \
+            AND author_identity.subject = #{subject} \
+        LIMIT 1 \
+    "
+    )]
+    async fn select_by_provider_and_subject(
+        rb: &RBatis,
+        provider: &String,
+        subject: &String,
+    ) -> rbatis::Result<Option<AuthorIdentity>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT \
+            author_identity.* \
+        FROM author_identity \
+        WHERE author_identity.author_id = #{author_id} \
+        ORDER BY author_identity.id ASC \
+    "
+    )]
+    async fn select_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Vec<AuthorIdentity>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM author_identity \
+        WHERE id = #{id} \
+    "
+    )]
+    async fn delete_by_id(rb: &RBatis, id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+struct RbatisIdentityService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl IdentityService for RbatisIdentityService {
+    async fn identity_by_provider_and_subject(
+        &self,
+        provider: &String,
+        subject: &String,
+    ) -> DResult<Option<AuthorIdentity>> {
+        Ok(AuthorIdentity::select_by_provider_and_subject(&self.rb, provider, subject).await?)
+    }
+    async fn identities_by_author_id(&self, author_id: &u64) -> DResult<Vec<AuthorIdentity>> {
+        Ok(AuthorIdentity::select_by_author_id(&self.rb, author_id).await?)
+    }
+    async fn create_identity(&self, identity: &BaseAuthorIdentity) -> DResult<()> {
+        BaseAuthorIdentity::insert_if_missing(&self.rb, identity).await?;
+        Ok(())
+    }
+    async fn delete_identity(&self, id: &u64) -> DResult<()> {
+        AuthorIdentity::delete_by_id(&self.rb, id).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use crate::traits::login_attempt_service::{LoginAttemptService, LoginFailures};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_login_attempt_service(rb: RBatis) -> Arc<dyn LoginAttemptService> {
+    Arc::new(RbatisLoginAttemptService { rb })
+}
+
+impl LoginFailures {
+    #[py_sql(
+        "
+        SELECT \
+            login_attempt.* \
+        FROM login_attempt \
+        WHERE login_attempt.author_id = #{author_id} \
+            AND login_attempt.ip = #{ip} \
+            AND login_attempt.last_failed_at >= to_timestamp(#{since}) \
+        LIMIT 1 \
+    "
+    )]
+    async fn select_by_author_id_and_ip(
+        rb: &RBatis,
+        author_id: &u64,
+        ip: &String,
+        since: &u64,
+    ) -> rbatis::Result<Option<LoginFailures>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        INSERT INTO login_attempt \
+        (author_id, ip, failed_count, last_failed_at) \
+        VALUES \
+        (#{author_id}, #{ip}, #{failed_count}, to_timestamp(#{failed_at})) \
+        ON CONFLICT (author_id, ip) DO UPDATE \
+        SET \
+            failed_count = EXCLUDED.failed_count, \
+            last_failed_at = EXCLUDED.last_failed_at \
+        WHERE ( \
+                login_attempt.failed_count = #{current.failed_count} \
+                AND login_attempt.last_failed_at = to_timestamp(#{current.last_failed_at}) \
+            ) \
+            OR login_attempt.last_failed_at < to_timestamp(#{reset_before}) \
+    "
+    )]
+    async fn upsert_failure(
+        rb: &RBatis,
+        author_id: &u64,
+        ip: &String,
+        current: &LoginFailures,
+        failed_count: &u64,
+        failed_at: &u64,
+        reset_before: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM login_attempt \
+        WHERE last_failed_at < to_timestamp(#{before}) \
+    "
+    )]
+    async fn delete_stale(rb: &RBatis, before: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        INSERT INTO login_known_address \
+        (author_id, ip, last_succeeded_at) \
+        VALUES \
+        (#{author_id}, #{ip}, to_timestamp(#{succeeded_at})) \
+        ON CONFLICT (author_id, ip) DO UPDATE \
+        SET last_succeeded_at = EXCLUDED.last_succeeded_at \
+    "
+    )]
+    async fn upsert_known_address(
+        rb: &RBatis,
+        author_id: &u64,
+        ip: &String,
+        succeeded_at: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT \
+            COUNT(1) \
+        FROM login_known_address \
+        WHERE author_id = #{author_id} \
+            AND ip = #{ip} \
+            AND last_succeeded_at >= to_timestamp(#{since}) \
+    "
+    )]
+    async fn count_known_address(
+        rb: &RBatis,
+        author_id: &u64,
+        ip: &String,
+        since: &u64,
+    ) -> rbatis::Result<u64> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM login_known_address \
+        WHERE last_succeeded_at < to_timestamp(#{before}) \
+    "
+    )]
+    async fn delete_stale_known_addresses(rb: &RBatis, before: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM login_attempt \
+        WHERE author_id = #{author_id} \
+            AND ip = #{ip} \
+    "
+    )]
+    async fn delete_by_author_id_and_ip(
+        rb: &RBatis,
+        author_id: &u64,
+        ip: &String,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+struct RbatisLoginAttemptService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl LoginAttemptService for RbatisLoginAttemptService {
+    async fn failures(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        since: &u64,
+    ) -> DResult<Option<LoginFailures>> {
+        Ok(LoginFailures::select_by_author_id_and_ip(&self.rb, author_id, ip, since).await?)
+    }
+    async fn register_failure(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        current: &LoginFailures,
+        failed_at: &u64,
+        reset_before: &u64,
+    ) -> DResult<bool> {
+        Ok(LoginFailures::upsert_failure(
+            &self.rb,
+            author_id,
+            ip,
+            current,
+            &(current.failed_count + 1),
+            failed_at,
+            reset_before,
+        )
+        .await?
+        .rows_affected
+            > 0)
+    }
+    async fn clear_failures(&self, author_id: &u64, ip: &String) -> DResult<()> {
+        LoginFailures::delete_by_author_id_and_ip(&self.rb, author_id, ip).await?;
+        Ok(())
+    }
+    async fn register_success(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        succeeded_at: &u64,
+    ) -> DResult<()> {
+        LoginFailures::upsert_known_address(&self.rb, author_id, ip, succeeded_at).await?;
+        Ok(())
+    }
+    async fn is_known_address(&self, author_id: &u64, ip: &String, since: &u64) -> DResult<bool> {
+        Ok(LoginFailures::count_known_address(&self.rb, author_id, ip, since).await? > 0)
+    }
+    async fn delete_stale(&self, failed_before: &u64, succeeded_before: &u64) -> DResult<()> {
+        LoginFailures::delete_stale(&self.rb, failed_before).await?;
+        LoginFailures::delete_stale_known_addresses(&self.rb, succeeded_before).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::traits::notification_preference_service::{
+    DigestFrequency, DueEmailDigest, NotificationPreferenceService, NotificationPreferences,
+};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_notification_preference_service(
+    rb: RBatis,
+) -> Arc<dyn NotificationPreferenceService> {
+    Arc::new(RbatisNotificationPreferenceService { rb })
+}
+
+impl NotificationPreferences {
+    #[py_sql(
+        "
+        SELECT author_notification_preference.* \
+        FROM author_notification_preference \
+        WHERE author_notification_preference.author_id = #{author_id} \
+    "
+    )]
+    async fn select_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Option<NotificationPreferences>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT author_notification_preference.* \
+        FROM author_notification_preference \
+        WHERE \
+            author_notification_preference.author_id IN (
+                trim ',': for _,id in author_ids:
+                    #{id},
+                )
+    "
+    )]
+    async fn select_by_author_ids(
+        rb: &RBatis,
+        author_ids: &HashSet<u64>,
+    ) -> rbatis::Result<Vec<NotificationPreferences>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        INSERT INTO author_notification_preference \
+        (author_id, telegram_events, email_events, in_app_events, digest, updated_at) \
+        VALUES \
+        (#{preferences.author_id}, #{preferences.telegram_events}, #{preferences.email_events}, #{preferences.in_app_events}, #{preferences.digest}, now()) \
+        ON CONFLICT (author_id) DO UPDATE SET \
+            telegram_events = EXCLUDED.telegram_events, \
+            email_events = EXCLUDED.email_events, \
+            in_app_events = EXCLUDED.in_app_events, \
+            digest = EXCLUDED.digest, \
+            updated_at = EXCLUDED.updated_at \
+    "
+    )]
+    async fn upsert(
+        rb: &RBatis,
+        preferences: &NotificationPreferences,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+impl DueEmailDigest {
+    /// Moves `digest_sent_at` of the due authors to `sent_at` and returns them with the
+    /// previous value, the same way email subscriptions are claimed.
+    #[py_sql(
+        "
+        UPDATE author_notification_preference \
+        SET digest_sent_at = to_timestamp(#{sent_at}) \
+        FROM ( \
+            SELECT author_id, digest_sent_at \
+            FROM author_notification_preference \
+            WHERE author_notification_preference.email_events <> '' \
+                AND author_notification_preference.digest = #{frequency} \
+                AND ( \
+                    author_notification_preference.digest_sent_at IS NULL \
+                    OR author_notification_preference.digest_sent_at < to_timestamp(#{sent_before}) \
+                ) \
+            ORDER BY author_notification_preference.author_id ASC \
+            FOR UPDATE SKIP LOCKED \
+        ) AS due \
+        WHERE author_notification_preference.author_id = due.author_id \
+        RETURNING \
+            author_notification_preference.author_id, \
+            author_notification_preference.email_events, \
+            due.digest_sent_at \
+    "
+    )]
+    async fn claim_due(
+        rb: &RBatis,
+        frequency: &DigestFrequency,
+        sent_before: &u64,
+        sent_at: &u64,
+    ) -> rbatis::Result<Vec<DueEmailDigest>> {
+        impled!()
+    }
+}
+
+struct RbatisNotificationPreferenceService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl NotificationPreferenceService for RbatisNotificationPreferenceService {
+    async fn notification_preferences_by_author_id(
+        &self,
+        author_id: &u64,
+    ) -> DResult<NotificationPreferences> {
+        Ok(
+            NotificationPreferences::select_by_author_id(&self.rb, author_id)
+                .await?
+                .unwrap_or_else(|| NotificationPreferences::default_for(*author_id)),
+        )
+    }
+    async fn notification_preferences_by_author_ids(
+        &self,
+        author_ids: &HashSet<u64>,
+    ) -> DResult<Vec<NotificationPreferences>> {
+        if author_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut preferences =
+            NotificationPreferences::select_by_author_ids(&self.rb, author_ids).await?;
+        let saved_ids: HashSet<u64> = preferences.iter().map(|p| p.author_id).collect();
+        preferences.extend(
+            author_ids
+                .difference(&saved_ids)
+                .map(|id| NotificationPreferences::default_for(*id)),
+        );
+        Ok(preferences)
+    }
+    async fn set_notification_preferences(
+        &self,
+        preferences: &NotificationPreferences,
+    ) -> DResult<()> {
+        NotificationPreferences::upsert(&self.rb, preferences).await?;
+        Ok(())
+    }
+    async fn claim_due_email_digests(
+        &self,
+        frequency: &DigestFrequency,
+        sent_before: &u64,
+        sent_at: &u64,
+    ) -> DResult<Vec<DueEmailDigest>> {
+        Ok(DueEmailDigest::claim_due(&self.rb, frequency, sent_before, sent_at).await?)
+    }
+}
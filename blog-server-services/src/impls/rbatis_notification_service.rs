@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::traits::notification_service::{BaseNotification, Notification, NotificationService};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_notification_service(rb: RBatis) -> Arc<dyn NotificationService> {
+    Arc::new(RbatisNotificationService { rb })
+}
+
+impl BaseNotification {
+    #[py_sql(
+        "
+        INSERT INTO author_notification \
+        (author_id, event, actor_id, action, link, created_at, read) \
+        VALUES
+            trim ',': for _,n in notifications:
+                (#{n.author_id}, #{n.event}, #{n.actor_id}, #{n.action}, #{n.link}, to_timestamp(#{n.created_at}), #{n.read}),
+    "
+    )]
+    async fn insert_all(
+        rb: &RBatis,
+        notifications: &[BaseNotification],
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+impl Notification {
+    #[py_sql(
+        "
+        SELECT COUNT(1) \
+        FROM author_notification \
+        WHERE author_notification.author_id = #{author_id} \
+    "
+    )]
+    async fn count_by_author_id(rb: &RBatis, author_id: &u64) -> rbatis::Result<u64> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT COUNT(1) \
+        FROM author_notification \
+        WHERE author_notification.author_id = #{author_id} \
+            AND author_notification.read = 0 \
+    "
+    )]
+    async fn count_unread_by_author_id(rb: &RBatis, author_id: &u64) -> rbatis::Result<u64> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT author_notification.* \
+        FROM author_notification \
+        WHERE author_notification.author_id = #{author_id} \
+        ORDER BY author_notification.id DESC \
+        LIMIT #{limit} \
+        OFFSET #{offset} \
+    "
+    )]
+    async fn select_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+        offset: &u64,
+        limit: &u64,
+    ) -> rbatis::Result<Vec<Notification>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_notification \
+        SET read = 1 \
+        WHERE author_notification.author_id = #{author_id} \
+            AND author_notification.read = 0 \
+            AND author_notification.id IN (
+                trim ',': for _,id in ids:
+                    #{id},
+                )
+    "
+    )]
+    async fn mark_read_by_ids(
+        rb: &RBatis,
+        author_id: &u64,
+        ids: &HashSet<u64>,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_notification \
+        SET read = 1 \
+        WHERE author_notification.author_id = #{author_id} \
+            AND author_notification.read = 0 \
+    "
+    )]
+    async fn mark_all_read(rb: &RBatis, author_id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+struct RbatisNotificationService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl NotificationService for RbatisNotificationService {
+    async fn create_notifications(&self, notifications: &[BaseNotification]) -> DResult<()> {
+        if notifications.is_empty() {
+            return Ok(());
+        }
+        BaseNotification::insert_all(&self.rb, notifications).await?;
+        Ok(())
+    }
+    async fn notifications_count_by_author_id(&self, author_id: &u64) -> DResult<u64> {
+        Ok(Notification::count_by_author_id(&self.rb, author_id).await?)
+    }
+    async fn unread_notifications_count_by_author_id(&self, author_id: &u64) -> DResult<u64> {
+        Ok(Notification::count_unread_by_author_id(&self.rb, author_id).await?)
+    }
+    async fn notifications_by_author_id(
+        &self,
+        author_id: &u64,
+        offset: &u64,
+        limit: &u64,
+    ) -> DResult<Vec<Notification>> {
+        Ok(Notification::select_by_author_id(&self.rb, author_id, offset, limit).await?)
+    }
+    async fn mark_notifications_read(&self, author_id: &u64, ids: &HashSet<u64>) -> DResult<u64> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        Ok(Notification::mark_read_by_ids(&self.rb, author_id, ids)
+            .await?
+            .rows_affected)
+    }
+    async fn mark_all_notifications_read(&self, author_id: &u64) -> DResult<u64> {
+        Ok(Notification::mark_all_read(&self.rb, author_id)
+            .await?
+            .rows_affected)
+    }
+}
@@ -1,4 +1,9 @@
-use crate::traits::post_service::{BasePost, Post, PostService, PostsQuery, PostsQueryAnswer, Tag};
+use crate::traits::audit_service::BaseAuditRecord;
+use crate::traits::post_service::{
+    BasePost, Post, PostService, PostsPagination, PostsQuery, PostsQueryAnswer, PostsSort, Tag,
+    TagsMatch,
+};
+use crate::utils::cursor::Cursor;
 use crate::utils::{string_filter, transliteration};
 use rbatis::executor::RBatisTxExecutorGuard;
 use rbatis::{rbatis::RBatis, rbdc::db::ExecResult};
@@ -156,7 +161,7 @@ impl Post {
     "
     )]
     async fn set_recommended_by_id(
-        rb: &RBatis,
+        rb: &mut RBatisTxExecutorGuard,
         id: &u64,
         recommended: &u8,
     ) -> rbatis::Result<ExecResult> {
@@ -178,7 +183,7 @@ impl RbatisPostService {
         INSERT INTO post
         (author_id,title,slug,summary,publish_type,created_at,content,plain_text_content,image_url,lang)
         VALUES
-        (#{post.author_id},#{post.title},#{post.slug},#{post.summary},#{post.publish_type},to_timestamp(#{post.created_at}),#{post.content},#{post.plain_text_content},#{post.image_url},#{post.lang})
+        (#{post.author_id},#{post.title},#{post.slug},#{post.summary},#{post.publish_type},to_timestamp(#{post.created_at}) AT TIME ZONE 'UTC',#{post.content},#{post.plain_text_content},#{post.image_url},#{post.lang})
         RETURNING id
     "
     )]
@@ -195,17 +200,18 @@ impl RbatisPostService {
             summary = #{post_data.summary}, \
             publish_type = #{post_data.publish_type},
             if update_created_at:
-                created_at = to_timestamp(#{post_data.created_at}),
+                created_at = to_timestamp(#{post_data.created_at}) AT TIME ZONE 'UTC',
             content = #{post_data.content}, \
             plain_text_content = #{post_data.plain_text_content}, \
             image_url = #{post_data.image_url}, \
-            lang = #{post_data.lang} \
+            lang = #{post_data.lang}, \
+            updated_at = now() AT TIME ZONE 'UTC' \
         WHERE id = #{post_id} \
         RETURNING id
     "
     )]
     async fn update_post_by_id(
-        rb: &RBatis,
+        rb: &mut RBatisTxExecutorGuard,
         post_id: &u64,
         post_data: &BasePost,
         update_created_at: &bool,
@@ -292,7 +298,51 @@ impl RbatisPostService {
 struct PostAndTotalCount {
     #[serde(flatten)]
     pub origin: Post,
-    pub total_count: u64,
+    #[serde(default)]
+    pub total_count: Option<u64>,
+    #[serde(default)]
+    pub cursor_key: Option<f64>,
+}
+
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+/// Listing order: an optional leading sort key (as a `float8` SQL expression
+/// and whether it is descending), with `post.id` as the unique tie breaker.
+struct PostsOrder {
+    key: Option<(&'static str, bool)>,
+    id_desc: bool,
+}
+
+impl PostsOrder {
+    fn for_query(query: &PostsQuery) -> Self {
+        const RANK: &str = "ts_rank_cd(textsearch, query)::float8";
+        const CREATED_AT: &str = "EXTRACT(EPOCH FROM post.created_at)::float8";
+        const UPDATED_AT: &str =
+            "EXTRACT(EPOCH FROM COALESCE(post.updated_at, post.created_at))::float8";
+        const COMMENTS_COUNT: &str =
+            "(SELECT COUNT(*) FROM post_comment WHERE post_comment.post_id = post.id)::float8";
+
+        let (key, id_desc) = match (query.sort, query.search_query) {
+            (Some(PostsSort::Newest), _) => (Some((CREATED_AT, true)), true),
+            (Some(PostsSort::Oldest), _) => (Some((CREATED_AT, false)), false),
+            (Some(PostsSort::RecentlyUpdated), _) => (Some((UPDATED_AT, true)), true),
+            (Some(PostsSort::MostCommented), _) => (Some((COMMENTS_COUNT, true)), true),
+            (Some(PostsSort::Relevance), Some(_)) => (Some((RANK, true)), true),
+            (None, Some(_)) => (Some((RANK, false)), true),
+            (Some(PostsSort::Relevance), None) | (None, None) => (None, true),
+        };
+        PostsOrder { key, id_desc }
+    }
+
+    fn direction(desc: bool) -> &'static str {
+        if desc { "DESC" } else { "ASC" }
+    }
+
+    fn after_comparator(desc: bool) -> &'static str {
+        if desc { "<" } else { ">" }
+    }
 }
 
 #[async_trait]
@@ -301,14 +351,17 @@ impl PostService for RbatisPostService {
         &self,
         query: PostsQuery<'q, 'a, 't, 'p, 'o, 'l>,
     ) -> DResult<PostsQueryAnswer> {
+        let order = PostsOrder::for_query(&query);
         let mut args: Vec<Value> = vec![];
-        let query = vec![
+        let sql = vec![
             {
-                let mut select_parts = vec!["post.*"];
-                if let Some(_) = query.search_query {
-                    select_parts.push("ts_rank_cd(textsearch, query) AS rank");
+                let mut select_parts = vec!["post.*".to_string()];
+                if let Some((key, _)) = order.key {
+                    select_parts.push(format!("{key} AS cursor_key"));
+                }
+                if let PostsPagination::Offset(_) = query.pagination {
+                    select_parts.push("COUNT(*) OVER() AS total_count".to_string());
                 }
-                select_parts.push("COUNT(*) OVER() AS total_count");
                 Some(format!("SELECT {}", select_parts.join(", ")))
             },
             {
@@ -320,38 +373,87 @@ impl PostService for RbatisPostService {
                 }
                 Some(format!("FROM {}", from_parts.join(", ")))
             },
-            {
-                let mut join_parts = vec![];
-                if let Some(_) = query.tag_id {
-                    join_parts.push("post_tag ON post.id = post_tag.post_id");
-                }
-                if join_parts.is_empty() {
-                    None
-                } else {
-                    Some(format!("JOIN {}", join_parts.join(", ")))
-                }
-            },
             {
                 let mut where_parts = vec![];
                 if let Some(_) = query.search_query {
-                    where_parts.push("textsearch @@ query");
+                    where_parts.push("textsearch @@ query".to_string());
+                }
+                if !query.author_ids.is_empty() {
+                    where_parts.push(format!(
+                        "post.author_id IN ({})",
+                        placeholders(query.author_ids.len())
+                    ));
+                    args.extend(query.author_ids.iter().map(|id| value!(id)));
+                }
+                if !query.tag_ids.is_empty() {
+                    let tag_ids = query.tag_ids.iter().collect::<HashSet<_>>();
+                    let tags_in = format!(
+                        "SELECT post_tag.post_id FROM post_tag WHERE post_tag.tag_id IN ({})",
+                        placeholders(tag_ids.len())
+                    );
+                    args.extend(tag_ids.iter().map(|id| value!(id)));
+                    match query.tags_match {
+                        TagsMatch::Any => {
+                            where_parts.push(format!("post.id IN ({tags_in})"));
+                        }
+                        TagsMatch::All => {
+                            where_parts.push(format!(
+                                "post.id IN ({tags_in} GROUP BY post_tag.post_id HAVING COUNT(*) = ?)"
+                            ));
+                            args.push(value!(tag_ids.len() as u64));
+                        }
+                    }
                 }
-                if let Some(author_id) = query.author_id {
-                    where_parts.push("author_id = ?");
-                    args.push(value!(author_id));
+                if !query.exclude_tag_ids.is_empty() {
+                    where_parts.push(format!(
+                        "NOT EXISTS (SELECT 1 FROM post_tag WHERE post_tag.post_id = post.id AND post_tag.tag_id IN ({}))",
+                        placeholders(query.exclude_tag_ids.len())
+                    ));
+                    args.extend(query.exclude_tag_ids.iter().map(|id| value!(id)));
                 }
-                if let Some(tag_id) = query.tag_id {
-                    where_parts.push("post_tag.tag_id = ?");
-                    args.push(value!(tag_id));
+                if let Some(created_from) = query.created_from {
+                    where_parts.push("post.created_at >= to_timestamp(?) AT TIME ZONE 'UTC'".to_string());
+                    args.push(value!(created_from));
+                }
+                if let Some(created_to) = query.created_to {
+                    where_parts.push("post.created_at < to_timestamp(?) AT TIME ZONE 'UTC'".to_string());
+                    args.push(value!(created_to));
+                }
+                if let Some(follower_id) = query.followed_by {
+                    where_parts.push(
+                        "(post.author_id IN (SELECT author_follow.author_id FROM author_follow WHERE author_follow.follower_id = ?) \
+                        OR post.id IN (SELECT post_tag.post_id FROM post_tag JOIN tag_follow ON tag_follow.tag_id = post_tag.tag_id WHERE tag_follow.follower_id = ?))"
+                            .to_string(),
+                    );
+                    args.push(value!(follower_id));
+                    args.push(value!(follower_id));
                 }
                 if let Some(publish_type) = query.publish_type {
-                    where_parts.push("publish_type = ?");
+                    where_parts.push("publish_type = ?".to_string());
                     args.push(value!(publish_type));
                 }
                 if let Some(lang) = BasePost::current_lang() {
-                    where_parts.push("(post.lang = ? OR post.lang IS NULL)");
+                    where_parts.push("(post.lang = ? OR post.lang IS NULL)".to_string());
                     args.push(value!(lang));
                 }
+                if let PostsPagination::Cursor(Some(cursor)) = &query.pagination {
+                    let id_comparator = PostsOrder::after_comparator(order.id_desc);
+                    match (order.key, cursor.key) {
+                        (Some((key, key_desc)), Some(cursor_key)) => {
+                            let key_comparator = PostsOrder::after_comparator(key_desc);
+                            where_parts.push(format!(
+                                "({key} {key_comparator} ? OR ({key} = ? AND post.id {id_comparator} ?))"
+                            ));
+                            args.push(value!(cursor_key));
+                            args.push(value!(cursor_key));
+                            args.push(value!(cursor.id));
+                        }
+                        _ => {
+                            where_parts.push(format!("post.id {id_comparator} ?"));
+                            args.push(value!(cursor.id));
+                        }
+                    }
+                }
                 if where_parts.is_empty() {
                     None
                 } else {
@@ -360,10 +462,11 @@ impl PostService for RbatisPostService {
             },
             {
                 let mut order_by_parts = vec![];
-                if let Some(_) = query.search_query {
-                    order_by_parts.push("rank");
+                if let Some((_, key_desc)) = order.key {
+                    order_by_parts
+                        .push(format!("cursor_key {}", PostsOrder::direction(key_desc)));
                 }
-                order_by_parts.push("post.id DESC");
+                order_by_parts.push(format!("post.id {}", PostsOrder::direction(order.id_desc)));
                 Some(format!("ORDER BY {}", order_by_parts.join(", ")))
             },
             {
@@ -371,22 +474,38 @@ impl PostService for RbatisPostService {
                 Some("LIMIT ?".to_string())
             },
             {
-                args.push(value!(query.offset));
-                Some("OFFSET ?".to_string())
-            }
+                if let PostsPagination::Offset(offset) = query.pagination {
+                    args.push(value!(offset));
+                    Some("OFFSET ?".to_string())
+                } else {
+                    None
+                }
+            },
         ]
-            .into_iter()
-            .filter_map(|x| x)
-            .collect::<Vec<String>>()
-            .join(" ");
+        .into_iter()
+        .filter_map(|x| x)
+        .collect::<Vec<String>>()
+        .join(" ");
 
         let posts_with_total_count: Vec<PostAndTotalCount> =
-            self.rb.query_decode(query.as_str(), args).await?;
-
-        let total_count = posts_with_total_count
-            .first()
-            .map(|p| p.total_count)
-            .unwrap_or(0);
+            self.rb.query_decode(sql.as_str(), args).await?;
+
+        let total_count = match query.pagination {
+            PostsPagination::Offset(_) => Some(
+                posts_with_total_count
+                    .first()
+                    .and_then(|p| p.total_count)
+                    .unwrap_or(0),
+            ),
+            PostsPagination::Cursor(_) => None,
+        };
+        let next_cursor = posts_with_total_count
+            .last()
+            .filter(|_| posts_with_total_count.len() as u64 >= *query.limit)
+            .map(|p| Cursor {
+                key: p.cursor_key,
+                id: p.origin.id,
+            });
         let posts = posts_with_total_count
             .into_iter()
             .map(|p| p.origin)
@@ -397,6 +516,7 @@ impl PostService for RbatisPostService {
         Ok(PostsQueryAnswer {
             total_count,
             posts: posts_with_tags,
+            next_cursor,
         })
     }
 
@@ -415,8 +535,19 @@ impl PostService for RbatisPostService {
         id: &u64,
         post_data: &BasePost,
         update_created_at: &bool,
+        audit_record: Option<&BaseAuditRecord>,
     ) -> DResult<()> {
-        RbatisPostService::update_post_by_id(&self.rb, id, post_data, update_created_at).await?;
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        RbatisPostService::update_post_by_id(&mut tx, id, post_data, update_created_at).await?;
+        if let Some(audit_record) = audit_record {
+            BaseAuditRecord::insert(&mut tx, audit_record).await?;
+        }
+        tx.commit().await?;
         Ok(())
     }
 
@@ -439,8 +570,21 @@ impl PostService for RbatisPostService {
         RbatisPostService::saturate_with_tags(&self, post_option).await
     }
 
-    async fn set_post_recommended_by_id(&self, id: &u64, recommended: &u8) -> DResult<()> {
-        Post::set_recommended_by_id(&self.rb, id, recommended).await?;
+    async fn set_post_recommended_by_id(
+        &self,
+        id: &u64,
+        recommended: &u8,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        Post::set_recommended_by_id(&mut tx, id, recommended).await?;
+        BaseAuditRecord::insert(&mut tx, audit_record).await?;
+        tx.commit().await?;
         Ok(())
     }
 
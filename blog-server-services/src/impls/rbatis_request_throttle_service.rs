@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::traits::request_throttle_service::{RequestThrottleService, RequestWindow};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_request_throttle_service(rb: RBatis) -> Arc<dyn RequestThrottleService> {
+    Arc::new(RbatisRequestThrottleService { rb })
+}
+
+impl RequestWindow {
+    #[py_sql(
+        "
+        INSERT INTO request_throttle \
+        (key, request_count, started_at) \
+        VALUES \
+        (#{key}, 1, to_timestamp(#{now})) \
+        ON CONFLICT (key) DO UPDATE \
+        SET \
+            request_count = CASE \
+                WHEN request_throttle.started_at < to_timestamp(#{window_start}) THEN 1 \
+                ELSE request_throttle.request_count + 1 \
+            END, \
+            started_at = CASE \
+                WHEN request_throttle.started_at < to_timestamp(#{window_start}) THEN EXCLUDED.started_at \
+                ELSE request_throttle.started_at \
+            END \
+        RETURNING request_count, started_at \
+    "
+    )]
+    async fn upsert(
+        rb: &RBatis,
+        key: &String,
+        now: &u64,
+        window_start: &u64,
+    ) -> rbatis::Result<RequestWindow> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM request_throttle \
+        WHERE started_at < to_timestamp(#{before}) \
+    "
+    )]
+    async fn delete_stale(rb: &RBatis, before: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+struct RbatisRequestThrottleService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl RequestThrottleService for RbatisRequestThrottleService {
+    async fn hit(&self, key: &String, now: &u64, window_start: &u64) -> DResult<RequestWindow> {
+        RequestWindow::delete_stale(&self.rb, window_start).await?;
+        Ok(RequestWindow::upsert(&self.rb, key, now, window_start).await?)
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use crate::traits::session_service::{BaseSession, Session, SessionService};
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+
+pub fn create_rbatis_session_service(rb: RBatis) -> Arc<dyn SessionService> {
+    Arc::new(RbatisSessionService { rb })
+}
+
+impl BaseSession {
+    #[py_sql(
+        "
+        INSERT INTO author_session
+        (author_id, refresh_token_hash, user_agent, created_at, last_used_at, expires_at)
+        VALUES
+        (#{session.author_id}, #{session.refresh_token_hash}, #{session.user_agent}, to_timestamp(#{session.created_at}), to_timestamp(#{session.last_used_at}), to_timestamp(#{session.expires_at}))
+        RETURNING id
+    "
+    )]
+    async fn insert(rb: &RBatis, session: &BaseSession) -> rbatis::Result<u64> {
+        impled!()
+    }
+}
+
+impl Session {
+    #[py_sql(
+        "
+        SELECT \
+            author_session.* \
+        FROM author_session \
+        WHERE author_session.author_id = #{author_id} \
+            AND author_session.revoked = 0 \
+            AND author_session.expires_at > now() \
+        ORDER BY author_session.last_used_at DESC \
+    "
+    )]
+    async fn select_active_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Vec<Session>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        WITH rotated AS ( \
+            UPDATE author_session \
+            SET \
+                refresh_token_hash = #{new_refresh_token_hash}, \
+                last_used_at = now(), \
+                expires_at = to_timestamp(#{expires_at}) \
+            WHERE id = #{id} \
+                AND refresh_token_hash = #{refresh_token_hash} \
+                AND revoked = 0 \
+                AND expires_at > now() \
+            RETURNING * \
+        ), used AS ( \
+            INSERT INTO author_session_rotated_token \
+            (refresh_token_hash, session_id, author_id, rotated_at) \
+            SELECT #{refresh_token_hash}, rotated.id, rotated.author_id, now() \
+            FROM rotated \
+        ) \
+        SELECT * FROM rotated \
+    "
+    )]
+    async fn rotate_refresh_token(
+        rb: &RBatis,
+        id: &u64,
+        refresh_token_hash: &String,
+        new_refresh_token_hash: &String,
+        expires_at: &u64,
+    ) -> rbatis::Result<Option<Session>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_session \
+        SET revoked = 1 \
+        WHERE id = #{id} \
+            AND revoked = 0 \
+            AND EXISTS ( \
+                SELECT 1 FROM author_session_rotated_token \
+                WHERE session_id = #{id} \
+                    AND refresh_token_hash = #{refresh_token_hash} \
+            ) \
+    "
+    )]
+    async fn revoke_by_rotated_refresh_token(
+        rb: &RBatis,
+        id: &u64,
+        refresh_token_hash: &String,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_session \
+        SET revoked = 1 \
+        WHERE id = #{id} \
+    "
+    )]
+    async fn revoke_by_id(rb: &RBatis, id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_session \
+        SET revoked = 1 \
+        WHERE author_id = #{author_id} \
+            AND revoked = 0 \
+    "
+    )]
+    async fn revoke_by_author_id(rb: &RBatis, author_id: &u64) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+struct RbatisSessionService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl SessionService for RbatisSessionService {
+    async fn create_session(&self, session: &BaseSession) -> DResult<u64> {
+        Ok(BaseSession::insert(&self.rb, session).await?)
+    }
+    async fn active_sessions_by_author_id(&self, author_id: &u64) -> DResult<Vec<Session>> {
+        Ok(Session::select_active_by_author_id(&self.rb, author_id).await?)
+    }
+    async fn rotate_refresh_token(
+        &self,
+        id: &u64,
+        refresh_token_hash: &String,
+        new_refresh_token_hash: &String,
+        expires_at: &u64,
+    ) -> DResult<Option<Session>> {
+        Ok(Session::rotate_refresh_token(
+            &self.rb,
+            id,
+            refresh_token_hash,
+            new_refresh_token_hash,
+            expires_at,
+        )
+        .await?)
+    }
+    async fn revoke_session_by_rotated_refresh_token(
+        &self,
+        id: &u64,
+        refresh_token_hash: &String,
+    ) -> DResult<bool> {
+        Ok(
+            Session::revoke_by_rotated_refresh_token(&self.rb, id, refresh_token_hash)
+                .await?
+                .rows_affected
+                > 0,
+        )
+    }
+    async fn revoke_session_by_id(&self, id: &u64) -> DResult<()> {
+        Session::revoke_by_id(&self.rb, id).await?;
+        Ok(())
+    }
+    async fn revoke_sessions_by_author_id(&self, author_id: &u64) -> DResult<()> {
+        Session::revoke_by_author_id(&self.rb, author_id).await?;
+        Ok(())
+    }
+}
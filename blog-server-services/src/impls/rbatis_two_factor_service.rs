@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use crate::traits::two_factor_service::{AuthorTotp, TwoFactorService};
+use rbatis::executor::RBatisTxExecutorGuard;
+use rbatis::rbatis::RBatis;
+use rbatis::rbdc::db::ExecResult;
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+pub fn create_rbatis_two_factor_service(rb: RBatis) -> Arc<dyn TwoFactorService> {
+    Arc::new(RbatisTwoFactorService { rb })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct AuthorRecoveryCode {
+    author_id: u64,
+    code_hash: String,
+}
+
+impl_insert!(AuthorRecoveryCode {}, "author_recovery_code");
+
+impl AuthorRecoveryCode {
+    #[py_sql(
+        "
+        UPDATE author_recovery_code \
+        SET used_at = now() \
+        WHERE author_id = #{author_id} \
+            AND code_hash = #{code_hash} \
+            AND used_at IS NULL \
+        RETURNING author_id \
+    "
+    )]
+    async fn mark_used(
+        rb: &RBatis,
+        author_id: &u64,
+        code_hash: &String,
+    ) -> rbatis::Result<Option<u64>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        SELECT COUNT(1) \
+        FROM author_recovery_code \
+        WHERE author_id = #{author_id} \
+            AND used_at IS NULL \
+    "
+    )]
+    async fn count_unused(rb: &RBatis, author_id: &u64) -> rbatis::Result<u64> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM author_recovery_code \
+        WHERE author_id = #{author_id} \
+    "
+    )]
+    async fn delete_by_author_id(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+impl AuthorTotp {
+    #[py_sql(
+        "
+        SELECT \
+            author_totp.* \
+        FROM author_totp \
+        WHERE author_totp.author_id = #{author_id} \
+        LIMIT 1 \
+    "
+    )]
+    async fn select_by_author_id(
+        rb: &RBatis,
+        author_id: &u64,
+    ) -> rbatis::Result<Option<AuthorTotp>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        INSERT INTO author_totp \
+        (author_id, secret, enabled, created_at) \
+        VALUES \
+        (#{author_id}, #{secret}, 0, now()) \
+        ON CONFLICT (author_id) DO UPDATE \
+        SET \
+            secret = EXCLUDED.secret, \
+            last_used_step = NULL, \
+            created_at = EXCLUDED.created_at \
+        WHERE author_totp.enabled = 0 \
+    "
+    )]
+    async fn upsert_pending(
+        rb: &RBatis,
+        author_id: &u64,
+        secret: &String,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_totp \
+        SET enabled = 1 \
+        WHERE author_id = #{author_id} \
+    "
+    )]
+    async fn set_enabled(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        UPDATE author_totp \
+        SET last_used_step = #{step} \
+        WHERE author_id = #{author_id} \
+            AND (last_used_step IS NULL OR last_used_step < #{step}) \
+        RETURNING author_id \
+    "
+    )]
+    async fn set_last_used_step(
+        rb: &RBatis,
+        author_id: &u64,
+        step: &u64,
+    ) -> rbatis::Result<Option<u64>> {
+        impled!()
+    }
+
+    #[py_sql(
+        "
+        DELETE FROM author_totp \
+        WHERE author_id = #{author_id} \
+    "
+    )]
+    async fn delete_by_author_id(
+        rb: &mut RBatisTxExecutorGuard,
+        author_id: &u64,
+    ) -> rbatis::Result<ExecResult> {
+        impled!()
+    }
+}
+
+struct RbatisTwoFactorService {
+    rb: RBatis,
+}
+
+#[async_trait]
+impl TwoFactorService for RbatisTwoFactorService {
+    async fn totp_by_author_id(&self, author_id: &u64) -> DResult<Option<AuthorTotp>> {
+        Ok(AuthorTotp::select_by_author_id(&self.rb, author_id).await?)
+    }
+    async fn set_pending_totp(&self, author_id: &u64, secret: &String) -> DResult<()> {
+        AuthorTotp::upsert_pending(&self.rb, author_id, secret).await?;
+        Ok(())
+    }
+    async fn enable_totp(
+        &self,
+        author_id: &u64,
+        recovery_code_hashes: &Vec<String>,
+    ) -> DResult<()> {
+        let recovery_codes: Vec<AuthorRecoveryCode> = recovery_code_hashes
+            .iter()
+            .map(|code_hash| AuthorRecoveryCode {
+                author_id: *author_id,
+                code_hash: code_hash.clone(),
+            })
+            .collect();
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        AuthorRecoveryCode::delete_by_author_id(&mut tx, author_id).await?;
+        if !recovery_codes.is_empty() {
+            AuthorRecoveryCode::insert_batch(&mut tx, &recovery_codes, recovery_codes.len() as u64)
+                .await?;
+        }
+        AuthorTotp::set_enabled(&mut tx, author_id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    async fn use_totp_step(&self, author_id: &u64, step: &u64) -> DResult<bool> {
+        Ok(AuthorTotp::set_last_used_step(&self.rb, author_id, step)
+            .await?
+            .is_some())
+    }
+    async fn use_recovery_code(&self, author_id: &u64, code_hash: &String) -> DResult<bool> {
+        Ok(
+            AuthorRecoveryCode::mark_used(&self.rb, author_id, code_hash)
+                .await?
+                .is_some(),
+        )
+    }
+    async fn unused_recovery_codes_count(&self, author_id: &u64) -> DResult<u64> {
+        Ok(AuthorRecoveryCode::count_unused(&self.rb, author_id).await?)
+    }
+    async fn disable_totp(&self, author_id: &u64) -> DResult<()> {
+        let tx = self.rb.acquire_begin().await?;
+        let mut tx = tx.defer_async(|tx| async move {
+            if !tx.done() {
+                let _ = tx.rollback().await;
+            }
+        });
+        AuthorRecoveryCode::delete_by_author_id(&mut tx, author_id).await?;
+        AuthorTotp::delete_by_author_id(&mut tx, author_id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use lettre::message::{Mailbox, MultiPart, header::ContentType};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use screw_components::dyn_result::DResult;
+use std::sync::Arc;
+
+use crate::traits::mail_service::{Mail, MailService, SmtpConfig, SmtpSecurity};
+
+pub fn create_smtp_mail_service(
+    config: SmtpConfig,
+) -> Result<Arc<dyn MailService>, Box<dyn std::error::Error + Send + Sync>> {
+    let from: Mailbox = config.from.parse()?;
+
+    let mut transport = match config.security {
+        SmtpSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        SmtpSecurity::StartTls => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+        }
+        SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)?,
+    }
+    .port(config.port);
+
+    if let (Some(username), Some(password)) = (config.username, config.password) {
+        transport = transport.credentials(Credentials::new(username, password));
+    }
+
+    Ok(Arc::new(SmtpMailService {
+        transport: transport.build(),
+        from,
+    }))
+}
+
+struct SmtpMailService {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+#[async_trait]
+impl MailService for SmtpMailService {
+    async fn send(&self, mail: Mail) -> DResult<()> {
+        let builder = Message::builder()
+            .from(self.from.clone())
+            .to(mail.to.parse()?)
+            .subject(mail.subject);
+        let message = match mail.html {
+            Some(html) => builder.multipart(MultiPart::alternative_plain_html(mail.body, html))?,
+            None => builder.header(ContentType::TEXT_PLAIN).body(mail.body)?,
+        };
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
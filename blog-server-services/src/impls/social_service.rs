@@ -5,22 +5,66 @@ use std::sync::Arc;
 
 use crate::traits::Publish;
 use crate::traits::author_service::{Author, AuthorService, BaseMinimalAuthor};
-use crate::traits::social_service::{SocialId, SocialService as SocialServiceTrait};
+use crate::traits::identity_service::{AuthorIdentity, BaseAuthorIdentity, IdentityService};
+use crate::traits::social_service::{IdentityLink, SocialId, SocialService as SocialServiceTrait};
+use crate::utils::time_utils;
 
 pub fn create_social_service(
     author_service: Arc<dyn AuthorService>,
+    identity_service: Arc<dyn IdentityService>,
     subscription_state_changed_service: Arc<dyn Publish<SubscriptionStateChanged>>,
 ) -> Arc<dyn SocialServiceTrait> {
     Arc::new(SocialService {
         author_service,
+        identity_service,
         subscription_state_changed_service,
     })
 }
 struct SocialService {
     author_service: Arc<dyn AuthorService>,
+    identity_service: Arc<dyn IdentityService>,
     subscription_state_changed_service: Arc<dyn Publish<SubscriptionStateChanged>>,
 }
 
+impl SocialService {
+    /// Linked identities first, Yandex and Telegram ids of the author row are
+    /// still checked for rows that predate identity records.
+    async fn author_by_social_id(&self, social_id: &SocialId) -> DResult<Option<Author>> {
+        let (provider, subject) = social_id.provider_and_subject();
+        if let Some(identity) = self
+            .identity_service
+            .identity_by_provider_and_subject(&provider, &subject)
+            .await?
+        {
+            return self
+                .author_service
+                .author_by_id(&identity.base.author_id)
+                .await;
+        }
+        match social_id {
+            SocialId::TelegramId(telegram_id) => {
+                self.author_service.author_by_telegram_id(telegram_id).await
+            }
+            SocialId::YandexId(yandex_id) => {
+                self.author_service.author_by_yandex_id(yandex_id).await
+            }
+            SocialId::Identity { .. } => Ok(None),
+        }
+    }
+
+    async fn create_identity(&self, author_id: &u64, social_id: &SocialId) -> DResult<()> {
+        let (provider, subject) = social_id.provider_and_subject();
+        self.identity_service
+            .create_identity(&BaseAuthorIdentity {
+                author_id: *author_id,
+                provider,
+                subject,
+                created_at: time_utils::now_as_secs(),
+            })
+            .await
+    }
+}
+
 #[async_trait]
 impl SocialServiceTrait for SocialService {
     async fn set_subscribe_for_author(&self, author: &Author, subscribe: &u8) -> DResult<()> {
@@ -47,27 +91,21 @@ impl SocialServiceTrait for SocialService {
         social_id: &SocialId,
         base_minimal_author: &BaseMinimalAuthor,
     ) -> DResult<Author> {
-        let (social_author_id, _is_new_author) = if let Some(author) = match &social_id {
-            SocialId::TelegramId(telegram_id) => {
-                self.author_service
-                    .author_by_telegram_id(&telegram_id)
-                    .await?
-            }
-            SocialId::YandexId(yandex_id) => {
-                self.author_service.author_by_yandex_id(&yandex_id).await?
-            }
-        } {
+        let existing_author = self.author_by_social_id(social_id).await?;
+
+        let (social_author_id, _is_new_author) = if let Some(author) = existing_author {
             (
                 if author.base.override_social_data != 0 {
                     author.id
                 } else {
+                    // Ids of other providers linked to the author are kept.
                     let updated_id = self
                         .author_service
                         .update_minimal_social_author_by_id(
                             &author.id,
                             base_minimal_author,
-                            social_id.yandex_id(),
-                            social_id.telegram_id(),
+                            social_id.yandex_id().or(author.base.yandex_id.as_ref()),
+                            social_id.telegram_id().or(author.base.telegram_id.as_ref()),
                         )
                         .await?;
                     updated_id
@@ -86,6 +124,8 @@ impl SocialServiceTrait for SocialService {
             (insert_id, true)
         };
 
+        self.create_identity(&social_author_id, social_id).await?;
+
         let social_author = self
             .author_service
             .author_by_id(&social_author_id)
@@ -94,4 +134,58 @@ impl SocialServiceTrait for SocialService {
 
         Ok(social_author)
     }
+    async fn link_identity(&self, author: &Author, social_id: &SocialId) -> DResult<IdentityLink> {
+        if let Some(owner) = self.author_by_social_id(social_id).await? {
+            if owner.id != author.id {
+                return Ok(IdentityLink::LinkedToAnotherAuthor {
+                    author_id: owner.id,
+                });
+            }
+            self.create_identity(&author.id, social_id).await?;
+            return Ok(IdentityLink::AlreadyLinked);
+        }
+
+        // Telegram notifications and subscriptions use the author row ids,
+        // ids the author already has are kept.
+        if social_id.yandex_id().is_some() && author.base.yandex_id.is_none()
+            || social_id.telegram_id().is_some() && author.base.telegram_id.is_none()
+        {
+            self.author_service
+                .set_author_social_ids_by_id(
+                    &author.id,
+                    author.base.yandex_id.as_ref().or(social_id.yandex_id()),
+                    author.base.telegram_id.as_ref().or(social_id.telegram_id()),
+                )
+                .await?;
+        }
+
+        self.create_identity(&author.id, social_id).await?;
+
+        Ok(IdentityLink::Linked)
+    }
+    async fn unlink_identity(&self, author: &Author, identity: &AuthorIdentity) -> DResult<()> {
+        self.identity_service.delete_identity(&identity.id).await?;
+
+        let is_row_id = |id: &Option<u64>, provider: &str| {
+            identity.base.provider == provider
+                && id.map(|id| id.to_string()).as_ref() == Some(&identity.base.subject)
+        };
+        let unlink_yandex = is_row_id(&author.base.yandex_id, "yandex");
+        let unlink_telegram = is_row_id(&author.base.telegram_id, "telegram");
+        if unlink_yandex || unlink_telegram {
+            self.author_service
+                .set_author_social_ids_by_id(
+                    &author.id,
+                    author.base.yandex_id.as_ref().filter(|_| !unlink_yandex),
+                    author
+                        .base
+                        .telegram_id
+                        .as_ref()
+                        .filter(|_| !unlink_telegram),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,13 +1,19 @@
 use blog_generic::events::{NewPostPublished, SubscriptionStateChanged};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::traits::{Publish, author_service::AuthorService};
+use crate::traits::notification_preference_service::{
+    NotificationChannel, NotificationPreferenceService,
+};
+use crate::traits::{Publish, author_service::AuthorService, follow_service::FollowService};
 use crate::utils::telegram_send_message_request::TelegramSendMessageRequest;
 
 pub fn create_telegram_user_updates_service(
     bot_token: String,
     site_url: String,
     author_service: Arc<dyn AuthorService>,
+    follow_service: Arc<dyn FollowService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
 ) -> Result<
     Arc<impl Publish<SubscriptionStateChanged> + Publish<NewPostPublished>>,
     Box<dyn std::error::Error + Send + Sync>,
@@ -19,6 +25,8 @@ pub fn create_telegram_user_updates_service(
         telegram_send_message_request: TelegramSendMessageRequest { bot_token },
         site_url,
         author_service,
+        follow_service,
+        notification_preference_service,
     }))
 }
 
@@ -26,6 +34,8 @@ struct TelegramUserUpdatesService {
     telegram_send_message_request: TelegramSendMessageRequest,
     site_url: String,
     author_service: Arc<dyn AuthorService>,
+    follow_service: Arc<dyn FollowService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
 }
 
 #[async_trait]
@@ -48,16 +58,39 @@ impl Publish<NewPostPublished> for TelegramUserUpdatesService {
         let Ok(authors) = self.author_service.authors(&0, &(i64::MAX as u64)).await else {
             return;
         };
-        for author in authors {
-            if author.base.notification_subscribed.unwrap_or_default() == 0 {
+        // The bot-wide switch, toggled by the subscribe and unsubscribe commands.
+        let subscribers: HashMap<u64, u64> = authors
+            .into_iter()
+            .filter(|a| a.base.notification_subscribed.unwrap_or_default() != 0)
+            .filter_map(|a| Some((a.id, a.base.telegram_id?)))
+            .collect();
+        if subscribers.is_empty() {
+            return;
+        }
+        let Ok(follower_ids) = self.follow_service.follower_ids(&event.blog_user_id).await else {
+            return;
+        };
+        let follower_ids: HashSet<u64> = follower_ids.into_iter().collect();
+        let Ok(preferences) = self
+            .notification_preference_service
+            .notification_preferences_by_author_ids(&subscribers.keys().copied().collect())
+            .await
+        else {
+            return;
+        };
+        for preferences in preferences {
+            if !preferences.wants_post(
+                NotificationChannel::Telegram,
+                follower_ids.contains(&preferences.author_id),
+            ) {
                 continue;
             }
-            let Some(author_telegram_id) = author.base.telegram_id else {
+            let Some(author_telegram_id) = subscribers.get(&preferences.author_id) else {
                 continue;
             };
             self.telegram_send_message_request
                 .send(
-                    &(author_telegram_id as i64),
+                    &(*author_telegram_id as i64),
                     &format!(
                         "{SITE_URL}{PATH}",
                         SITE_URL = self.site_url,
@@ -0,0 +1,126 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+use crate::traits::author_service::Capability;
+
+/// What a personal access token may be used for, each scope grants one capability.
+/// Managing roles is never available through a token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenScope {
+    #[serde(rename = "profile:write")]
+    ProfileWrite,
+    #[serde(rename = "comments:write")]
+    CommentsWrite,
+    #[serde(rename = "posts:write")]
+    PostsWrite,
+    #[serde(rename = "posts:publish")]
+    PostsPublish,
+    #[serde(rename = "posts:manage")]
+    PostsManage,
+    #[serde(rename = "posts:recommend")]
+    PostsRecommend,
+    #[serde(rename = "comments:moderate")]
+    CommentsModerate,
+    #[serde(rename = "authors:manage")]
+    AuthorsManage,
+    #[serde(rename = "audit:read")]
+    AuditRead,
+}
+
+impl TokenScope {
+    pub const ALL: [TokenScope; 9] = [
+        TokenScope::ProfileWrite,
+        TokenScope::CommentsWrite,
+        TokenScope::PostsWrite,
+        TokenScope::PostsPublish,
+        TokenScope::PostsManage,
+        TokenScope::PostsRecommend,
+        TokenScope::CommentsModerate,
+        TokenScope::AuthorsManage,
+        TokenScope::AuditRead,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenScope::ProfileWrite => "profile:write",
+            TokenScope::CommentsWrite => "comments:write",
+            TokenScope::PostsWrite => "posts:write",
+            TokenScope::PostsPublish => "posts:publish",
+            TokenScope::PostsManage => "posts:manage",
+            TokenScope::PostsRecommend => "posts:recommend",
+            TokenScope::CommentsModerate => "comments:moderate",
+            TokenScope::AuthorsManage => "authors:manage",
+            TokenScope::AuditRead => "audit:read",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        TokenScope::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+
+    pub fn capability(&self) -> Capability {
+        match self {
+            TokenScope::ProfileWrite => Capability::EditOwnProfile,
+            TokenScope::CommentsWrite => Capability::CreateComments,
+            TokenScope::PostsWrite => Capability::WritePosts,
+            TokenScope::PostsPublish => Capability::PublishPosts,
+            TokenScope::PostsManage => Capability::ManageAnyPost,
+            TokenScope::PostsRecommend => Capability::RecommendPosts,
+            TokenScope::CommentsModerate => Capability::ModerateComments,
+            TokenScope::AuthorsManage => Capability::ManageAuthors,
+            TokenScope::AuditRead => Capability::ViewAuditLog,
+        }
+    }
+
+    /// Space separated, as stored in the `scopes` column.
+    pub fn join(scopes: &[TokenScope]) -> String {
+        scopes
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Unknown scopes are skipped, so renaming one revokes it from existing tokens.
+    pub fn split(scopes: &str) -> Vec<TokenScope> {
+        scopes
+            .split_whitespace()
+            .filter_map(TokenScope::parse)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BaseAccessToken {
+    pub author_id: u64,
+    pub name: String,
+    /// SHA-256 of the token secret, the secret itself is never stored.
+    pub token_hash: String,
+    pub scopes: String,
+    pub created_at: u64,
+    pub last_used_at: Option<u64>,
+    pub expires_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AccessToken {
+    pub id: u64,
+    pub revoked: u8,
+    #[serde(flatten)]
+    pub base: BaseAccessToken,
+}
+
+#[async_trait]
+pub trait AccessTokenService: Send + Sync {
+    async fn create_access_token(&self, access_token: &BaseAccessToken) -> DResult<u64>;
+    async fn active_access_tokens_by_author_id(&self, author_id: &u64)
+    -> DResult<Vec<AccessToken>>;
+    /// Returns the token if `token_hash` matches an active (not revoked and not expired)
+    /// one, recording it as used.
+    async fn use_access_token(&self, id: &u64, token_hash: &String)
+    -> DResult<Option<AccessToken>>;
+    /// Returns whether an active token of the author has been revoked.
+    async fn revoke_access_token(&self, id: &u64, author_id: &u64) -> DResult<bool>;
+}
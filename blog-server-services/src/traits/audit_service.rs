@@ -0,0 +1,149 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    AuthorBlocked,
+    AuthorUnblocked,
+    AuthorRoleChanged,
+    AuthorsMerged,
+    PostRecommended,
+    PostUnrecommended,
+    PostPublished,
+    PostUpdated,
+    CommentDeleted,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::AuthorBlocked => "author_blocked",
+            AuditAction::AuthorUnblocked => "author_unblocked",
+            AuditAction::AuthorRoleChanged => "author_role_changed",
+            AuditAction::AuthorsMerged => "authors_merged",
+            AuditAction::PostRecommended => "post_recommended",
+            AuditAction::PostUnrecommended => "post_unrecommended",
+            AuditAction::PostPublished => "post_published",
+            AuditAction::PostUpdated => "post_updated",
+            AuditAction::CommentDeleted => "comment_deleted",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "author_blocked" => Some(AuditAction::AuthorBlocked),
+            "author_unblocked" => Some(AuditAction::AuthorUnblocked),
+            "author_role_changed" => Some(AuditAction::AuthorRoleChanged),
+            "authors_merged" => Some(AuditAction::AuthorsMerged),
+            "post_recommended" => Some(AuditAction::PostRecommended),
+            "post_unrecommended" => Some(AuditAction::PostUnrecommended),
+            "post_published" => Some(AuditAction::PostPublished),
+            "post_updated" => Some(AuditAction::PostUpdated),
+            "comment_deleted" => Some(AuditAction::CommentDeleted),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditTarget {
+    Author,
+    Post,
+    Comment,
+}
+
+impl AuditTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditTarget::Author => "author",
+            AuditTarget::Post => "post",
+            AuditTarget::Comment => "comment",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "author" => Some(AuditTarget::Author),
+            "post" => Some(AuditTarget::Post),
+            "comment" => Some(AuditTarget::Comment),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BaseAuditRecord {
+    pub actor_id: u64,
+    pub action: AuditAction,
+    pub target_type: AuditTarget,
+    pub target_id: u64,
+    /// JSON snapshot of the target before the action.
+    pub before: Option<String>,
+    /// JSON snapshot of the target after the action.
+    pub after: Option<String>,
+    pub created_at: u64,
+}
+
+impl BaseAuditRecord {
+    pub fn new<S: Serialize>(
+        actor_id: u64,
+        action: AuditAction,
+        target_type: AuditTarget,
+        target_id: u64,
+        before: Option<&S>,
+        after: Option<&S>,
+    ) -> Self {
+        BaseAuditRecord {
+            actor_id,
+            action,
+            target_type,
+            target_id,
+            before: before.and_then(|s| serde_json::to_string(s).ok()),
+            after: after.and_then(|s| serde_json::to_string(s).ok()),
+            created_at: time_utils::now_as_secs(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AuditRecord {
+    pub id: u64,
+    #[serde(flatten)]
+    pub base: BaseAuditRecord,
+}
+
+/// Published next to an audit record when the action affects another author.
+#[derive(Clone, Debug)]
+pub struct ModerationDecision {
+    pub moderator_id: u64,
+    /// The affected author, the target itself or the author of the target post or comment.
+    pub author_id: u64,
+    pub action: AuditAction,
+    pub target_type: AuditTarget,
+    pub target_id: u64,
+}
+
+#[derive(Default)]
+pub struct AuditQuery {
+    pub actor_id: Option<u64>,
+    pub action: Option<AuditAction>,
+    pub target_type: Option<AuditTarget>,
+    pub target_id: Option<u64>,
+}
+
+#[async_trait]
+pub trait AuditService: Send + Sync {
+    async fn records_count(&self, query: &AuditQuery) -> DResult<u64>;
+    async fn records(
+        &self,
+        query: &AuditQuery,
+        offset: &u64,
+        limit: &u64,
+    ) -> DResult<Vec<AuditRecord>>;
+}
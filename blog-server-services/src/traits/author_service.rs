@@ -1,12 +1,65 @@
 use std::collections::HashSet;
 
-use blog_generic::entities::{
-    Author as EAuthor, CommonMinimalAuthor as ECommonMinimalAuthor,
-    CommonSecondaryAuthor as ECommonSecondaryAuthor,
-};
+use blog_generic::entities::{Author as EAuthor, CommonMinimalAuthor as ECommonMinimalAuthor};
 use screw_components::dyn_result::DResult;
 use serde::{Deserialize, Serialize};
 
+use crate::traits::access_token_service::TokenScope;
+use crate::traits::audit_service::BaseAuditRecord;
+
+/// Ordered from the least to the most privileged.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorRole {
+    Reader,
+    #[default]
+    Contributor,
+    Author,
+    Editor,
+    Admin,
+}
+
+/// Named actions checked by handlers through `Author::can`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    EditOwnProfile,
+    CreateComments,
+    /// Create and edit own drafts.
+    WritePosts,
+    /// Publish own posts and edit them once published.
+    PublishPosts,
+    /// Read, edit, publish and delete posts of other authors.
+    ManageAnyPost,
+    RecommendPosts,
+    ModerateComments,
+    /// Block authors and manage their subscriptions.
+    ManageAuthors,
+    ViewAuditLog,
+    ManageRoles,
+}
+
+impl AuthorRole {
+    pub fn minimal_for(capability: Capability) -> AuthorRole {
+        match capability {
+            Capability::EditOwnProfile | Capability::CreateComments => AuthorRole::Reader,
+            Capability::WritePosts => AuthorRole::Contributor,
+            Capability::PublishPosts => AuthorRole::Author,
+            Capability::ManageAnyPost
+            | Capability::RecommendPosts
+            | Capability::ModerateComments
+            | Capability::ManageAuthors
+            | Capability::ViewAuditLog => AuthorRole::Editor,
+            Capability::ManageRoles => AuthorRole::Admin,
+        }
+    }
+
+    pub fn has(&self, capability: Capability) -> bool {
+        *self >= AuthorRole::minimal_for(capability)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct BaseAuthor {
@@ -20,12 +73,28 @@ pub struct BaseAuthor {
     pub registered_at: u64,
     pub status: Option<String>,
     pub image_url: Option<String>,
-    pub editor: u8,
+    #[serde(default)]
+    pub role: AuthorRole,
     pub blocked: u8,
     pub yandex_id: Option<u64>,
     pub telegram_id: Option<u64>,
     pub notification_subscribed: Option<u8>,
     pub override_social_data: u8,
+    /// Set for seeded credentials, see `Author::can`.
+    #[serde(default)]
+    pub must_change_password: u8,
+    /// Sanitized HTML.
+    pub bio: Option<String>,
+    /// Space separated URLs, see `Author::links`.
+    pub links: Option<String>,
+    pub cover_url: Option<String>,
+    pub location: Option<String>,
+    /// Whether `email` is shown to everyone, it's hidden by default.
+    #[serde(default)]
+    pub email_public: u8,
+    /// Whether `mobile` is shown to everyone, it's hidden by default.
+    #[serde(default)]
+    pub mobile_public: u8,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -54,16 +123,24 @@ pub struct BaseSecondaryAuthor {
     pub email: Option<String>,
     pub mobile: Option<String>,
     pub status: Option<String>,
+    pub bio: Option<String>,
+    pub links: Option<String>,
+    pub cover_url: Option<String>,
+    pub location: Option<String>,
+    pub email_public: u8,
+    pub mobile_public: u8,
 }
 
-impl From<ECommonSecondaryAuthor> for BaseSecondaryAuthor {
-    fn from(value: ECommonSecondaryAuthor) -> Self {
-        BaseSecondaryAuthor {
-            email: value.email,
-            mobile: value.mobile,
-            status: value.status,
-        }
-    }
+/// Profile fields the shared author entity has no place for, returned next to it.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorProfile {
+    pub bio: Option<String>,
+    pub links: Vec<String>,
+    pub location: Option<String>,
+    pub cover_url: Option<String>,
+    /// `cover_url` signed for the images processor.
+    pub processed_cover_url: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -72,10 +149,89 @@ pub struct Author {
     pub id: u64,
     #[serde(flatten)]
     pub base: BaseAuthor,
+    /// Scopes of the personal access token the request is authenticated with,
+    /// `None` for interactive sessions.
+    #[serde(skip)]
+    pub token_scopes: Option<Vec<TokenScope>>,
+}
+
+impl Author {
+    pub fn links(&self) -> Vec<String> {
+        self.base
+            .links
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|l| l.to_string())
+            .collect()
+    }
+
+    /// Empty for blocked authors, like the rest of their profile.
+    pub fn profile(&self) -> AuthorProfile {
+        use crate::utils::image_signer::{ImageVariant, signed_image_url};
+        if self.base.blocked != 0 {
+            return AuthorProfile::default();
+        }
+        AuthorProfile {
+            bio: self.base.bio.clone(),
+            links: self.links(),
+            location: self.base.location.clone(),
+            cover_url: self.base.cover_url.clone(),
+            processed_cover_url: self
+                .base
+                .cover_url
+                .as_deref()
+                .map(|u| signed_image_url(u, ImageVariant::Normal)),
+        }
+    }
+
+    /// Single authorization check for handlers; blocked authors can do nothing,
+    /// authors that must change their password can only edit own profile,
+    /// access tokens are limited to their scopes.
+    pub fn can(&self, capability: Capability) -> bool {
+        if self.base.must_change_password != 0 && capability != Capability::EditOwnProfile {
+            return false;
+        }
+        if let Some(token_scopes) = &self.token_scopes {
+            if !token_scopes.iter().any(|s| s.capability() == capability) {
+                return false;
+            }
+        }
+        self.base.blocked == 0 && self.base.role.has(capability)
+    }
+}
+
+/// Who an author entity is built for, decides which private fields it carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorView {
+    /// Anonymous callers and other authors: contacts only when marked public,
+    /// no notification settings.
+    Public,
+    /// The author themselves.
+    Owner,
+    /// Editors managing authors see contacts and settings whatever the flags are.
+    Editor,
+}
+
+impl AuthorView {
+    pub fn of(author_id: &u64, viewer: Option<&Author>) -> Self {
+        match viewer {
+            Some(viewer) if viewer.id == *author_id => AuthorView::Owner,
+            Some(viewer) if viewer.can(Capability::ManageAuthors) => AuthorView::Editor,
+            _ => AuthorView::Public,
+        }
+    }
 }
 
+/// The public view, see `Author::entity` for the others.
 impl Into<EAuthor> for Author {
     fn into(self) -> EAuthor {
+        self.entity(AuthorView::Public)
+    }
+}
+
+impl Author {
+    pub fn entity(self, view: AuthorView) -> EAuthor {
         use crate::utils::image_signer::{ImageVariant, processed_image_urls};
         let avatar: Vec<(&str, ImageVariant)> = self
             .base
@@ -86,26 +242,42 @@ impl Into<EAuthor> for Author {
             .into_iter()
             .collect();
         let processed_image_urls = processed_image_urls(&avatar, None);
+        let public = view == AuthorView::Public;
         EAuthor {
             id: self.id,
             slug: self.base.slug,
             first_name: self.base.first_name.filter(|_| self.base.blocked == 0),
             last_name: self.base.last_name.filter(|_| self.base.blocked == 0),
             middle_name: self.base.middle_name.filter(|_| self.base.blocked == 0),
-            mobile: self.base.mobile.filter(|_| self.base.blocked == 0),
-            email: self.base.email.filter(|_| self.base.blocked == 0),
+            mobile: self
+                .base
+                .mobile
+                .filter(|_| self.base.blocked == 0 && (!public || self.base.mobile_public == 1)),
+            email: self
+                .base
+                .email
+                .filter(|_| self.base.blocked == 0 && (!public || self.base.email_public == 1)),
             registered_at: self.base.registered_at,
             status: self.base.status.filter(|_| self.base.blocked == 0),
             image_url: self.base.image_url.filter(|_| self.base.blocked == 0),
             processed_image_urls,
-            editor: self.base.editor,
+            editor: (self.base.role >= AuthorRole::Editor) as u8,
             blocked: self.base.blocked,
-            notification_subscribed: self.base.notification_subscribed,
+            notification_subscribed: self.base.notification_subscribed.filter(|_| !public),
             override_social_data: self.base.override_social_data,
         }
     }
 }
 
+/// What happens to posts of a deleted author.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeletedAuthorPosts {
+    /// Posts are deleted together with their tags and comments.
+    Delete,
+    /// Posts are moved to the author with the id.
+    Reassign(u64),
+}
+
 #[async_trait]
 pub trait AuthorService: Send + Sync {
     async fn authors_count_by_query(&self, query: &String) -> DResult<u64>;
@@ -122,6 +294,7 @@ pub trait AuthorService: Send + Sync {
     async fn author_by_slug(&self, slug: &String) -> DResult<Option<Author>>;
     async fn author_by_yandex_id(&self, yandex_id: &u64) -> DResult<Option<Author>>;
     async fn author_by_telegram_id(&self, telegram_id: &u64) -> DResult<Option<Author>>;
+    async fn author_by_email(&self, email: &String) -> DResult<Option<Author>>;
     async fn set_author_override_social_data_by_id(
         &self,
         id: &u64,
@@ -132,6 +305,8 @@ pub trait AuthorService: Send + Sync {
         id: &u64,
         base_minimal_author: &BaseMinimalAuthor,
     ) -> DResult<u64>;
+    /// The slug suggested by the provider is normalized and gets a numeric suffix
+    /// when taken or reserved, see `utils::author_slug`. Same for the insert below.
     async fn update_minimal_social_author_by_id(
         &self,
         id: &u64,
@@ -145,11 +320,246 @@ pub trait AuthorService: Send + Sync {
         yandex_id: Option<&u64>,
         telegram_id: Option<&u64>,
     ) -> DResult<u64>;
+    async fn insert_password_author(
+        &self,
+        base_minimal_author: &BaseMinimalAuthor,
+        email: Option<&String>,
+        password_hash: &String,
+    ) -> DResult<u64>;
     async fn update_secondary_author_by_id(
         &self,
         id: &u64,
         base_secondary_author: &BaseSecondaryAuthor,
     ) -> DResult<u64>;
-    async fn set_author_blocked_by_id(&self, id: &u64, is_blocked: &u8) -> DResult<()>;
+    /// Also clears `must_change_password`.
+    async fn set_author_password_hash_by_id(&self, id: &u64, password_hash: &String)
+    -> DResult<()>;
+    /// `audit_record` is written in the same transaction, like with the other
+    /// moderation actions below.
+    async fn set_author_blocked_by_id(
+        &self,
+        id: &u64,
+        is_blocked: &u8,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()>;
+    async fn set_author_role_by_id(
+        &self,
+        id: &u64,
+        role: &AuthorRole,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()>;
     async fn set_author_subscription_by_id(&self, id: &u64, is_subscribed: &u8) -> DResult<()>;
+    async fn set_author_social_ids_by_id(
+        &self,
+        id: &u64,
+        yandex_id: Option<&u64>,
+        telegram_id: Option<&u64>,
+    ) -> DResult<()>;
+    /// Moves posts, comments, linked identities and missing social ids of `source_id`
+    /// to `target_id`, then blocks the source author and revokes its sessions and access tokens.
+    async fn merge_authors(
+        &self,
+        source_id: &u64,
+        target_id: &u64,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()>;
+    /// Removes personal data of the author keeping the row itself, as comments and
+    /// the audit log still reference it. The row is blocked and stripped down to a `deleted-{id}`
+    /// slug, comments stay in their threads with the content cleared, sessions, tokens,
+    /// identities, follows, notifications and the email subscription are deleted.
+    async fn delete_author(&self, id: &u64, posts: &DeletedAuthorPosts) -> DResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_author(role: AuthorRole, token_scopes: Option<Vec<TokenScope>>) -> Author {
+        Author {
+            id: 1,
+            base: BaseAuthor {
+                slug: "john".into(),
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                mobile: None,
+                email: None,
+                password_hash: None,
+                registered_at: 0,
+                status: None,
+                image_url: None,
+                role,
+                blocked: 0,
+                yandex_id: None,
+                telegram_id: None,
+                notification_subscribed: None,
+                override_social_data: 0,
+                must_change_password: 0,
+                bio: None,
+                links: None,
+                cover_url: None,
+                location: None,
+                email_public: 0,
+                mobile_public: 0,
+            },
+            token_scopes,
+        }
+    }
+
+    #[test]
+    fn access_tokens_are_limited_to_their_scopes() {
+        let author = sample_author(AuthorRole::Editor, Some(vec![TokenScope::CommentsWrite]));
+        assert!(author.can(Capability::CreateComments));
+        assert!(!author.can(Capability::EditOwnProfile));
+        assert!(!author.can(Capability::WritePosts));
+        assert!(!author.can(Capability::ModerateComments));
+
+        let author = sample_author(AuthorRole::Editor, Some(vec![]));
+        assert!(!author.can(Capability::EditOwnProfile));
+    }
+
+    #[test]
+    fn access_token_scopes_stay_within_the_role() {
+        let author = sample_author(
+            AuthorRole::Contributor,
+            Some(vec![TokenScope::PostsWrite, TokenScope::PostsManage]),
+        );
+        assert!(author.can(Capability::WritePosts));
+        assert!(!author.can(Capability::ManageAnyPost));
+    }
+
+    #[test]
+    fn sessions_have_every_capability_of_the_role() {
+        let author = sample_author(AuthorRole::Editor, None);
+        assert!(author.can(Capability::EditOwnProfile));
+        assert!(author.can(Capability::ModerateComments));
+        assert!(!author.can(Capability::ManageRoles));
+    }
+
+    const ALL_CAPABILITIES: [Capability; 10] = [
+        Capability::EditOwnProfile,
+        Capability::CreateComments,
+        Capability::WritePosts,
+        Capability::PublishPosts,
+        Capability::ManageAnyPost,
+        Capability::RecommendPosts,
+        Capability::ModerateComments,
+        Capability::ManageAuthors,
+        Capability::ViewAuditLog,
+        Capability::ManageRoles,
+    ];
+
+    #[test]
+    fn password_change_requirement_leaves_only_own_profile() {
+        let mut author = sample_author(AuthorRole::Admin, None);
+        author.base.must_change_password = 1;
+        for capability in ALL_CAPABILITIES {
+            assert_eq!(
+                author.can(capability),
+                capability == Capability::EditOwnProfile,
+                "{capability:?}"
+            );
+        }
+
+        author.base.must_change_password = 0;
+        for capability in ALL_CAPABILITIES {
+            assert!(author.can(capability), "{capability:?}");
+        }
+    }
+
+    #[test]
+    fn password_change_requirement_applies_to_access_tokens() {
+        let mut author = sample_author(
+            AuthorRole::Editor,
+            Some(vec![TokenScope::PostsWrite, TokenScope::CommentsWrite]),
+        );
+        author.base.must_change_password = 1;
+        assert!(!author.can(Capability::WritePosts));
+        assert!(!author.can(Capability::CreateComments));
+    }
+
+    fn author_with_contacts(email_public: u8, mobile_public: u8) -> Author {
+        let mut author = sample_author(AuthorRole::Contributor, None);
+        author.base.email = Some("john@example.com".into());
+        author.base.mobile = Some("+10000000000".into());
+        author.base.notification_subscribed = Some(1);
+        author.base.email_public = email_public;
+        author.base.mobile_public = mobile_public;
+        author
+    }
+
+    fn viewer(id: u64, role: AuthorRole) -> Author {
+        Author {
+            id,
+            ..sample_author(role, None)
+        }
+    }
+
+    #[test]
+    fn view_depends_on_the_viewer() {
+        assert_eq!(AuthorView::of(&1, None), AuthorView::Public);
+        assert_eq!(
+            AuthorView::of(&1, Some(&viewer(1, AuthorRole::Reader))),
+            AuthorView::Owner
+        );
+        assert_eq!(
+            AuthorView::of(&1, Some(&viewer(2, AuthorRole::Editor))),
+            AuthorView::Editor
+        );
+        assert_eq!(
+            AuthorView::of(&1, Some(&viewer(2, AuthorRole::Contributor))),
+            AuthorView::Public
+        );
+
+        let mut blocked_editor = viewer(2, AuthorRole::Editor);
+        blocked_editor.base.blocked = 1;
+        assert_eq!(
+            AuthorView::of(&1, Some(&blocked_editor)),
+            AuthorView::Public
+        );
+
+        let token_editor = Author {
+            token_scopes: Some(vec![TokenScope::CommentsWrite]),
+            ..viewer(2, AuthorRole::Editor)
+        };
+        assert_eq!(AuthorView::of(&1, Some(&token_editor)), AuthorView::Public);
+    }
+
+    #[test]
+    fn public_view_drops_private_contacts() {
+        let entity = author_with_contacts(0, 0).entity(AuthorView::Public);
+        assert_eq!(entity.email, None);
+        assert_eq!(entity.mobile, None);
+        assert_eq!(entity.notification_subscribed, None);
+
+        let entity = author_with_contacts(1, 0).entity(AuthorView::Public);
+        assert_eq!(entity.email.as_deref(), Some("john@example.com"));
+        assert_eq!(entity.mobile, None);
+
+        let entity = author_with_contacts(0, 1).entity(AuthorView::Public);
+        assert_eq!(entity.email, None);
+        assert_eq!(entity.mobile.as_deref(), Some("+10000000000"));
+
+        let entity: EAuthor = author_with_contacts(0, 0).into();
+        assert_eq!(entity.email, None);
+    }
+
+    #[test]
+    fn owner_and_editor_views_keep_contacts() {
+        for view in [AuthorView::Owner, AuthorView::Editor] {
+            let entity = author_with_contacts(0, 0).entity(view);
+            assert_eq!(entity.email.as_deref(), Some("john@example.com"));
+            assert_eq!(entity.mobile.as_deref(), Some("+10000000000"));
+            assert_eq!(entity.notification_subscribed, Some(1));
+        }
+    }
+
+    #[test]
+    fn blocked_authors_show_no_contacts() {
+        let mut author = author_with_contacts(1, 1);
+        author.base.blocked = 1;
+        let entity = author.entity(AuthorView::Editor);
+        assert_eq!(entity.email, None);
+        assert_eq!(entity.mobile, None);
+    }
 }
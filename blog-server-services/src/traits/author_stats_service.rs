@@ -0,0 +1,34 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PostsCounts {
+    pub published: u64,
+    pub unpublished: u64,
+    pub hidden: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct MonthlyCount {
+    /// `YYYY-MM`.
+    pub month: String,
+    pub count: u64,
+}
+
+/// Views and reactions aren't tracked, so they aren't part of the stats.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AuthorStats {
+    pub posts: PostsCounts,
+    /// Published comments of other authors under posts of the author.
+    pub comments_received: u64,
+    /// Posts published in each of the last twelve months, oldest first, months without posts included.
+    pub published_by_month: Vec<MonthlyCount>,
+}
+
+#[async_trait]
+pub trait AuthorStatsService: Send + Sync {
+    async fn author_stats(&self, author_id: &u64) -> DResult<AuthorStats>;
+}
@@ -1,7 +1,10 @@
 use blog_generic::entities::CommonComment as ECommonComment;
 use screw_components::dyn_result::DResult;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
+use super::audit_service::BaseAuditRecord;
+use crate::utils::cursor::Cursor;
 use crate::utils::*;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -34,6 +37,34 @@ impl From<(u64, ECommonComment)> for BaseComment {
     }
 }
 
+/// Published once a new comment is stored.
+#[derive(Clone, Debug)]
+pub struct CommentPublished {
+    pub comment_id: u64,
+    pub post_id: u64,
+    pub author_id: u64,
+}
+
+impl CommentPublished {
+    /// Comments aren't threaded, so earlier commenters count as replied to,
+    /// except the new comment's author and the post author notified separately.
+    pub fn reply_recipient_ids(
+        &self,
+        earlier_commenter_ids: Vec<u64>,
+        post_author_id: u64,
+    ) -> HashSet<u64> {
+        earlier_commenter_ids
+            .into_iter()
+            .filter(|id| *id != self.author_id && *id != post_author_id)
+            .collect()
+    }
+}
+
+pub struct CommentsCursorAnswer {
+    pub comments: Vec<Comment>,
+    pub next_cursor: Option<Cursor>,
+}
+
 #[async_trait]
 pub trait CommentService: Send + Sync {
     async fn comments_count_by_post_id(&self, post_id: &u64) -> DResult<u64>;
@@ -43,8 +74,55 @@ pub trait CommentService: Send + Sync {
         offset: &u64,
         limit: &u64,
     ) -> DResult<Vec<Comment>>;
+    async fn comments_by_post_id_after_cursor(
+        &self,
+        post_id: &u64,
+        cursor: Option<&Cursor>,
+        limit: &u64,
+    ) -> DResult<CommentsCursorAnswer>;
+    /// Every comment of the author, deleted ones included, oldest first.
+    async fn comments_by_author_id(&self, author_id: &u64) -> DResult<Vec<Comment>>;
+    /// Distinct authors of published comments under the post before `comment_id`,
+    /// the `limit` most recent ones.
+    async fn commenter_ids_before(
+        &self,
+        post_id: &u64,
+        comment_id: &u64,
+        limit: &u64,
+    ) -> DResult<Vec<u64>>;
     async fn create_comment(&self, post: &BaseComment) -> DResult<u64>;
     async fn comment_by_id(&self, id: &u64) -> DResult<Option<Comment>>;
-    async fn mark_deleted_by_id(&self, id: &u64) -> DResult<()>;
+    /// `audit_record` is written in the same transaction when a moderator deletes
+    /// a comment of someone else.
+    async fn mark_deleted_by_id(
+        &self,
+        id: &u64,
+        audit_record: Option<&BaseAuditRecord>,
+    ) -> DResult<()>;
     async fn delete_by_post_id(&self, post_id: &u64) -> DResult<()>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn published(author_id: u64) -> CommentPublished {
+        CommentPublished {
+            comment_id: 10,
+            post_id: 1,
+            author_id,
+        }
+    }
+
+    #[test]
+    fn replies_skip_the_commenter_and_the_post_author() {
+        let recipients = published(2).reply_recipient_ids(vec![2, 3, 4, 5], 3);
+        assert_eq!(recipients, HashSet::from([4, 5]));
+    }
+
+    #[test]
+    fn first_comment_notifies_nobody_of_a_reply() {
+        assert!(published(2).reply_recipient_ids(vec![], 3).is_empty());
+        assert!(published(2).reply_recipient_ids(vec![2, 3], 3).is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+use crate::traits::notification_preference_service::DigestFrequency;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BaseEmailSubscription {
+    pub email: String,
+    /// Daily or weekly, digests aren't sent immediately.
+    pub frequency: DigestFrequency,
+    pub confirmed: u8,
+    pub created_at: u64,
+    pub confirmed_at: Option<u64>,
+    /// When the last digest was sent, posts published after it go into the next one.
+    pub last_sent_at: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct EmailSubscription {
+    pub id: u64,
+    #[serde(flatten)]
+    pub base: BaseEmailSubscription,
+}
+
+#[async_trait]
+pub trait EmailSubscriptionService: Send + Sync {
+    /// Creates an unconfirmed subscription, or updates the frequency of an unconfirmed one.
+    /// A confirmed subscription is returned as is.
+    async fn create_email_subscription(
+        &self,
+        email: &String,
+        frequency: &DigestFrequency,
+    ) -> DResult<EmailSubscription>;
+    async fn email_subscription_by_id(&self, id: &u64) -> DResult<Option<EmailSubscription>>;
+    async fn email_subscription_by_email(
+        &self,
+        email: &String,
+    ) -> DResult<Option<EmailSubscription>>;
+    /// Digests start from the moment of confirmation.
+    async fn confirm_email_subscription_by_id(&self, id: &u64) -> DResult<()>;
+    async fn delete_email_subscription_by_id(&self, id: &u64) -> DResult<()>;
+    /// Confirmed subscriptions with the frequency that haven't been sent anything since
+    /// `sent_before`, marked as sent at `sent_at` in the same statement, so concurrent
+    /// jobs never send one digest twice. `last_sent_at` of the returned ones is the
+    /// previous value.
+    async fn claim_due_email_subscriptions(
+        &self,
+        frequency: &DigestFrequency,
+        sent_before: &u64,
+        sent_at: &u64,
+    ) -> DResult<Vec<EmailSubscription>>;
+}
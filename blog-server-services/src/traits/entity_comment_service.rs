@@ -1,9 +1,15 @@
 use blog_generic::entities::Comment as EComment;
 use screw_components::dyn_result::DResult;
 
+use super::author_service::Author;
 use super::comment_service::*;
 
 #[async_trait]
 pub trait EntityCommentService: Send + Sync {
-    async fn comments_entities(&self, comments: Vec<Comment>) -> DResult<Vec<EComment>>;
+    /// Authors are built for `viewer`, see `AuthorView`.
+    async fn comments_entities(
+        &self,
+        comments: Vec<Comment>,
+        viewer: Option<&Author>,
+    ) -> DResult<Vec<EComment>>;
 }
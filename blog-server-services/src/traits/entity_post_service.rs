@@ -1,9 +1,15 @@
 use blog_generic::entities::Post as EPost;
 use screw_components::dyn_result::DResult;
 
+use super::author_service::Author;
 use super::post_service::*;
 
 #[async_trait]
 pub trait EntityPostService: Send + Sync {
-    async fn posts_entities(&self, posts: Vec<Post>) -> DResult<Vec<EPost>>;
+    /// Authors are built for `viewer`, see `AuthorView`.
+    async fn posts_entities(
+        &self,
+        posts: Vec<Post>,
+        viewer: Option<&Author>,
+    ) -> DResult<Vec<EPost>>;
 }
@@ -0,0 +1,24 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct FollowCounts {
+    /// Authors following this one.
+    pub followers: u64,
+    /// Authors this one follows, followed tags aren't counted.
+    pub following: u64,
+}
+
+#[async_trait]
+pub trait FollowService: Send + Sync {
+    /// Following twice is a no-op, as is unfollowing something not followed.
+    async fn follow_author(&self, follower_id: &u64, author_id: &u64) -> DResult<()>;
+    async fn unfollow_author(&self, follower_id: &u64, author_id: &u64) -> DResult<()>;
+    async fn follow_tag(&self, follower_id: &u64, tag_id: &u64) -> DResult<()>;
+    async fn unfollow_tag(&self, follower_id: &u64, tag_id: &u64) -> DResult<()>;
+    async fn followed_author_ids(&self, follower_id: &u64) -> DResult<Vec<u64>>;
+    async fn followed_tag_ids(&self, follower_id: &u64) -> DResult<Vec<u64>>;
+    async fn follower_ids(&self, author_id: &u64) -> DResult<Vec<u64>>;
+    async fn follow_counts(&self, author_id: &u64) -> DResult<FollowCounts>;
+}
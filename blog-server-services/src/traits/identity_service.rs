@@ -0,0 +1,34 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BaseAuthorIdentity {
+    pub author_id: u64,
+    /// `yandex`, `telegram` or a configured OpenID Connect provider name.
+    pub provider: String,
+    /// Account id at the provider, unique per provider.
+    pub subject: String,
+    pub created_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AuthorIdentity {
+    pub id: u64,
+    #[serde(flatten)]
+    pub base: BaseAuthorIdentity,
+}
+
+#[async_trait]
+pub trait IdentityService: Send + Sync {
+    async fn identity_by_provider_and_subject(
+        &self,
+        provider: &String,
+        subject: &String,
+    ) -> DResult<Option<AuthorIdentity>>;
+    async fn identities_by_author_id(&self, author_id: &u64) -> DResult<Vec<AuthorIdentity>>;
+    /// Does nothing if the identity is already linked to some author.
+    async fn create_identity(&self, identity: &BaseAuthorIdentity) -> DResult<()>;
+    async fn delete_identity(&self, id: &u64) -> DResult<()>;
+}
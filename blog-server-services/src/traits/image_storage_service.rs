@@ -0,0 +1,30 @@
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+
+use crate::utils::image_upload::UploadFormat;
+
+/// Keeps uploaded images on the local disk, served by the blog under `/uploads`.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct LocalImageStorageConfig {
+    /// Directory of the files, `uploads` when not set.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Base URL of the files when a proxy or CDN serves them, `{SITE_URL}/uploads` when not set.
+    #[serde(default)]
+    pub public_url: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StoredImage {
+    pub data: Vec<u8>,
+    pub format: UploadFormat,
+}
+
+#[async_trait]
+pub trait ImageStorageService: Send + Sync {
+    /// Stores an image already checked by `image_upload::sanitize` and returns its public,
+    /// absolute URL, suitable for `image_signer::signed_image_url`.
+    async fn store_image(&self, data: &[u8], format: UploadFormat) -> DResult<String>;
+    /// Image stored under `name`, for storages served by the blog itself.
+    async fn image_by_name(&self, name: &String) -> DResult<Option<StoredImage>>;
+}
@@ -0,0 +1,48 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LoginFailures {
+    /// Failures in a row since the last successful login.
+    pub failed_count: u64,
+    pub last_failed_at: u64,
+}
+
+/// Failed login tracking keyed by author and client address, so hammering
+/// an account from one address doesn't lock its owner out everywhere.
+/// Callers may keep an author-wide record under a placeholder address.
+#[async_trait]
+pub trait LoginAttemptService: Send + Sync {
+    /// Ignores failures that happened before `since`.
+    async fn failures(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        since: &u64,
+    ) -> DResult<Option<LoginFailures>>;
+    /// Counts one more failure, but only if the stored failures are still `current`
+    /// (the default when there were none since `reset_before`), so concurrent attempts
+    /// can't all pass the same check. Returns whether the failure was counted.
+    async fn register_failure(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        current: &LoginFailures,
+        failed_at: &u64,
+        reset_before: &u64,
+    ) -> DResult<bool>;
+    async fn clear_failures(&self, author_id: &u64, ip: &String) -> DResult<()>;
+    /// Remembers the address the author has signed in from.
+    async fn register_success(
+        &self,
+        author_id: &u64,
+        ip: &String,
+        succeeded_at: &u64,
+    ) -> DResult<()>;
+    /// Whether the author has signed in from the address since `since`.
+    async fn is_known_address(&self, author_id: &u64, ip: &String, since: &u64) -> DResult<bool>;
+    /// Drops failures older than `failed_before` and addresses not signed in from
+    /// since `succeeded_before`.
+    async fn delete_stale(&self, failed_before: &u64, succeeded_before: &u64) -> DResult<()>;
+}
@@ -0,0 +1,48 @@
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+
+#[derive(Clone, Debug)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    /// Plain text, always sent.
+    pub body: String,
+    /// Sent as an alternative to `body` when set.
+    pub html: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Plain connection, meant for local test servers.
+    #[default]
+    None,
+    StartTls,
+    Tls,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    pub from: String,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+}
+
+/// Writes mails to a file instead of sending them, or to stdout without a path.
+/// Meant for development and tests.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct FileMailConfig {
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+#[async_trait]
+pub trait MailService: Send + Sync {
+    async fn send(&self, mail: Mail) -> DResult<()>;
+}
@@ -1,9 +1,24 @@
+pub mod access_token_service;
+pub mod audit_service;
 pub mod author_service;
+pub mod author_stats_service;
 pub mod comment_service;
+pub mod email_subscription_service;
 pub mod entity_comment_service;
 pub mod entity_post_service;
+pub mod follow_service;
+pub mod identity_service;
+pub mod image_storage_service;
+pub mod login_attempt_service;
+pub mod mail_service;
+pub mod notification_preference_service;
+pub mod notification_service;
+pub mod oidc_service;
 pub mod post_service;
+pub mod request_throttle_service;
+pub mod session_service;
 pub mod social_service;
+pub mod two_factor_service;
 
 #[async_trait]
 pub trait Publish<E>: Send + Sync {
@@ -0,0 +1,263 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationChannel {
+    Telegram,
+    Email,
+    InApp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// Any published post, what Telegram subscribers got before preferences existed.
+    NewPost,
+    /// A post published by an author the recipient follows.
+    FollowedAuthorPost,
+    /// A comment left after the recipient's one under the same post.
+    CommentReply,
+    /// A comment under a post of the recipient.
+    PostComment,
+    /// A moderator acted on the recipient, one of their posts or comments.
+    ModerationDecision,
+}
+
+impl NotificationEvent {
+    pub const ALL: [NotificationEvent; 5] = [
+        NotificationEvent::NewPost,
+        NotificationEvent::FollowedAuthorPost,
+        NotificationEvent::CommentReply,
+        NotificationEvent::PostComment,
+        NotificationEvent::ModerationDecision,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationEvent::NewPost => "new_post",
+            NotificationEvent::FollowedAuthorPost => "followed_author_post",
+            NotificationEvent::CommentReply => "comment_reply",
+            NotificationEvent::PostComment => "post_comment",
+            NotificationEvent::ModerationDecision => "moderation_decision",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        NotificationEvent::ALL
+            .into_iter()
+            .find(|e| e.as_str() == value)
+    }
+
+    /// Space separated, as stored in the `*_events` columns.
+    pub fn join(events: &[NotificationEvent]) -> String {
+        events
+            .iter()
+            .map(|e| e.as_str())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    pub fn split(events: &str) -> Vec<NotificationEvent> {
+        events
+            .split_whitespace()
+            .filter_map(NotificationEvent::parse)
+            .collect()
+    }
+}
+
+/// How often post digests are mailed, to an email subscription or to an author who
+/// chose email notifications. Other channels always deliver immediately.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFrequency {
+    #[default]
+    Immediate,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigestFrequency::Immediate => "immediate",
+            DigestFrequency::Daily => "daily",
+            DigestFrequency::Weekly => "weekly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            DigestFrequency::Immediate,
+            DigestFrequency::Daily,
+            DigestFrequency::Weekly,
+        ]
+        .into_iter()
+        .find(|d| d.as_str() == value)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct NotificationPreferences {
+    pub author_id: u64,
+    pub telegram_events: String,
+    pub email_events: String,
+    pub in_app_events: String,
+    pub digest: String,
+}
+
+impl NotificationPreferences {
+    /// Used until the author saves their own preferences. Telegram keeps notifying
+    /// about every new post as it did before preferences existed, email stays off.
+    pub fn default_for(author_id: u64) -> Self {
+        NotificationPreferences {
+            author_id,
+            telegram_events: NotificationEvent::join(&[NotificationEvent::NewPost]),
+            email_events: String::new(),
+            in_app_events: NotificationEvent::join(&[
+                NotificationEvent::FollowedAuthorPost,
+                NotificationEvent::CommentReply,
+                NotificationEvent::PostComment,
+                NotificationEvent::ModerationDecision,
+            ]),
+            digest: DigestFrequency::Immediate.as_str().to_string(),
+        }
+    }
+
+    pub fn events(&self, channel: NotificationChannel) -> Vec<NotificationEvent> {
+        NotificationEvent::split(match channel {
+            NotificationChannel::Telegram => &self.telegram_events,
+            NotificationChannel::Email => &self.email_events,
+            NotificationChannel::InApp => &self.in_app_events,
+        })
+    }
+
+    pub fn wants(&self, channel: NotificationChannel, event: NotificationEvent) -> bool {
+        self.events(channel).contains(&event)
+    }
+
+    pub fn digest_frequency(&self) -> DigestFrequency {
+        DigestFrequency::parse(&self.digest).unwrap_or_default()
+    }
+
+    /// Whether a new post is delivered, either every one or only those of followed authors.
+    pub fn wants_post(&self, channel: NotificationChannel, follows_author: bool) -> bool {
+        self.wants(channel, NotificationEvent::NewPost)
+            || (follows_author && self.wants(channel, NotificationEvent::FollowedAuthorPost))
+    }
+}
+
+/// An author whose email digest is due, with the time the previous one was sent.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DueEmailDigest {
+    pub author_id: u64,
+    pub email_events: String,
+    pub digest_sent_at: Option<u64>,
+}
+
+impl DueEmailDigest {
+    pub fn events(&self) -> Vec<NotificationEvent> {
+        NotificationEvent::split(&self.email_events)
+    }
+}
+
+#[async_trait]
+pub trait NotificationPreferenceService: Send + Sync {
+    /// Defaults are returned for an author without saved preferences.
+    async fn notification_preferences_by_author_id(
+        &self,
+        author_id: &u64,
+    ) -> DResult<NotificationPreferences>;
+    /// One entry for each of `author_ids`, defaults filled in the same way.
+    async fn notification_preferences_by_author_ids(
+        &self,
+        author_ids: &HashSet<u64>,
+    ) -> DResult<Vec<NotificationPreferences>>;
+    async fn set_notification_preferences(
+        &self,
+        preferences: &NotificationPreferences,
+    ) -> DResult<()>;
+    /// Marks the digests of authors with any email event and the given frequency,
+    /// not sent since `sent_before`, as sent at `sent_at`. Rows claimed by a concurrent
+    /// call are skipped, so each digest goes out once.
+    async fn claim_due_email_digests(
+        &self,
+        frequency: &DigestFrequency,
+        sent_before: &u64,
+        sent_at: &u64,
+    ) -> DResult<Vec<DueEmailDigest>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preferences(telegram: &[NotificationEvent]) -> NotificationPreferences {
+        NotificationPreferences {
+            telegram_events: NotificationEvent::join(telegram),
+            ..NotificationPreferences::default_for(1)
+        }
+    }
+
+    #[test]
+    fn defaults_keep_every_post_on_telegram() {
+        let defaults = NotificationPreferences::default_for(1);
+        assert!(defaults.wants_post(NotificationChannel::Telegram, false));
+        assert!(defaults.wants_post(NotificationChannel::Telegram, true));
+        assert!(!defaults.wants(NotificationChannel::InApp, NotificationEvent::NewPost));
+        assert!(defaults.wants(
+            NotificationChannel::InApp,
+            NotificationEvent::FollowedAuthorPost
+        ));
+    }
+
+    #[test]
+    fn followed_author_posts_need_a_follow() {
+        let preferences = preferences(&[NotificationEvent::FollowedAuthorPost]);
+        assert!(preferences.wants_post(NotificationChannel::Telegram, true));
+        assert!(!preferences.wants_post(NotificationChannel::Telegram, false));
+    }
+
+    #[test]
+    fn posts_can_be_turned_off() {
+        let preferences = preferences(&[NotificationEvent::CommentReply]);
+        assert!(!preferences.wants_post(NotificationChannel::Telegram, true));
+        assert!(!preferences.wants_post(NotificationChannel::Telegram, false));
+    }
+
+    #[test]
+    fn email_is_off_by_default() {
+        let defaults = NotificationPreferences::default_for(1);
+        assert!(defaults.events(NotificationChannel::Email).is_empty());
+        assert_eq!(defaults.digest_frequency(), DigestFrequency::Immediate);
+    }
+
+    #[test]
+    fn digest_frequency_falls_back_to_immediate() {
+        let preferences = NotificationPreferences {
+            email_events: NotificationEvent::join(&[NotificationEvent::NewPost]),
+            digest: "weekly".to_string(),
+            ..NotificationPreferences::default_for(1)
+        };
+        assert!(preferences.wants_post(NotificationChannel::Email, false));
+        assert_eq!(preferences.digest_frequency(), DigestFrequency::Weekly);
+        let preferences = NotificationPreferences {
+            digest: "hourly".to_string(),
+            ..preferences
+        };
+        assert_eq!(preferences.digest_frequency(), DigestFrequency::Immediate);
+    }
+
+    #[test]
+    fn events_round_trip_through_columns() {
+        let joined = NotificationEvent::join(&NotificationEvent::ALL);
+        assert_eq!(NotificationEvent::split(&joined), NotificationEvent::ALL);
+        assert_eq!(
+            NotificationEvent::split("new_post unknown comment_reply"),
+            vec![NotificationEvent::NewPost, NotificationEvent::CommentReply]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::traits::audit_service::AuditAction;
+use crate::traits::notification_preference_service::NotificationEvent;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BaseNotification {
+    /// The recipient.
+    pub author_id: u64,
+    pub event: NotificationEvent,
+    /// The author who caused the notification.
+    pub actor_id: u64,
+    /// Set for moderation decisions only.
+    pub action: Option<AuditAction>,
+    /// Site path of the related post, if any.
+    pub link: Option<String>,
+    pub created_at: u64,
+    pub read: u8,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Notification {
+    pub id: u64,
+    #[serde(flatten)]
+    pub base: BaseNotification,
+}
+
+#[async_trait]
+pub trait NotificationService: Send + Sync {
+    async fn create_notifications(&self, notifications: &[BaseNotification]) -> DResult<()>;
+    async fn notifications_count_by_author_id(&self, author_id: &u64) -> DResult<u64>;
+    async fn unread_notifications_count_by_author_id(&self, author_id: &u64) -> DResult<u64>;
+    /// Newest first.
+    async fn notifications_by_author_id(
+        &self,
+        author_id: &u64,
+        offset: &u64,
+        limit: &u64,
+    ) -> DResult<Vec<Notification>>;
+    /// Ids of other authors' notifications are ignored. Returns how many were marked.
+    async fn mark_notifications_read(&self, author_id: &u64, ids: &HashSet<u64>) -> DResult<u64>;
+    async fn mark_all_notifications_read(&self, author_id: &u64) -> DResult<u64>;
+}
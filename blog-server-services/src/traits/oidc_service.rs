@@ -0,0 +1,102 @@
+use jsonwebtoken::Algorithm;
+use screw_components::dyn_result::DResult;
+use serde::Deserialize;
+
+fn default_scopes() -> Vec<String> {
+    vec!["openid".to_string(), "profile".to_string()]
+}
+
+fn default_algorithms() -> Vec<Algorithm> {
+    vec![Algorithm::RS256]
+}
+
+/// Names of the claims (or userinfo fields) author data is taken from.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct OidcClaimsConfig {
+    pub subject: String,
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub picture: String,
+}
+
+impl Default for OidcClaimsConfig {
+    fn default() -> Self {
+        OidcClaimsConfig {
+            subject: "sub".to_string(),
+            username: "preferred_username".to_string(),
+            first_name: "given_name".to_string(),
+            last_name: "family_name".to_string(),
+            picture: "picture".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OidcProviderConfig {
+    /// Shown on the login button, the provider name is used if missing.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// OpenID Connect issuer, endpoints and signing keys are discovered from it
+    /// and an ID token is required.
+    #[serde(default)]
+    pub issuer: Option<String>,
+    /// Plain OAuth2 providers without discovery (like GitHub) list endpoints
+    /// explicitly, the identity is then taken from the userinfo endpoint.
+    #[serde(default)]
+    pub authorization_endpoint: Option<String>,
+    #[serde(default)]
+    pub token_endpoint: Option<String>,
+    #[serde(default)]
+    pub userinfo_endpoint: Option<String>,
+    pub client_id: String,
+    /// Not needed for public clients, PKCE is used either way.
+    #[serde(default)]
+    pub client_secret: Option<String>,
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<String>,
+    /// ID token signature algorithms the provider uses, tokens signed otherwise
+    /// are rejected. Symmetric ones are never accepted.
+    #[serde(default = "default_algorithms")]
+    pub algorithms: Vec<Algorithm>,
+    /// Appended to slugs of authors created by this provider, like `y` for Yandex.
+    pub slug_suffix: String,
+    #[serde(default)]
+    pub claims: OidcClaimsConfig,
+}
+
+#[derive(Clone, Debug)]
+pub struct OidcUserInfo {
+    pub subject: String,
+    pub username: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub picture: Option<String>,
+}
+
+#[async_trait]
+pub trait OidcService: Send + Sync {
+    /// Configured providers as `(name, title)` pairs.
+    fn providers(&self) -> Vec<(String, String)>;
+    fn provider(&self, name: &String) -> Option<OidcProviderConfig>;
+    /// Authorization code request with the S256 PKCE challenge of `code_verifier`.
+    async fn authorization_url(
+        &self,
+        name: &String,
+        redirect_uri: &String,
+        state: &String,
+        nonce: &String,
+        code_verifier: &String,
+    ) -> DResult<String>;
+    /// Exchanges the code, validates the ID token (signature, issuer, audience,
+    /// expiry and `nonce`) and returns the identity.
+    async fn user_info(
+        &self,
+        name: &String,
+        redirect_uri: &String,
+        code: &String,
+        code_verifier: &String,
+        nonce: &String,
+    ) -> DResult<OidcUserInfo>;
+}
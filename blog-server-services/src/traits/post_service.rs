@@ -6,6 +6,8 @@ use blog_generic::entities::{CommonPost as ECommonPost, PublishType};
 use screw_components::dyn_result::DResult;
 use serde::{Deserialize, Serialize};
 
+use super::audit_service::BaseAuditRecord;
+use crate::utils::cursor::Cursor;
 use crate::utils::*;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -94,24 +96,92 @@ pub struct Post {
     pub base: BasePost,
 }
 
+pub enum PostsPagination<'o> {
+    Offset(&'o u64),
+    Cursor(Option<Cursor>),
+}
+
+/// Listing orders accepted from clients; only these ever reach the SQL builder.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PostsSort {
+    Newest,
+    Oldest,
+    RecentlyUpdated,
+    MostCommented,
+    /// Falls back to the default order when there is no search query.
+    Relevance,
+}
+
+impl PostsSort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "newest" => Some(PostsSort::Newest),
+            "oldest" => Some(PostsSort::Oldest),
+            "recently_updated" => Some(PostsSort::RecentlyUpdated),
+            "most_commented" => Some(PostsSort::MostCommented),
+            "relevance" => Some(PostsSort::Relevance),
+            _ => None,
+        }
+    }
+}
+
+/// How `PostsQuery::tag_ids` are matched against post tags.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum TagsMatch {
+    #[default]
+    Any,
+    All,
+}
+
 pub struct PostsQuery<'q, 'a, 't, 'p, 'o, 'l> {
     pub search_query: Option<&'q String>,
-    pub author_id: Option<&'a u64>,
-    pub tag_id: Option<&'t u64>,
+    pub author_ids: &'a [u64],
+    pub tag_ids: &'t [u64],
+    pub tags_match: TagsMatch,
+    pub exclude_tag_ids: &'t [u64],
+    /// Inclusive lower bound of `created_at`, in seconds.
+    pub created_from: Option<u64>,
+    /// Exclusive upper bound of `created_at`, in seconds.
+    pub created_to: Option<u64>,
+    /// Only posts of authors or with tags followed by this author.
+    pub followed_by: Option<u64>,
     pub publish_type: Option<&'p PublishType>,
-    pub offset: &'o u64,
+    pub pagination: PostsPagination<'o>,
     pub limit: &'l u64,
+    pub sort: Option<PostsSort>,
 }
 
 impl<'q, 'a, 't, 'p, 'o, 'l> PostsQuery<'q, 'a, 't, 'p, 'o, 'l> {
     pub fn offset_and_limit(offset: &'o u64, limit: &'l u64) -> Self {
         Self {
             search_query: None,
-            author_id: None,
-            tag_id: None,
+            author_ids: &[],
+            tag_ids: &[],
+            tags_match: TagsMatch::Any,
+            exclude_tag_ids: &[],
+            created_from: None,
+            created_to: None,
+            followed_by: None,
+            publish_type: None,
+            pagination: PostsPagination::Offset(offset),
+            limit,
+            sort: None,
+        }
+    }
+    pub fn cursor_and_limit(cursor: Option<Cursor>, limit: &'l u64) -> Self {
+        Self {
+            search_query: None,
+            author_ids: &[],
+            tag_ids: &[],
+            tags_match: TagsMatch::Any,
+            exclude_tag_ids: &[],
+            created_from: None,
+            created_to: None,
+            followed_by: None,
             publish_type: None,
-            offset,
+            pagination: PostsPagination::Cursor(cursor),
             limit,
+            sort: None,
         }
     }
     pub fn search_query(mut self, search_query: Option<&'q String>) -> Self {
@@ -119,22 +189,50 @@ impl<'q, 'a, 't, 'p, 'o, 'l> PostsQuery<'q, 'a, 't, 'p, 'o, 'l> {
         self
     }
     pub fn author_id(mut self, author_id: Option<&'a u64>) -> Self {
-        self.author_id = author_id;
+        self.author_ids = author_id.map(std::slice::from_ref).unwrap_or_default();
+        self
+    }
+    pub fn author_ids(mut self, author_ids: &'a [u64]) -> Self {
+        self.author_ids = author_ids;
         self
     }
     pub fn tag_id(mut self, tag_id: Option<&'t u64>) -> Self {
-        self.tag_id = tag_id;
+        self.tag_ids = tag_id.map(std::slice::from_ref).unwrap_or_default();
+        self
+    }
+    pub fn tag_ids(mut self, tag_ids: &'t [u64], tags_match: TagsMatch) -> Self {
+        self.tag_ids = tag_ids;
+        self.tags_match = tags_match;
+        self
+    }
+    pub fn exclude_tag_ids(mut self, exclude_tag_ids: &'t [u64]) -> Self {
+        self.exclude_tag_ids = exclude_tag_ids;
+        self
+    }
+    pub fn created_between(mut self, from: Option<u64>, to: Option<u64>) -> Self {
+        self.created_from = from;
+        self.created_to = to;
+        self
+    }
+    pub fn followed_by(mut self, follower_id: Option<u64>) -> Self {
+        self.followed_by = follower_id;
         self
     }
     pub fn publish_type(mut self, publish_type: Option<&'p PublishType>) -> Self {
         self.publish_type = publish_type;
         self
     }
+    pub fn sort(mut self, sort: Option<PostsSort>) -> Self {
+        self.sort = sort;
+        self
+    }
 }
 
 pub struct PostsQueryAnswer {
-    pub total_count: u64,
+    /// Not counted for cursor pagination.
+    pub total_count: Option<u64>,
     pub posts: Vec<Post>,
+    pub next_cursor: Option<Cursor>,
 }
 
 #[async_trait]
@@ -146,16 +244,24 @@ pub trait PostService: Send + Sync {
 
     async fn post_by_id(&self, id: &u64) -> DResult<Option<Post>>;
     async fn create_post(&self, post: &BasePost) -> DResult<u64>;
+    /// `audit_record` is written in the same transaction when an editor changes
+    /// a post of someone else.
     async fn update_post_by_id(
         &self,
         id: &u64,
         post: &BasePost,
         update_created_at: &bool,
+        audit_record: Option<&BaseAuditRecord>,
     ) -> DResult<()>;
     async fn delete_post_by_id(&self, id: &u64) -> DResult<()>;
 
     async fn random_recommended_post(&self, post_id: &u64) -> DResult<Option<Post>>;
-    async fn set_post_recommended_by_id(&self, id: &u64, recommended: &u8) -> DResult<()>;
+    async fn set_post_recommended_by_id(
+        &self,
+        id: &u64,
+        recommended: &u8,
+        audit_record: &BaseAuditRecord,
+    ) -> DResult<()>;
 
     async fn tag_by_id(&self, id: &u64) -> DResult<Option<Tag>>;
     async fn create_tags(&self, tag_titles: Vec<String>) -> DResult<Vec<Tag>>;
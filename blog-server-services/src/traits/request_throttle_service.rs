@@ -0,0 +1,20 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RequestWindow {
+    /// Requests counted in the window, the latest one included.
+    pub request_count: u64,
+    pub started_at: u64,
+}
+
+/// Fixed window request counters for endpoints that can be abused without
+/// logging in, keyed by whatever they are limited by, like an address or an email.
+#[async_trait]
+pub trait RequestThrottleService: Send + Sync {
+    /// Counts one more request under `key` in a single step, so concurrent requests
+    /// are all counted. A window started before `window_start` is restarted at `now`.
+    /// Windows of other keys started before `window_start` may be dropped.
+    async fn hit(&self, key: &String, now: &u64, window_start: &u64) -> DResult<RequestWindow>;
+}
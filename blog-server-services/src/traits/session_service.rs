@@ -0,0 +1,49 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BaseSession {
+    pub author_id: u64,
+    /// SHA-256 of the refresh token secret, the secret itself is never stored.
+    pub refresh_token_hash: String,
+    pub user_agent: Option<String>,
+    pub created_at: u64,
+    pub last_used_at: u64,
+    pub expires_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Session {
+    pub id: u64,
+    pub revoked: u8,
+    #[serde(flatten)]
+    pub base: BaseSession,
+}
+
+#[async_trait]
+pub trait SessionService: Send + Sync {
+    async fn create_session(&self, session: &BaseSession) -> DResult<u64>;
+    async fn active_sessions_by_author_id(&self, author_id: &u64) -> DResult<Vec<Session>>;
+    /// Swaps the refresh token hash of an active session, only if `refresh_token_hash`
+    /// is the current one. Returns the updated session on success. The replaced hash
+    /// is remembered, see `revoke_session_by_rotated_refresh_token`.
+    async fn rotate_refresh_token(
+        &self,
+        id: &u64,
+        refresh_token_hash: &String,
+        new_refresh_token_hash: &String,
+        expires_at: &u64,
+    ) -> DResult<Option<Session>>;
+    /// Revokes the session if `refresh_token_hash` is one it has already rotated away
+    /// from: a reused refresh token means it leaked, so neither copy may go on.
+    /// Returns whether the session was revoked.
+    async fn revoke_session_by_rotated_refresh_token(
+        &self,
+        id: &u64,
+        refresh_token_hash: &String,
+    ) -> DResult<bool>;
+    async fn revoke_session_by_id(&self, id: &u64) -> DResult<()>;
+    async fn revoke_sessions_by_author_id(&self, author_id: &u64) -> DResult<()>;
+}
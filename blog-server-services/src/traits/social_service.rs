@@ -1,10 +1,16 @@
 use screw_components::dyn_result::DResult;
 
 use super::author_service::*;
+use super::identity_service::AuthorIdentity;
 
 pub enum SocialId {
     TelegramId(u64),
     YandexId(u64),
+    /// Account at a configured OpenID Connect provider.
+    Identity {
+        provider: String,
+        subject: String,
+    },
 }
 
 impl SocialId {
@@ -12,14 +18,33 @@ impl SocialId {
         match self {
             SocialId::TelegramId(telegram_id) => Some(telegram_id),
             SocialId::YandexId(_) => None,
+            SocialId::Identity { .. } => None,
         }
     }
     pub fn yandex_id(&self) -> Option<&u64> {
         match self {
             SocialId::TelegramId(_) => None,
             SocialId::YandexId(yandex_id) => Some(yandex_id),
+            SocialId::Identity { .. } => None,
         }
     }
+    /// Key of the `author_identity` record, every variant has one.
+    pub fn provider_and_subject(&self) -> (String, String) {
+        match self {
+            SocialId::TelegramId(telegram_id) => ("telegram".to_string(), telegram_id.to_string()),
+            SocialId::YandexId(yandex_id) => ("yandex".to_string(), yandex_id.to_string()),
+            SocialId::Identity { provider, subject } => (provider.clone(), subject.clone()),
+        }
+    }
+}
+
+pub enum IdentityLink {
+    Linked,
+    AlreadyLinked,
+    /// The identity belongs to another author, the two can only be merged by an editor.
+    LinkedToAnotherAuthor {
+        author_id: u64,
+    },
 }
 
 #[async_trait]
@@ -30,4 +55,7 @@ pub trait SocialService: Send + Sync {
         social_id: &SocialId,
         base_minimal_author: &BaseMinimalAuthor,
     ) -> DResult<Author>;
+    /// Attaches the identity to `author` without touching profile data.
+    async fn link_identity(&self, author: &Author, social_id: &SocialId) -> DResult<IdentityLink>;
+    async fn unlink_identity(&self, author: &Author, identity: &AuthorIdentity) -> DResult<()>;
 }
@@ -0,0 +1,32 @@
+use screw_components::dyn_result::DResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AuthorTotp {
+    pub author_id: u64,
+    /// Base32 secret shared with the authenticator app.
+    pub secret: String,
+    /// Zero until enrollment is confirmed with a valid code.
+    pub enabled: u8,
+    /// Last accepted TOTP step, a code is never accepted twice.
+    pub last_used_step: Option<u64>,
+    pub created_at: u64,
+}
+
+#[async_trait]
+pub trait TwoFactorService: Send + Sync {
+    async fn totp_by_author_id(&self, author_id: &u64) -> DResult<Option<AuthorTotp>>;
+    /// Replaces an unconfirmed secret, an enabled one is left untouched.
+    async fn set_pending_totp(&self, author_id: &u64, secret: &String) -> DResult<()>;
+    /// Confirms the pending secret and replaces recovery codes with the given hashes.
+    async fn enable_totp(&self, author_id: &u64, recovery_code_hashes: &Vec<String>)
+    -> DResult<()>;
+    /// Returns `false` if the step is not newer than the last accepted one.
+    async fn use_totp_step(&self, author_id: &u64, step: &u64) -> DResult<bool>;
+    /// Returns `false` if there is no such unused code.
+    async fn use_recovery_code(&self, author_id: &u64, code_hash: &String) -> DResult<bool>;
+    async fn unused_recovery_codes_count(&self, author_id: &u64) -> DResult<u64>;
+    /// Removes the secret together with recovery codes.
+    async fn disable_totp(&self, author_id: &u64) -> DResult<()>;
+}
@@ -0,0 +1,108 @@
+use crate::utils::transliteration::{self, TranslitOption};
+
+pub const MAX_LENGTH: usize = 50;
+
+/// Taken by routes and well-known accounts, never given to authors.
+const RESERVED: [&str; 3] = ["me", "admin", "api"];
+
+/// Slugs of deleted accounts, see `AuthorService::delete_author`.
+const DELETED_PREFIX: &str = "deleted-";
+
+/// Used when nothing is left of the slug a provider suggested.
+const FALLBACK: &str = "author";
+
+pub fn is_valid(slug: &str) -> bool {
+    !slug.is_empty()
+        && slug.len() <= MAX_LENGTH
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+pub fn is_reserved(slug: &str) -> bool {
+    RESERVED.contains(&slug) || slug.starts_with(DELETED_PREFIX)
+}
+
+/// Turns a slug suggested by a login provider into a valid one, transliterating
+/// cyrillic, dropping unsupported chars and cutting it to the maximum length.
+pub fn normalize(slug: &str) -> String {
+    let normalized: String =
+        transliteration::ru_to_latin_single(slug.to_string(), TranslitOption::ToLowerCase)
+            .transliterated
+            .chars()
+            .filter(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-' || *c == '_')
+            .take(MAX_LENGTH)
+            .collect();
+    if normalized.is_empty() {
+        FALLBACK.to_string()
+    } else {
+        normalized
+    }
+}
+
+/// The `attempt`-th slug to try for a normalized `slug`: the slug itself, then
+/// `slug-2`, `slug-3` and so on. A reserved slug always gets a suffix.
+pub fn candidate(slug: &str, attempt: usize) -> String {
+    let number = attempt + 1 + is_reserved(slug) as usize;
+    if number == 1 {
+        slug.to_string()
+    } else {
+        with_suffix(slug, number)
+    }
+}
+
+/// Cuts the slug to keep the suffix within the maximum length.
+fn with_suffix(slug: &str, number: usize) -> String {
+    let suffix = format!("-{number}");
+    let base: String = slug.chars().take(MAX_LENGTH - suffix.len()).collect();
+    format!("{base}{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_drops_unsupported_chars() {
+        assert_eq!(normalize("John.Doe"), "johndoe");
+        assert_eq!(normalize("李小龍"), "author");
+        assert_eq!(normalize(&"a".repeat(80)).len(), MAX_LENGTH);
+    }
+
+    #[test]
+    fn normalize_transliterates_cyrillic() {
+        assert_eq!(normalize("Иван"), "ivan");
+        assert_eq!(normalize("Иван_Петров"), "ivan_petrov");
+    }
+
+    #[test]
+    fn normalized_slugs_are_valid() {
+        assert!(is_valid(&normalize("John Doe!")));
+        assert!(is_valid("john_doe-2"));
+        assert!(!is_valid("John"));
+        assert!(!is_valid(""));
+    }
+
+    #[test]
+    fn candidates_get_numeric_suffixes() {
+        assert_eq!(candidate("johny", 0), "johny");
+        assert_eq!(candidate("johny", 1), "johny-2");
+        assert_eq!(candidate("johny", 9), "johny-10");
+    }
+
+    #[test]
+    fn reserved_slugs_always_get_a_suffix() {
+        assert!(is_reserved("admin"));
+        assert!(is_reserved("deleted-12"));
+        assert_eq!(candidate("me", 0), "me-2");
+        assert_eq!(candidate("api", 1), "api-3");
+    }
+
+    #[test]
+    fn candidates_stay_within_maximum_length() {
+        let slug = "a".repeat(MAX_LENGTH);
+        let second = candidate(&slug, 1);
+        assert_eq!(second.len(), MAX_LENGTH);
+        assert!(second.ends_with("-2"));
+    }
+}
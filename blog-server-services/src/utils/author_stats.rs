@@ -0,0 +1,130 @@
+use crate::traits::author_stats_service::{MonthlyCount, PostsCounts};
+use blog_generic::entities::PublishType;
+
+/// Months in the histogram, the current one included.
+pub const HISTOGRAM_MONTHS: u32 = 12;
+
+pub fn posts_counts(rows: impl IntoIterator<Item = (PublishType, u64)>) -> PostsCounts {
+    let mut posts = PostsCounts::default();
+    for (publish_type, count) in rows {
+        match publish_type {
+            PublishType::Published => posts.published = count,
+            PublishType::Unpublished => posts.unpublished = count,
+            PublishType::Hidden => posts.hidden = count,
+        }
+    }
+    posts
+}
+
+fn parse_month(month: &str) -> Option<(i32, u32)> {
+    let (year, month) = month.split_once('-')?;
+    let month: u32 = month.parse().ok()?;
+    (1..=12).contains(&month).then_some(())?;
+    Some((year.parse().ok()?, month))
+}
+
+/// Puts `rows` of months with posts on the `months` months up to `current_month`,
+/// oldest first, months without posts counted as zero. Rows of other months are
+/// ignored. `None` when `current_month` isn't `YYYY-MM`.
+pub fn monthly_histogram(
+    current_month: &str,
+    rows: Vec<MonthlyCount>,
+    months: u32,
+) -> Option<Vec<MonthlyCount>> {
+    let (year, month) = parse_month(current_month)?;
+    let current = year * 12 + month as i32 - 1;
+    Some(
+        (current - months as i32 + 1..=current)
+            .map(|index| {
+                let month = format!(
+                    "{:04}-{:02}",
+                    index.div_euclid(12),
+                    index.rem_euclid(12) + 1
+                );
+                let count = rows
+                    .iter()
+                    .filter(|row| row.month == month)
+                    .map(|row| row.count)
+                    .sum();
+                MonthlyCount { month, count }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(month: &str, count: u64) -> MonthlyCount {
+        MonthlyCount {
+            month: month.to_string(),
+            count,
+        }
+    }
+
+    fn pairs(histogram: Vec<MonthlyCount>) -> Vec<(String, u64)> {
+        histogram.into_iter().map(|m| (m.month, m.count)).collect()
+    }
+
+    #[test]
+    fn months_without_posts_are_zero() {
+        let histogram = monthly_histogram(
+            "2024-03",
+            vec![row("2024-03", 2), row("2023-12", 1), row("2023-04", 5)],
+            HISTOGRAM_MONTHS,
+        )
+        .unwrap();
+        assert_eq!(histogram.len(), 12);
+        assert_eq!(histogram.first().unwrap().month, "2023-04");
+        assert_eq!(histogram.last().unwrap().month, "2024-03");
+        assert_eq!(
+            pairs(histogram)
+                .into_iter()
+                .filter(|(_, count)| *count > 0)
+                .collect::<Vec<_>>(),
+            vec![
+                ("2023-04".to_string(), 5),
+                ("2023-12".to_string(), 1),
+                ("2024-03".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_posts_give_a_full_zero_histogram() {
+        let histogram = monthly_histogram("2024-01", vec![], 3).unwrap();
+        assert_eq!(
+            pairs(histogram),
+            vec![
+                ("2023-11".to_string(), 0),
+                ("2023-12".to_string(), 0),
+                ("2024-01".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn rows_outside_the_range_are_ignored() {
+        let histogram =
+            monthly_histogram("2024-01", vec![row("2022-01", 7), row("2024-02", 1)], 2).unwrap();
+        assert_eq!(
+            pairs(histogram),
+            vec![("2023-12".to_string(), 0), ("2024-01".to_string(), 0)]
+        );
+    }
+
+    #[test]
+    fn malformed_current_month_is_rejected() {
+        assert!(monthly_histogram("2024-13", vec![], 12).is_none());
+        assert!(monthly_histogram("January", vec![], 12).is_none());
+    }
+
+    #[test]
+    fn posts_are_counted_by_publish_type() {
+        let posts = posts_counts([(PublishType::Published, 3), (PublishType::Hidden, 1)]);
+        assert_eq!(posts.published, 3);
+        assert_eq!(posts.unpublished, 0);
+        assert_eq!(posts.hidden, 1);
+    }
+}
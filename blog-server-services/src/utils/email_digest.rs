@@ -0,0 +1,117 @@
+use crate::traits::notification_preference_service::DigestFrequency;
+use crate::traits::post_service::Post;
+
+pub struct Digest {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+fn escape(src: &str) -> String {
+    src.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Lists `posts` in the given order, each with its title, summary and link.
+pub fn render(
+    posts: &[Post],
+    frequency: DigestFrequency,
+    site_url: &str,
+    unsubscribe_url: &str,
+) -> Digest {
+    let subject = match frequency {
+        DigestFrequency::Immediate => "New posts",
+        DigestFrequency::Daily => "New posts of the day",
+        DigestFrequency::Weekly => "New posts of the week",
+    }
+    .to_string();
+
+    let links: Vec<String> = posts
+        .iter()
+        .map(|p| format!("{site_url}/post/{}/{}", p.base.slug, p.id))
+        .collect();
+
+    let mut text = format!("{subject}\n\n");
+    for (post, link) in posts.iter().zip(links.iter()) {
+        text += &format!("{}\n{}\n{link}\n\n", post.base.title, post.base.summary);
+    }
+    text += &format!("Unsubscribe: {unsubscribe_url}\n");
+
+    let mut html = format!("<h1>{}</h1>\n", escape(&subject));
+    for (post, link) in posts.iter().zip(links.iter()) {
+        html += &format!(
+            "<h2><a href=\"{}\">{}</a></h2>\n<p>{}</p>\n",
+            escape(link),
+            escape(&post.base.title),
+            escape(&post.base.summary),
+        );
+    }
+    html += &format!(
+        "<p><small><a href=\"{}\">Unsubscribe</a></small></p>\n",
+        escape(unsubscribe_url)
+    );
+
+    Digest {
+        subject,
+        text,
+        html,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::post_service::BasePost;
+    use blog_generic::entities::PublishType;
+
+    fn post(id: u64, title: &str) -> Post {
+        Post {
+            id,
+            recommended: 0,
+            tags: vec![],
+            base: BasePost {
+                author_id: 1,
+                title: title.to_string(),
+                slug: format!("post{id}"),
+                summary: "Summary".to_string(),
+                publish_type: PublishType::Published,
+                created_at: 0,
+                content: None,
+                plain_text_content: None,
+                image_url: None,
+                lang: None,
+            },
+        }
+    }
+
+    #[test]
+    fn render_links_every_post() {
+        let digest = render(
+            &[post(1, "First"), post(2, "Second")],
+            DigestFrequency::Daily,
+            "https://blog.test",
+            "https://blog.test/unsubscribe",
+        );
+        assert!(digest.text.contains("https://blog.test/post/post1/1"));
+        assert!(digest.text.contains("https://blog.test/post/post2/2"));
+        assert!(digest.html.contains("https://blog.test/post/post2/2"));
+        assert!(digest.text.contains("https://blog.test/unsubscribe"));
+        assert!(digest.html.contains("https://blog.test/unsubscribe"));
+    }
+
+    #[test]
+    fn render_escapes_html() {
+        let digest = render(
+            &[post(1, "<script>alert(1)</script>")],
+            DigestFrequency::Weekly,
+            "https://blog.test",
+            "https://blog.test/unsubscribe",
+        );
+        assert!(!digest.html.contains("<script>"));
+        assert!(digest.text.contains("<script>"));
+        assert_eq!(digest.subject, "New posts of the week");
+    }
+}
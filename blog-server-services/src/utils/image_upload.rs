@@ -0,0 +1,177 @@
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+use std::io::Cursor;
+
+pub const MAX_SIZE: usize = 5 * 1024 * 1024;
+pub const MAX_DIMENSION: u32 = 4096;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadFormat {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+impl UploadFormat {
+    pub const ALL: [UploadFormat; 3] = [UploadFormat::Jpeg, UploadFormat::Png, UploadFormat::Webp];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            UploadFormat::Jpeg => "jpg",
+            UploadFormat::Png => "png",
+            UploadFormat::Webp => "webp",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            UploadFormat::Jpeg => "image/jpeg",
+            UploadFormat::Png => "image/png",
+            UploadFormat::Webp => "image/webp",
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        UploadFormat::ALL
+            .into_iter()
+            .find(|f| f.extension() == extension)
+    }
+
+    fn image_format(&self) -> ImageFormat {
+        match self {
+            UploadFormat::Jpeg => ImageFormat::Jpeg,
+            UploadFormat::Png => ImageFormat::Png,
+            UploadFormat::Webp => ImageFormat::WebP,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    TooLarge,
+    UnsupportedType,
+    Corrupted(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::TooLarge => write!(
+                f,
+                "image must be up to {} MiB and {MAX_DIMENSION}px on each side",
+                MAX_SIZE / 1024 / 1024
+            ),
+            Error::UnsupportedType => write!(f, "only JPEG, PNG and WebP images are supported"),
+            Error::Corrupted(e) => write!(f, "image can't be decoded: {e}"),
+        }
+    }
+}
+
+/// Checks the size and the actual type of an uploaded image, whatever the client
+/// claims, and encodes it again. The new file keeps only pixels, so EXIF (location,
+/// camera) and other metadata are dropped; the EXIF orientation is applied first.
+pub fn sanitize(data: &[u8]) -> Result<(Vec<u8>, UploadFormat), Error> {
+    if data.len() > MAX_SIZE {
+        return Err(Error::TooLarge);
+    }
+    let format = image::guess_format(data)
+        .ok()
+        .and_then(|f| {
+            UploadFormat::ALL
+                .into_iter()
+                .find(|u| u.image_format() == f)
+        })
+        .ok_or(Error::UnsupportedType)?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    let mut reader = ImageReader::with_format(Cursor::new(data), format.image_format());
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(decoding_error)?;
+    let orientation = decoder.orientation().map_err(decoding_error)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(decoding_error)?;
+    image.apply_orientation(orientation);
+
+    let mut encoded = Cursor::new(vec![]);
+    image
+        .write_to(&mut encoded, format.image_format())
+        .map_err(|e| Error::Corrupted(e.to_string()))?;
+    Ok((encoded.into_inner(), format))
+}
+
+fn decoding_error(e: image::ImageError) -> Error {
+    match e {
+        image::ImageError::Limits(_) => Error::TooLarge,
+        e => Error::Corrupted(e.to_string()),
+    }
+}
+
+/// Stored images are named by the hex SHA-256 of their content and the extension,
+/// anything else can't be one, so it never reaches the storage as a path.
+pub fn is_stored_name(name: &str) -> bool {
+    let Some((hash, extension)) = name.split_once('.') else {
+        return false;
+    };
+    hash.len() == 64
+        && hash.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+        && UploadFormat::from_extension(extension).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jpeg() -> Vec<u8> {
+        let mut encoded = Cursor::new(vec![]);
+        DynamicImage::new_rgb8(4, 4)
+            .write_to(&mut encoded, ImageFormat::Jpeg)
+            .unwrap();
+        encoded.into_inner()
+    }
+
+    #[test]
+    fn sanitize_drops_exif() {
+        let payload = b"Exif\0\0secret-camera-serial";
+        let length = (payload.len() + 2) as u16;
+        let original = jpeg();
+        let mut with_exif = original[..2].to_vec();
+        with_exif.extend_from_slice(&[0xFF, 0xE1]);
+        with_exif.extend_from_slice(&length.to_be_bytes());
+        with_exif.extend_from_slice(payload);
+        with_exif.extend_from_slice(&original[2..]);
+
+        let (sanitized, format) = sanitize(&with_exif).unwrap();
+        assert_eq!(format, UploadFormat::Jpeg);
+        assert!(
+            !sanitized
+                .windows(b"secret-camera-serial".len())
+                .any(|w| w == b"secret-camera-serial")
+        );
+    }
+
+    #[test]
+    fn sanitize_checks_the_content_not_the_name() {
+        assert!(matches!(
+            sanitize(b"GIF89a\x01\x00\x01\x00"),
+            Err(Error::UnsupportedType)
+        ));
+        assert!(matches!(
+            sanitize(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>"),
+            Err(Error::UnsupportedType)
+        ));
+        assert!(matches!(
+            sanitize(&vec![0; MAX_SIZE + 1]),
+            Err(Error::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn stored_names_are_hashes_with_known_extensions() {
+        let hash = "a".repeat(64);
+        assert!(is_stored_name(&format!("{hash}.png")));
+        assert!(!is_stored_name(&format!("{hash}.svg")));
+        assert!(!is_stored_name("../../etc/passwd"));
+        assert!(!is_stored_name(&format!("{}.jpg", "A".repeat(64))));
+    }
+}
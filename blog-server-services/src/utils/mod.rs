@@ -1,8 +1,15 @@
+pub mod author_slug;
+pub mod author_stats;
+pub mod cursor;
 pub mod discord_webhook_request;
+pub mod email_digest;
 pub mod html;
+pub mod id_token;
 pub mod image_signer;
+pub mod image_upload;
 pub mod map_in_pattern;
 pub mod string_filter;
 pub mod telegram_send_message_request;
 pub mod time_utils;
+pub mod totp;
 pub mod transliteration;
@@ -0,0 +1,150 @@
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// RFC 6238 defaults, the only parameters authenticator apps reliably support.
+pub const DIGITS: u32 = 6;
+pub const STEP: u64 = 30;
+/// Accepted clock drift in steps on either side.
+const SKEW: u64 = 1;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// RFC 4648 base32 without padding, the format of otpauth secrets.
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            result.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        result.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    result
+}
+
+pub fn base32_decode(value: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::with_capacity(value.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in value.bytes() {
+        if c == b'=' || c == b' ' {
+            continue;
+        }
+        let index = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | index as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+        }
+    }
+    Some(result)
+}
+
+pub fn step_at(unix_time: u64) -> u64 {
+    unix_time / STEP
+}
+
+/// HOTP value (RFC 4226) of `secret` for the given counter.
+pub fn code_at_step(secret: &[u8], step: u64) -> u32 {
+    let mut mac = HmacSha1::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    binary % 10u32.pow(DIGITS)
+}
+
+/// Returns the matched step, so the caller can refuse to accept it twice.
+pub fn verify(secret_base32: &str, code: &str, unix_time: u64) -> Option<u64> {
+    if code.len() != DIGITS as usize || !code.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let code = code.parse::<u32>().ok()?;
+    let secret = base32_decode(secret_base32)?;
+    let current = step_at(unix_time);
+    (current.saturating_sub(SKEW)..=current + SKEW)
+        .find(|step| code_at_step(&secret, *step) == code)
+}
+
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// `otpauth://` URI understood by authenticator apps, usually shown as a QR code.
+pub fn otpauth_uri(issuer: &str, account: &str, secret_base32: &str) -> String {
+    format!(
+        "otpauth://totp/{issuer_label}:{account}?secret={secret_base32}&issuer={issuer}&algorithm=SHA1&digits={DIGITS}&period={STEP}",
+        issuer_label = uri_encode(issuer),
+        account = uri_encode(account),
+        issuer = uri_encode(issuer),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn codes_match_rfc_6238_vectors() {
+        assert_eq!(code_at_step(RFC_SECRET, step_at(59)), 287082);
+        assert_eq!(code_at_step(RFC_SECRET, step_at(1111111109)), 81804);
+        assert_eq!(code_at_step(RFC_SECRET, step_at(2000000000)), 279037);
+    }
+
+    #[test]
+    fn base32_round_trips() {
+        assert_eq!(base32_encode(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(base32_decode("MZXW6YTBOI"), Some(b"foobar".to_vec()));
+        assert_eq!(base32_decode("mzxw6ytboi======"), Some(b"foobar".to_vec()));
+        assert_eq!(base32_decode("not base32!"), None);
+    }
+
+    #[test]
+    fn verify_accepts_adjacent_steps_only() {
+        let secret = base32_encode(RFC_SECRET);
+        assert_eq!(
+            verify(&secret, "081804", 1111111109),
+            Some(step_at(1111111109))
+        );
+        assert_eq!(
+            verify(&secret, "081804", 1111111109 + STEP),
+            Some(step_at(1111111109))
+        );
+        assert_eq!(verify(&secret, "081804", 1111111109 + STEP * 3), None);
+        assert_eq!(verify(&secret, "81804", 1111111109), None);
+    }
+
+    #[test]
+    fn otpauth_uri_escapes_labels() {
+        assert_eq!(
+            otpauth_uri("my blog", "john", "MZXW6YTBOI"),
+            "otpauth://totp/my%20blog:john?secret=MZXW6YTBOI&issuer=my%20blog&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}